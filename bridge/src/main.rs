@@ -0,0 +1,578 @@
+use std::env;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+// Même codec OSC que l'application : le fichier ne dépend que de la bibliothèque standard.
+#[path = "../../src/osc_codec.rs"]
+#[allow(dead_code)]
+mod osc;
+mod slip;
+mod ws;
+
+// --- Relais OSC-sur-WebSocket pour le guide Eos ---
+// Le navigateur ne parle ni UDP ni TCP brut : il envoie les paquets OSC en trames WebSocket
+// binaires à ce relais local, qui les transmet à la console (UDP, ou TCP avec encadrement SLIP)
+// et renvoie ses réponses (`/eos/out/cmd`…) à tous les navigateurs connectés.
+
+const USAGE: &str = "\
+Usage : eos-osc-bridge [relay|fake-console|selftest] [options]
+
+  relay          relaie WebSocket ↔ console (par défaut)
+  fake-console   fausse console Eos de test : renvoie /eos/out/cmd
+  selftest       relais + fausse console en boucle locale, UDP puis TCP
+
+Options :
+  --listen ADR   WebSocket du relais, ou écoute de la fausse console
+                 (défaut 127.0.0.1:8090, ou 127.0.0.1:8000 pour fake-console)
+  --console ADR  console Eos / Nomad (défaut 127.0.0.1:8000 en UDP, 127.0.0.1:3032 en TCP)
+  --udp          OSC sur UDP (défaut)
+  --tcp          OSC 1.1 sur TCP, paquets encadrés en SLIP
+  --local ADR    port UDP local où la console répond (défaut 0.0.0.0:8001)
+  --allow-origin URL
+                 page autorisée à se connecter au relais, répétable
+                 (défaut http://127.0.0.1:8080 et http://localhost:8080)";
+
+/// Origines acceptées sans `--allow-origin` : l'application servie par `trunk serve`.
+const DEFAULT_ORIGINS: [&str; 2] = ["http://127.0.0.1:8080", "http://localhost:8080"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Transport {
+    Udp,
+    Tcp,
+}
+
+struct Config {
+    listen: Option<String>,
+    console: Option<String>,
+    transport: Transport,
+    local: String,
+    allowed_origins: Vec<String>,
+}
+
+impl Config {
+    fn console(&self) -> String {
+        self.console.clone().unwrap_or_else(|| match self.transport {
+            Transport::Udp => "127.0.0.1:8000".to_string(),
+            Transport::Tcp => "127.0.0.1:3032".to_string(),
+        })
+    }
+
+    fn allowed_origins(&self) -> Vec<String> {
+        if self.allowed_origins.is_empty() {
+            DEFAULT_ORIGINS.iter().map(|o| o.to_string()).collect()
+        } else {
+            self.allowed_origins.clone()
+        }
+    }
+}
+
+fn resolve(address: &str) -> io::Result<SocketAddr> {
+    address
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("adresse introuvable : {}", address)))
+}
+
+/// Messages d'un paquet, bundles dépliés ; vide si le paquet n'est pas de l'OSC valide.
+fn messages(packet: &[u8]) -> Vec<osc::OscMessage> {
+    fn flatten(packet: osc::OscPacket, out: &mut Vec<osc::OscMessage>) {
+        match packet {
+            osc::OscPacket::Message(message) => out.push(message),
+            osc::OscPacket::Bundle(bundle) => bundle.elements.into_iter().for_each(|e| flatten(e, out)),
+        }
+    }
+    let mut out = Vec::new();
+    if let Ok(packet) = osc::decode_packet(packet) {
+        flatten(packet, &mut out);
+    }
+    out
+}
+
+/// Adresses OSC d'un paquet, pour le journal.
+fn describe(packet: &[u8]) -> String {
+    let messages = messages(packet);
+    if messages.is_empty() {
+        return format!("{} octet(s) non OSC", packet.len());
+    }
+    messages
+        .iter()
+        .map(|m| match m.args.first() {
+            Some(osc::OscArg::String(s)) => format!("{} \"{}\"", m.address, s),
+            _ => m.address.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// --- Navigateurs connectés ---
+
+type Clients = Arc<Mutex<Vec<(SocketAddr, TcpStream)>>>;
+
+fn broadcast(clients: &Clients, packet: &[u8]) {
+    let mut clients = clients.lock().unwrap_or_else(|e| e.into_inner());
+    clients.retain_mut(|(_, stream)| ws::write_frame(stream, ws::OP_BINARY, packet, false).is_ok());
+}
+
+// --- Liaison console ---
+
+enum Console {
+    Udp { socket: UdpSocket, target: SocketAddr },
+    Tcp(Mutex<TcpStream>),
+}
+
+impl Console {
+    /// Ouvre la liaison ; un fil de lecture renvoie les réponses aux navigateurs.
+    fn open(config: &Config, clients: Clients) -> io::Result<Arc<Console>> {
+        let target = resolve(&config.console())?;
+        match config.transport {
+            Transport::Udp => {
+                let socket = UdpSocket::bind(&config.local)?;
+                let reader = socket.try_clone()?;
+                thread::spawn(move || {
+                    let mut buffer = [0u8; 65536];
+                    loop {
+                        match reader.recv_from(&mut buffer) {
+                            Ok((n, _)) => {
+                                println!("← console : {}", describe(&buffer[..n]));
+                                broadcast(&clients, &buffer[..n]);
+                            }
+                            Err(e) => {
+                                eprintln!("Lecture UDP interrompue : {}", e);
+                                break;
+                            }
+                        }
+                    }
+                });
+                Ok(Arc::new(Console::Udp { socket, target }))
+            }
+            Transport::Tcp => {
+                let stream = TcpStream::connect_timeout(&target, Duration::from_secs(5))?;
+                let mut reader = stream.try_clone()?;
+                thread::spawn(move || {
+                    let mut decoder = slip::Decoder::default();
+                    let mut buffer = [0u8; 4096];
+                    loop {
+                        match reader.read(&mut buffer) {
+                            Ok(0) => {
+                                eprintln!("La console a fermé la connexion TCP");
+                                break;
+                            }
+                            Ok(n) => {
+                                for packet in decoder.push(&buffer[..n]) {
+                                    println!("← console : {}", describe(&packet));
+                                    broadcast(&clients, &packet);
+                                }
+                            }
+                            Err(e) => {
+                                eprintln!("Lecture TCP interrompue : {}", e);
+                                break;
+                            }
+                        }
+                    }
+                });
+                Ok(Arc::new(Console::Tcp(Mutex::new(stream))))
+            }
+        }
+    }
+
+    fn send(&self, packet: &[u8]) -> io::Result<()> {
+        match self {
+            Console::Udp { socket, target } => socket.send_to(packet, target).map(|_| ()),
+            Console::Tcp(stream) => stream.lock().unwrap_or_else(|e| e.into_inner()).write_all(&slip::encode(packet)),
+        }
+    }
+}
+
+// --- Relais ---
+
+fn handle_client(mut stream: TcpStream, console: Arc<Console>, clients: Clients, origins: Arc<Vec<String>>) -> io::Result<()> {
+    let peer = stream.peer_addr()?;
+    ws::accept(&mut stream, &origins)?;
+    println!("Navigateur connecté : {}", peer);
+    clients.lock().unwrap_or_else(|e| e.into_inner()).push((peer, stream.try_clone()?));
+    let mut reader = ws::MessageReader::new(true);
+    let result = loop {
+        let (opcode, payload) = match reader.read(&mut stream) {
+            Ok(message) => message,
+            Err(e) => break Err(e),
+        };
+        match opcode {
+            ws::OP_BINARY => {
+                println!("→ console : {}", describe(&payload));
+                if let Err(e) = console.send(&payload) {
+                    eprintln!("Envoi à la console impossible : {}", e);
+                }
+            }
+            ws::OP_PING => {
+                let _guard = clients.lock().unwrap_or_else(|e| e.into_inner());
+                ws::write_frame(&mut stream, ws::OP_PONG, &payload, false)?;
+            }
+            ws::OP_CLOSE => {
+                let _guard = clients.lock().unwrap_or_else(|e| e.into_inner());
+                let _ = ws::write_frame(&mut stream, ws::OP_CLOSE, &payload, false);
+                break Ok(());
+            }
+            ws::OP_TEXT => eprintln!("Trame texte ignorée : le relais attend des paquets OSC binaires"),
+            _ => {}
+        }
+    };
+    clients.lock().unwrap_or_else(|e| e.into_inner()).retain(|(addr, _)| *addr != peer);
+    println!("Navigateur déconnecté : {}", peer);
+    result
+}
+
+/// Démarre le relais ; renvoie l'adresse WebSocket effectivement ouverte.
+fn start_relay(config: &Config) -> io::Result<SocketAddr> {
+    let clients = Clients::default();
+    let console = Console::open(config, clients.clone())?;
+    let listener = TcpListener::bind(config.listen.as_deref().unwrap_or("127.0.0.1:8090"))?;
+    let address = listener.local_addr()?;
+    let origins = Arc::new(config.allowed_origins());
+    println!("Relais ws://{} → console {} ({:?}), origines : {}", address, config.console(), config.transport, origins.join(", "));
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let console = console.clone();
+            let clients = clients.clone();
+            let origins = origins.clone();
+            thread::spawn(move || {
+                if let Err(e) = handle_client(stream, console, clients, origins) {
+                    eprintln!("Session WebSocket terminée : {}", e);
+                }
+            });
+        }
+    });
+    Ok(address)
+}
+
+// --- Fausse console ---
+// Tient un command line comme Eos : /eos/newcmd le remplace, /eos/cmd et /eos/key/… le complètent,
+// `#` l'exécute. Chaque changement est renvoyé sur /eos/out/cmd.
+
+#[derive(Default)]
+struct FakeConsole {
+    line: String,
+}
+
+impl FakeConsole {
+    fn append(&mut self, text: &str) {
+        if !self.line.is_empty() && !self.line.ends_with([' ', '#']) && !text.starts_with('#') {
+            self.line.push(' ');
+        }
+        self.line.push_str(text);
+    }
+
+    fn handle(&mut self, packet: &[u8]) -> Vec<Vec<u8>> {
+        let mut replies = Vec::new();
+        for message in messages(packet) {
+            let text = message.args.iter().find_map(|a| match a {
+                osc::OscArg::String(s) => Some(s.clone()),
+                _ => None,
+            });
+            let pressed = match message.args.first() {
+                Some(osc::OscArg::Float(v)) => *v > 0.0,
+                Some(osc::OscArg::Int(v)) => *v > 0,
+                _ => true,
+            };
+            match message.address.as_str() {
+                "/eos/newcmd" => {
+                    self.line.clear();
+                    self.append(&text.unwrap_or_default());
+                }
+                "/eos/cmd" => self.append(&text.unwrap_or_default()),
+                "/eos/ping" => {
+                    replies.push(osc::encode_message(&osc::OscMessage::new("/eos/out/ping", message.args.clone())));
+                    continue;
+                }
+                address => match address.strip_prefix("/eos/key/") {
+                    Some(key) if pressed => match key {
+                        "enter" => self.append("#"),
+                        "clear" => {
+                            self.line.pop();
+                        }
+                        key => self.append(&key.replace('_', " ")),
+                    },
+                    _ => continue,
+                },
+            }
+            println!("Fausse console : {}", self.line);
+            replies.push(osc::encode_message(&osc::OscMessage::new("/eos/out/cmd", vec![osc::OscArg::String(format!("LIVE: Cmd: {}", self.line))])));
+            // Ce qui précède le dernier `#` est exécuté : le command line repart de la suite.
+            if let Some(end) = self.line.rfind('#') {
+                self.line = self.line[end + 1..].trim_start().to_string();
+            }
+        }
+        replies
+    }
+}
+
+/// Démarre la fausse console ; renvoie l'adresse d'écoute effective.
+fn spawn_fake_console(listen: &str, transport: Transport) -> io::Result<SocketAddr> {
+    match transport {
+        Transport::Udp => {
+            let socket = UdpSocket::bind(listen)?;
+            let address = socket.local_addr()?;
+            thread::spawn(move || {
+                let mut console = FakeConsole::default();
+                let mut buffer = [0u8; 65536];
+                while let Ok((n, from)) = socket.recv_from(&mut buffer) {
+                    for reply in console.handle(&buffer[..n]) {
+                        let _ = socket.send_to(&reply, from);
+                    }
+                }
+            });
+            Ok(address)
+        }
+        Transport::Tcp => {
+            let listener = TcpListener::bind(listen)?;
+            let address = listener.local_addr()?;
+            thread::spawn(move || {
+                for mut stream in listener.incoming().flatten() {
+                    thread::spawn(move || {
+                        let mut console = FakeConsole::default();
+                        let mut decoder = slip::Decoder::default();
+                        let mut buffer = [0u8; 4096];
+                        while let Ok(n @ 1..) = stream.read(&mut buffer) {
+                            for packet in decoder.push(&buffer[..n]) {
+                                for reply in console.handle(&packet) {
+                                    if stream.write_all(&slip::encode(&reply)).is_err() {
+                                        return;
+                                    }
+                                }
+                            }
+                        }
+                    });
+                }
+            });
+            Ok(address)
+        }
+    }
+}
+
+// --- Autotest en boucle locale ---
+
+/// Relais + fausse console sur un transport : envoie un command line et renvoie l'écho reçu.
+fn selftest_transport(transport: Transport) -> io::Result<String> {
+    let console = spawn_fake_console("127.0.0.1:0", transport)?;
+    let config = Config {
+        listen: Some("127.0.0.1:0".to_string()),
+        console: Some(console.to_string()),
+        transport,
+        local: "127.0.0.1:0".to_string(),
+        allowed_origins: Vec::new(),
+    };
+    let relay = start_relay(&config)?;
+
+    let mut client = TcpStream::connect(relay)?;
+    client.set_read_timeout(Some(Duration::from_secs(2)))?;
+    ws::connect(&mut client, &relay.to_string(), DEFAULT_ORIGINS[0])?;
+    let command = "Chan 1 Thru 10 At Full#";
+    let packet = osc::encode_message(&osc::OscMessage::new("/eos/newcmd", vec![osc::OscArg::String(command.to_string())]));
+    ws::write_frame(&mut client, ws::OP_BINARY, &packet, true)?;
+
+    let (_, reply) = ws::read_message(&mut client, false)?;
+    let echoed = messages(&reply).into_iter().find(|m| m.address == "/eos/out/cmd").and_then(|m| match m.args.first() {
+        Some(osc::OscArg::String(s)) => Some(s.clone()),
+        _ => None,
+    });
+    match echoed {
+        Some(text) if text.ends_with(command) => Ok(text),
+        other => Err(io::Error::other(format!("{:?} : écho inattendu {:?}", transport, other))),
+    }
+}
+
+fn selftest() -> io::Result<()> {
+    for transport in [Transport::Udp, Transport::Tcp] {
+        let text = selftest_transport(transport)?;
+        println!("✔ {:?} : écho reçu « {} »", transport, text);
+    }
+    Ok(())
+}
+
+// --- Ligne de commande ---
+
+fn parse_args(args: &[String]) -> Result<(String, Config), String> {
+    let mut mode = "relay".to_string();
+    let mut config = Config {
+        listen: None,
+        console: None,
+        transport: Transport::Udp,
+        local: "0.0.0.0:8001".to_string(),
+        allowed_origins: Vec::new(),
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().cloned().ok_or_else(|| format!("{} attend une adresse", name));
+        match arg.as_str() {
+            "relay" | "fake-console" | "selftest" => mode = arg.clone(),
+            "--listen" => config.listen = Some(value("--listen")?),
+            "--console" => config.console = Some(value("--console")?),
+            "--local" => config.local = value("--local")?,
+            "--allow-origin" => config.allowed_origins.push(value("--allow-origin")?),
+            "--udp" => config.transport = Transport::Udp,
+            "--tcp" => config.transport = Transport::Tcp,
+            "-h" | "--help" => return Err(String::new()),
+            other => return Err(format!("option inconnue : {}", other)),
+        }
+    }
+    Ok((mode, config))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (mode, config) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("{}\n", e);
+            }
+            eprintln!("{}", USAGE);
+            return if e.is_empty() { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+        }
+    };
+
+    let result = match mode.as_str() {
+        "selftest" => selftest(),
+        "fake-console" => {
+            let listen = config.listen.as_deref().unwrap_or("127.0.0.1:8000");
+            spawn_fake_console(listen, config.transport).map(|address| {
+                println!("Fausse console Eos en écoute sur {} ({:?})", address, config.transport);
+                loop {
+                    thread::park();
+                }
+            })
+        }
+        _ => start_relay(&config).map(|_| loop {
+            thread::park();
+        }),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Erreur : {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selftest_udp() {
+        assert!(selftest_transport(Transport::Udp).unwrap().ends_with("Chan 1 Thru 10 At Full#"));
+    }
+
+    #[test]
+    fn selftest_tcp() {
+        assert!(selftest_transport(Transport::Tcp).unwrap().ends_with("Chan 1 Thru 10 At Full#"));
+    }
+
+    #[test]
+    fn rejects_foreign_origin() {
+        let console = spawn_fake_console("127.0.0.1:0", Transport::Udp).unwrap();
+        let config = Config {
+            listen: Some("127.0.0.1:0".to_string()),
+            console: Some(console.to_string()),
+            transport: Transport::Udp,
+            local: "127.0.0.1:0".to_string(),
+            allowed_origins: Vec::new(),
+        };
+        let relay = start_relay(&config).unwrap();
+        let mut client = TcpStream::connect(relay).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        assert!(ws::connect(&mut client, &relay.to_string(), "https://example.com").is_err());
+    }
+
+    #[test]
+    fn rejects_unmasked_client_frame() {
+        let mut frame = Vec::new();
+        ws::write_frame(&mut frame, ws::OP_BINARY, b"abc", false).unwrap();
+        assert!(ws::read_message(&mut frame.as_slice(), true).is_err());
+        assert_eq!(ws::read_message(&mut frame.as_slice(), false).unwrap(), (ws::OP_BINARY, b"abc".to_vec()));
+    }
+
+    #[test]
+    fn caps_fragmented_message() {
+        // Fragments de 64 Kio chacun sous la limite, dont la somme la dépasse.
+        let chunk = vec![0u8; 1 << 16];
+        let mut stream = Vec::new();
+        for i in 0..20 {
+            let mut frame = Vec::new();
+            ws::write_frame(&mut frame, if i == 0 { ws::OP_BINARY } else { ws::OP_CONTINUATION }, &chunk, true).unwrap();
+            frame[0] &= 0x7F; // FIN à 0 : le message continue
+            stream.extend(frame);
+        }
+        assert!(ws::read_message(&mut stream.as_slice(), true).is_err());
+    }
+
+    /// Bundles imbriqués jusqu'à remplir un datagramme UDP : de quoi épuiser la pile sans limite de profondeur.
+    fn hostile_bundle() -> Vec<u8> {
+        let mut packet = osc::encode_message(&osc::OscMessage::new("/eos/ping", Vec::new()));
+        for _ in 0..3270 {
+            let mut bundle = b"#bundle\0".to_vec();
+            bundle.extend_from_slice(&osc::IMMEDIATELY.to_be_bytes());
+            bundle.extend_from_slice(&(packet.len() as i32).to_be_bytes());
+            bundle.extend(packet);
+            packet = bundle;
+        }
+        packet
+    }
+
+    #[test]
+    fn survives_hostile_console_packet() {
+        let hostile = hostile_bundle();
+        assert!(hostile.len() <= 65507);
+        assert_eq!(describe(&hostile), format!("{} octet(s) non OSC", hostile.len()));
+
+        let console = spawn_fake_console("127.0.0.1:0", Transport::Udp).unwrap();
+        let local = UdpSocket::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let config = Config {
+            listen: Some("127.0.0.1:0".to_string()),
+            console: Some(console.to_string()),
+            transport: Transport::Udp,
+            local: local.to_string(),
+            allowed_origins: Vec::new(),
+        };
+        let relay = start_relay(&config).unwrap();
+        UdpSocket::bind("127.0.0.1:0").unwrap().send_to(&hostile, local).unwrap();
+        thread::sleep(Duration::from_millis(200));
+
+        // Le relais tourne toujours : un command line fait encore l'aller-retour.
+        let mut client = TcpStream::connect(relay).unwrap();
+        client.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        ws::connect(&mut client, &relay.to_string(), DEFAULT_ORIGINS[0]).unwrap();
+        let packet = osc::encode_message(&osc::OscMessage::new("/eos/newcmd", vec![osc::OscArg::String("Chan 1#".to_string())]));
+        ws::write_frame(&mut client, ws::OP_BINARY, &packet, true).unwrap();
+        let (_, reply) = ws::read_message(&mut client, false).unwrap();
+        assert!(messages(&reply).iter().any(|m| m.address == "/eos/out/cmd"));
+    }
+
+    #[test]
+    fn keeps_fragments_across_control_frames() {
+        let mut stream = Vec::new();
+        ws::write_frame(&mut stream, ws::OP_BINARY, b"ab", true).unwrap();
+        stream[0] &= 0x7F; // FIN à 0 : le message continue
+        ws::write_frame(&mut stream, ws::OP_PING, b"p", true).unwrap();
+        ws::write_frame(&mut stream, ws::OP_CONTINUATION, b"cd", true).unwrap();
+        let mut input = stream.as_slice();
+        let mut reader = ws::MessageReader::new(true);
+        assert_eq!(reader.read(&mut input).unwrap(), (ws::OP_PING, b"p".to_vec()));
+        assert_eq!(reader.read(&mut input).unwrap(), (ws::OP_BINARY, b"abcd".to_vec()));
+    }
+
+    #[test]
+    fn rejects_invalid_control_frames() {
+        let mut long = Vec::new();
+        ws::write_frame(&mut long, ws::OP_PING, &[0u8; 126], true).unwrap();
+        assert!(ws::read_message(&mut long.as_slice(), true).is_err());
+        let mut fragmented = Vec::new();
+        ws::write_frame(&mut fragmented, ws::OP_PING, b"p", true).unwrap();
+        fragmented[0] &= 0x7F;
+        assert!(ws::read_message(&mut fragmented.as_slice(), true).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+// --- SLIP (RFC 1055) ---
+// Encadrement des paquets OSC 1.1 sur TCP : chaque paquet est entouré de END,
+// les octets END et ESC du contenu sont échappés.
+
+const END: u8 = 0xC0;
+const ESC: u8 = 0xDB;
+const ESC_END: u8 = 0xDC;
+const ESC_ESC: u8 = 0xDD;
+
+pub fn encode(packet: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(packet.len() + 2);
+    out.push(END);
+    for &b in packet {
+        match b {
+            END => out.extend_from_slice(&[ESC, ESC_END]),
+            ESC => out.extend_from_slice(&[ESC, ESC_ESC]),
+            b => out.push(b),
+        }
+    }
+    out.push(END);
+    out
+}
+
+/// Décodeur de flux : les octets arrivent par morceaux, les paquets complets en sortent.
+#[derive(Default)]
+pub struct Decoder {
+    buffer: Vec<u8>,
+    escaped: bool,
+}
+
+impl Decoder {
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut packets = Vec::new();
+        for &b in bytes {
+            if self.escaped {
+                self.escaped = false;
+                self.buffer.push(match b {
+                    ESC_END => END,
+                    ESC_ESC => ESC,
+                    // Échappement invalide : l'octet est gardé tel quel
+                    other => other,
+                });
+                continue;
+            }
+            match b {
+                END => {
+                    // Les END consécutifs délimitent des paquets vides, ignorés
+                    if !self.buffer.is_empty() {
+                        packets.push(std::mem::take(&mut self.buffer));
+                    }
+                }
+                ESC => self.escaped = true,
+                b => self.buffer.push(b),
+            }
+        }
+        packets
+    }
+}
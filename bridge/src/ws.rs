@@ -0,0 +1,247 @@
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+
+// --- WebSocket (RFC 6455), le strict nécessaire ---
+// Poignée de main HTTP, trames binaires, ping/pong et fermeture. Les trames du client sont masquées,
+// celles du serveur non. Le serveur n'accepte que les pages d'origine autorisée : sans ce contrôle,
+// n'importe quel site ouvert dans le navigateur pourrait piloter la console à travers le relais.
+
+const GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+pub const OP_CONTINUATION: u8 = 0x0;
+pub const OP_TEXT: u8 = 0x1;
+pub const OP_BINARY: u8 = 0x2;
+pub const OP_CLOSE: u8 = 0x8;
+pub const OP_PING: u8 = 0x9;
+pub const OP_PONG: u8 = 0xA;
+
+/// Taille maximale acceptée pour un message, fragments compris : largement au-delà d'un paquet OSC.
+const MAX_MESSAGE: u64 = 1 << 20;
+
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, wi) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(*wi);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (hi, v) in h.iter_mut().zip([a, b, c, d, e]) {
+            *hi = hi.wrapping_add(v);
+        }
+    }
+    let mut out = [0u8; 20];
+    for (i, v) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&v.to_be_bytes());
+    }
+    out
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            out.push(if i <= chunk.len() { ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char } else { '=' });
+        }
+    }
+    out
+}
+
+/// Valeur de `Sec-WebSocket-Accept` pour la clé envoyée par le client.
+pub fn accept_key(key: &str) -> String {
+    base64(&sha1(format!("{}{}", key.trim(), GUID).as_bytes()))
+}
+
+/// Lit l'en-tête HTTP jusqu'à la ligne vide.
+fn read_head(stream: &mut TcpStream) -> io::Result<String> {
+    let mut head = Vec::new();
+    let mut byte = [0u8; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        if stream.read(&mut byte)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "connexion fermée pendant la poignée de main"));
+        }
+        head.push(byte[0]);
+        if head.len() > 16 * 1024 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "en-tête HTTP trop long"));
+        }
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+fn header<'a>(head: &'a str, name: &str) -> Option<&'a str> {
+    head.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+    })
+}
+
+/// Côté serveur : répond à la demande de passage en WebSocket si l'en-tête `Origin` est autorisé.
+pub fn accept(stream: &mut TcpStream, allowed_origins: &[String]) -> io::Result<()> {
+    let head = read_head(stream)?;
+    let origin = header(&head, "Origin").unwrap_or_default();
+    if !allowed_origins.iter().any(|allowed| allowed.trim_end_matches('/').eq_ignore_ascii_case(origin)) {
+        stream.write_all(b"HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\n\r\n")?;
+        let origin = if origin.is_empty() { "absente" } else { origin };
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, format!("origine refusée : {} (voir --allow-origin)", origin)));
+    }
+    let Some(key) = header(&head, "Sec-WebSocket-Key") else {
+        stream.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")?;
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "requête sans Sec-WebSocket-Key"));
+    };
+    let response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(key)
+    );
+    stream.write_all(response.as_bytes())
+}
+
+/// Côté client (autotest) : ouvre la session WebSocket en se présentant avec l'origine donnée.
+pub fn connect(stream: &mut TcpStream, host: &str, origin: &str) -> io::Result<()> {
+    let key = "dGhlIHNhbXBsZSBub25jZQ==";
+    let request = format!(
+        "GET / HTTP/1.1\r\nHost: {}\r\nOrigin: {}\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Key: {}\r\nSec-WebSocket-Version: 13\r\n\r\n",
+        host, origin, key
+    );
+    stream.write_all(request.as_bytes())?;
+    let head = read_head(stream)?;
+    if !head.starts_with("HTTP/1.1 101") || header(&head, "Sec-WebSocket-Accept") != Some(accept_key(key).as_str()) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "poignée de main refusée"));
+    }
+    Ok(())
+}
+
+pub fn write_frame(stream: &mut impl Write, opcode: u8, payload: &[u8], masked: bool) -> io::Result<()> {
+    let mut frame = vec![0x80 | opcode];
+    let mask_bit = if masked { 0x80 } else { 0 };
+    match payload.len() {
+        len @ 0..=125 => frame.push(mask_bit | len as u8),
+        len @ 126..=0xFFFF => {
+            frame.push(mask_bit | 126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(mask_bit | 127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    if masked {
+        // Le masque n'a pas besoin d'être imprévisible pour un client local.
+        let mask = [0x12, 0x34, 0x56, 0x78];
+        frame.extend_from_slice(&mask);
+        frame.extend(payload.iter().enumerate().map(|(i, b)| b ^ mask[i % 4]));
+    } else {
+        frame.extend_from_slice(payload);
+    }
+    stream.write_all(&frame)
+}
+
+/// Lecteur de messages : garde le début d'un message fragmenté pendant qu'une trame de contrôle
+/// (ping, pong, fermeture) s'intercale entre ses fragments.
+/// Côté serveur, `from_client` impose le masquage exigé par la RFC 6455 pour les trames du client.
+pub struct MessageReader {
+    from_client: bool,
+    message: Vec<u8>,
+    opcode: Option<u8>,
+}
+
+impl MessageReader {
+    pub fn new(from_client: bool) -> Self {
+        MessageReader { from_client, message: Vec::new(), opcode: None }
+    }
+
+    /// Prochain message complet ou prochaine trame de contrôle : code d'opération et contenu.
+    pub fn read(&mut self, stream: &mut impl Read) -> io::Result<(u8, Vec<u8>)> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+        loop {
+            let mut head = [0u8; 2];
+            stream.read_exact(&mut head)?;
+            let fin = head[0] & 0x80 != 0;
+            let opcode = head[0] & 0x0F;
+            let masked = head[1] & 0x80 != 0;
+            let len = match head[1] & 0x7F {
+                126 => {
+                    let mut ext = [0u8; 2];
+                    stream.read_exact(&mut ext)?;
+                    u16::from_be_bytes(ext) as u64
+                }
+                127 => {
+                    let mut ext = [0u8; 8];
+                    stream.read_exact(&mut ext)?;
+                    u64::from_be_bytes(ext)
+                }
+                len => len as u64,
+            };
+            if self.from_client && !masked {
+                return Err(invalid("trame du client non masquée"));
+            }
+            let control = opcode >= OP_CLOSE;
+            // RFC 6455 §5.5 : une trame de contrôle tient en une seule trame de 125 octets au plus.
+            if control && (len > 125 || !fin) {
+                return Err(invalid("trame de contrôle invalide"));
+            }
+            if !control && self.message.len() as u64 + len > MAX_MESSAGE {
+                return Err(invalid("message trop grand"));
+            }
+            if opcode == OP_CONTINUATION && self.opcode.is_none() {
+                return Err(invalid("trame de continuation sans début de message"));
+            }
+            if !control && opcode != OP_CONTINUATION && self.opcode.is_some() {
+                return Err(invalid("nouveau message avant la fin du précédent"));
+            }
+            let mut mask = [0u8; 4];
+            if masked {
+                stream.read_exact(&mut mask)?;
+            }
+            let mut payload = vec![0u8; len as usize];
+            stream.read_exact(&mut payload)?;
+            if masked {
+                for (i, b) in payload.iter_mut().enumerate() {
+                    *b ^= mask[i % 4];
+                }
+            }
+            // Les trames de contrôle peuvent s'intercaler dans un message fragmenté :
+            // elles sont rendues tout de suite, le message en cours reste dans le lecteur.
+            if control {
+                return Ok((opcode, payload));
+            }
+            if opcode != OP_CONTINUATION {
+                self.opcode = Some(opcode);
+            }
+            self.message.extend_from_slice(&payload);
+            if fin {
+                let opcode = self.opcode.take().unwrap_or(OP_BINARY);
+                return Ok((opcode, std::mem::take(&mut self.message)));
+            }
+        }
+    }
+}
+
+/// Lit un seul message avec un lecteur neuf, pour les échanges courts (auto-test).
+pub fn read_message(stream: &mut impl Read, from_client: bool) -> io::Result<(u8, Vec<u8>)> {
+    MessageReader::new(from_client).read(stream)
+}
@@ -0,0 +1,855 @@
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::capture::{Capture, CaptureLoader, UdpDatagram};
+use crate::sacn::{demo_console_levels, demo_media_levels, multicast_address, DmxGrid, SacnReport, SequenceGap, DEMO_START};
+use crate::timecode::seconds_text;
+
+// --- Art-Net 4 ---
+// En-tête « Art-Net\0 », OpCode en little-endian puis, sauf pour ArtPollReply, la version de protocole (14).
+// Un univers Art-Net est une Port-Address 15 bits : Net (7 bits), Sub-Net (4 bits), Universe (4 bits).
+
+pub const ARTNET_PORT: u16 = 6454;
+const ARTNET_ID: &[u8; 8] = b"Art-Net\0";
+const PROTOCOL_VERSION: u16 = 14;
+
+const OP_POLL: u16 = 0x2000;
+const OP_POLL_REPLY: u16 = 0x2100;
+const OP_DMX: u16 = 0x5000;
+const OP_SYNC: u16 = 0x5200;
+
+const OPCODE_NAMES: [(u16, &str); 14] = [
+    (OP_POLL, "ArtPoll"),
+    (OP_POLL_REPLY, "ArtPollReply"),
+    (0x2300, "ArtDiagData"),
+    (0x2400, "ArtCommand"),
+    (OP_DMX, "ArtDmx"),
+    (0x5100, "ArtNzs"),
+    (OP_SYNC, "ArtSync"),
+    (0x6000, "ArtAddress"),
+    (0x7000, "ArtInput"),
+    (0x8000, "ArtTodRequest"),
+    (0x8100, "ArtTodData"),
+    (0x9700, "ArtTimeCode"),
+    (0xF800, "ArtIpProg"),
+    (0xF900, "ArtIpProgReply"),
+];
+
+pub fn opcode_name(opcode: u16) -> String {
+    OPCODE_NAMES.iter().find(|(op, _)| *op == opcode).map(|(_, name)| name.to_string()).unwrap_or_else(|| format!("OpCode 0x{:04X}", opcode))
+}
+
+// --- Port-Address ---
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PortAddress(pub u16);
+
+pub const MAX_PORT_ADDRESS: u16 = 0x7FFF;
+
+impl PortAddress {
+    pub fn new(net: u8, sub_net: u8, universe: u8) -> Result<Self, String> {
+        if net > 127 {
+            return Err(format!("Net {} hors de 0-127", net));
+        }
+        if sub_net > 15 || universe > 15 {
+            return Err(format!("Sub-Net et Universe vont de 0 à 15 (reçu {}:{})", sub_net, universe));
+        }
+        Ok(PortAddress(((net as u16) << 8) | ((sub_net as u16) << 4) | universe as u16))
+    }
+
+    pub fn net(self) -> u8 {
+        (self.0 >> 8) as u8 & 0x7F
+    }
+
+    pub fn sub_net(self) -> u8 {
+        (self.0 >> 4) as u8 & 0x0F
+    }
+
+    pub fn universe(self) -> u8 {
+        self.0 as u8 & 0x0F
+    }
+
+    /// `net:sub:uni` (ou `.` comme séparateur), ou la Port-Address en décimal.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let parts: Vec<&str> = text.split([':', '.', '/']).collect();
+        let number = |part: &str| part.trim().parse::<u8>().map_err(|_| format!("« {} » n'est pas un nombre", part.trim()));
+        match parts.as_slice() {
+            [net, sub, uni] => PortAddress::new(number(net)?, number(sub)?, number(uni)?),
+            [single] => match single.parse::<u16>() {
+                Ok(value) if value <= MAX_PORT_ADDRESS => Ok(PortAddress(value)),
+                Ok(value) => Err(format!("Port-Address {} hors de 0-32767", value)),
+                Err(_) => Err(format!("« {} » : attendu net:sub:uni ou un nombre", text)),
+            },
+            _ => Err(format!("« {} » : attendu net:sub:uni ou un nombre", text)),
+        }
+    }
+
+    pub fn text(self) -> String {
+        format!("{}:{}:{}", self.net(), self.sub_net(), self.universe())
+    }
+
+    /// Univers sACN équivalent ; `offset` vaut 1 quand Art-Net 0:0:0 correspond à l'univers sACN 1.
+    pub fn to_sacn(self, offset: u16) -> Option<u16> {
+        let universe = self.0 + offset;
+        (1..=63999).contains(&universe).then_some(universe)
+    }
+
+    pub fn from_sacn(universe: u16, offset: u16) -> Option<Self> {
+        universe.checked_sub(offset).filter(|&value| value <= MAX_PORT_ADDRESS).map(PortAddress)
+    }
+}
+
+/// Conventions de numérotation entre Art-Net et sACN.
+pub const NUMBERING_OFFSETS: [(u16, &str); 2] = [
+    (1, "Art-Net 0:0:0 = sACN 1 (Eos, la plupart des nodes)"),
+    (0, "Art-Net 0:0:1 = sACN 1"),
+];
+
+// --- Paquets ---
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodePort {
+    /// Rang du port sur le node, à partir de 1.
+    pub number: u8,
+    /// Protocole du connecteur : 0 = DMX512, 1 = MIDI, 5 = Art-Net…
+    pub protocol: u8,
+    pub output: Option<PortAddress>,
+    pub input: Option<PortAddress>,
+    /// Sortie alimentée en sACN plutôt qu'en Art-Net (Art-Net 4, GoodOutputA bit 0).
+    pub sacn: bool,
+    pub transmitting: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PollReply {
+    pub ip: Ipv4Addr,
+    pub firmware: u16,
+    pub oem: u16,
+    pub esta: u16,
+    pub short_name: String,
+    pub long_name: String,
+    pub node_report: String,
+    pub ports: Vec<NodePort>,
+    pub mac: [u8; 6],
+    /// 1 pour le node racine, 2+ pour ses éléments liés (nodes à plus de quatre ports).
+    pub bind_index: u8,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArtPacket {
+    Poll { version: u16, flags: u8 },
+    PollReply(PollReply),
+    Dmx { version: u16, sequence: u8, physical: u8, port_address: PortAddress, data: Vec<u8> },
+    Sync,
+    Other { opcode: u16 },
+}
+
+fn text_field(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).trim().to_string()
+}
+
+const PORT_OUTPUT: u8 = 0x80;
+const PORT_INPUT: u8 = 0x40;
+const GOOD_OUTPUT_DATA: u8 = 0x80;
+const GOOD_OUTPUT_SACN: u8 = 0x01;
+
+/// Longueur minimale d'un ArtPollReply (avant les champs ajoutés par Art-Net 3 et 4).
+const POLL_REPLY_MIN: usize = 207;
+const POLL_REPLY_LENGTH: usize = 239;
+
+pub fn decode(bytes: &[u8]) -> Result<ArtPacket, String> {
+    if bytes.len() < 10 || &bytes[0..8] != ARTNET_ID {
+        return Err("identifiant « Art-Net » absent".to_string());
+    }
+    let opcode = u16::from_le_bytes([bytes[8], bytes[9]]);
+    let need = |length: usize| {
+        if bytes.len() < length {
+            Err(format!("{} : {} octets, au moins {} attendus", opcode_name(opcode), bytes.len(), length))
+        } else {
+            Ok(())
+        }
+    };
+    let version = || u16::from_be_bytes([bytes[10], bytes[11]]);
+    Ok(match opcode {
+        OP_POLL => {
+            need(12)?;
+            ArtPacket::Poll { version: version(), flags: bytes.get(12).copied().unwrap_or(0) }
+        }
+        OP_POLL_REPLY => {
+            need(POLL_REPLY_MIN)?;
+            let (net, sub_net) = (bytes[18] & 0x7F, bytes[19] & 0x0F);
+            let count = (u16::from_be_bytes([bytes[172], bytes[173]]) as usize).min(4);
+            let ports = (0..4)
+                .filter(|&i| i < count || bytes[174 + i] & (PORT_OUTPUT | PORT_INPUT) != 0)
+                .map(|i| {
+                    let kind = bytes[174 + i];
+                    let address = |switch: u8| PortAddress(((net as u16) << 8) | ((sub_net as u16) << 4) | (switch & 0x0F) as u16);
+                    NodePort {
+                        number: i as u8 + 1,
+                        protocol: kind & 0x3F,
+                        output: (kind & PORT_OUTPUT != 0).then(|| address(bytes[190 + i])),
+                        input: (kind & PORT_INPUT != 0).then(|| address(bytes[186 + i])),
+                        sacn: bytes[182 + i] & GOOD_OUTPUT_SACN != 0,
+                        transmitting: bytes[182 + i] & GOOD_OUTPUT_DATA != 0,
+                    }
+                })
+                .collect();
+            let mut mac = [0; 6];
+            mac.copy_from_slice(&bytes[201..207]);
+            ArtPacket::PollReply(PollReply {
+                ip: Ipv4Addr::new(bytes[10], bytes[11], bytes[12], bytes[13]),
+                firmware: u16::from_be_bytes([bytes[16], bytes[17]]),
+                oem: u16::from_be_bytes([bytes[20], bytes[21]]),
+                esta: u16::from_le_bytes([bytes[24], bytes[25]]),
+                short_name: text_field(&bytes[26..44]),
+                long_name: text_field(&bytes[44..108]),
+                node_report: text_field(&bytes[108..172]),
+                ports,
+                mac,
+                bind_index: bytes.get(211).copied().filter(|&b| b > 0).unwrap_or(1),
+            })
+        }
+        OP_DMX => {
+            need(18)?;
+            let length = u16::from_be_bytes([bytes[16], bytes[17]]) as usize;
+            if !(1..=512).contains(&length) {
+                return Err(format!("ArtDmx : longueur {} hors de 1-512", length));
+            }
+            need(18 + length)?;
+            ArtPacket::Dmx {
+                version: version(),
+                sequence: bytes[12],
+                physical: bytes[13],
+                port_address: PortAddress(u16::from_le_bytes([bytes[14], bytes[15] & 0x7F])),
+                data: bytes[18..18 + length].to_vec(),
+            }
+        }
+        OP_SYNC => {
+            need(12)?;
+            ArtPacket::Sync
+        }
+        opcode => ArtPacket::Other { opcode },
+    })
+}
+
+impl ArtPacket {
+    fn opcode(&self) -> u16 {
+        match self {
+            ArtPacket::Poll { .. } => OP_POLL,
+            ArtPacket::PollReply(_) => OP_POLL_REPLY,
+            ArtPacket::Dmx { .. } => OP_DMX,
+            ArtPacket::Sync => OP_SYNC,
+            ArtPacket::Other { opcode } => *opcode,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = ARTNET_ID.to_vec();
+        out.extend_from_slice(&self.opcode().to_le_bytes());
+        match self {
+            ArtPacket::Poll { version, flags } => {
+                out.extend_from_slice(&version.to_be_bytes());
+                out.extend_from_slice(&[*flags, 0]);
+            }
+            ArtPacket::PollReply(r) => {
+                out.resize(POLL_REPLY_LENGTH, 0);
+                out[10..14].copy_from_slice(&r.ip.octets());
+                out[14..16].copy_from_slice(&ARTNET_PORT.to_le_bytes());
+                out[16..18].copy_from_slice(&r.firmware.to_be_bytes());
+                // Net et Sub-Net communs à tous les ports : ceux du premier
+                let first = r.ports.iter().find_map(|p| p.output.or(p.input)).unwrap_or(PortAddress(0));
+                out[18] = first.net();
+                out[19] = first.sub_net();
+                out[20..22].copy_from_slice(&r.oem.to_be_bytes());
+                out[24..26].copy_from_slice(&r.esta.to_le_bytes());
+                let mut text = |at: usize, size: usize, value: &str| {
+                    let bytes = value.as_bytes();
+                    let length = bytes.len().min(size - 1);
+                    out[at..at + length].copy_from_slice(&bytes[..length]);
+                };
+                text(26, 18, &r.short_name);
+                text(44, 64, &r.long_name);
+                text(108, 64, &r.node_report);
+                out[173] = r.ports.len().min(4) as u8;
+                for (i, port) in r.ports.iter().take(4).enumerate() {
+                    out[174 + i] = port.protocol | if port.output.is_some() { PORT_OUTPUT } else { 0 } | if port.input.is_some() { PORT_INPUT } else { 0 };
+                    out[182 + i] = if port.transmitting { GOOD_OUTPUT_DATA } else { 0 } | if port.sacn { GOOD_OUTPUT_SACN } else { 0 };
+                    out[186 + i] = port.input.map(|p| p.universe()).unwrap_or(0);
+                    out[190 + i] = port.output.map(|p| p.universe()).unwrap_or(0);
+                }
+                out[201..207].copy_from_slice(&r.mac);
+                out[211] = r.bind_index;
+            }
+            ArtPacket::Dmx { version, sequence, physical, port_address, data } => {
+                out.extend_from_slice(&version.to_be_bytes());
+                out.push(*sequence);
+                out.push(*physical);
+                out.extend_from_slice(&port_address.0.to_le_bytes());
+                out.extend_from_slice(&(data.len() as u16).to_be_bytes());
+                out.extend_from_slice(data);
+            }
+            ArtPacket::Sync => out.extend_from_slice(&[0, PROTOCOL_VERSION as u8, 0, 0]),
+            ArtPacket::Other { .. } => out.extend_from_slice(&PROTOCOL_VERSION.to_be_bytes()),
+        }
+        out
+    }
+}
+
+// --- Analyse d'une capture ---
+
+/// Flux ArtDmx d'un émetteur vers une Port-Address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DmxStream {
+    pub port_address: PortAddress,
+    pub source: Ipv4Addr,
+    /// Destination du dernier paquet : broadcast ou unicast vers un node.
+    pub destination: Ipv4Addr,
+    pub physical: u8,
+    pub packets: usize,
+    pub first_time: f64,
+    pub last_time: f64,
+    pub sequence: Option<u8>,
+    pub gaps: Vec<SequenceGap>,
+    pub lost: usize,
+    pub out_of_order: usize,
+    pub levels: Vec<u8>,
+    pub length: usize,
+}
+
+impl DmxStream {
+    pub fn rate(&self) -> f64 {
+        let span = self.last_time - self.first_time;
+        if span > 0.0 { (self.packets - 1) as f64 / span } else { 0.0 }
+    }
+
+    /// Séquence Art-Net : 0 la désactive, sinon elle va de 1 à 255 puis repart à 1.
+    fn accept(&mut self, time: f64, sequence: u8) -> bool {
+        if sequence == 0 {
+            return true;
+        }
+        if let Some(last) = self.sequence {
+            let step = ((sequence as i16 - last as i16).rem_euclid(255)) as u8;
+            // Un pas de plus d'une demi-boucle est un paquet en retard ou dupliqué
+            if step == 0 || step > 127 {
+                self.out_of_order += 1;
+                return false;
+            }
+            if step > 1 {
+                self.gaps.push(SequenceGap { time, expected: last % 255 + 1, received: sequence });
+                self.lost += step as usize - 1;
+            }
+        }
+        self.sequence = Some(sequence);
+        true
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArtNode {
+    pub reply: PollReply,
+    /// Adresse IP d'où vient la réponse (peut différer du champ IP d'un node mal configuré).
+    pub source: Ipv4Addr,
+    pub replies: usize,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArtReport {
+    pub packets: usize,
+    pub opcodes: BTreeMap<u16, usize>,
+    pub pollers: BTreeMap<Ipv4Addr, usize>,
+    pub syncs: usize,
+    pub nodes: Vec<ArtNode>,
+    pub streams: Vec<DmxStream>,
+    pub errors: Vec<String>,
+}
+
+const MAX_ERRORS: usize = 50;
+
+impl ArtReport {
+    pub fn from_capture(capture: &Capture) -> Self {
+        let start = capture.start_time();
+        let mut report = ArtReport::default();
+        for datagram in &capture.datagrams {
+            let time = datagram.time - start;
+            let packet = match decode(&datagram.payload) {
+                Ok(packet) => packet,
+                Err(e) => {
+                    if datagram.destination_port == ARTNET_PORT && report.errors.len() < MAX_ERRORS {
+                        report.errors.push(format!("{} — {} : {}", seconds_text(time), datagram.source, e));
+                    }
+                    continue;
+                }
+            };
+            report.packets += 1;
+            *report.opcodes.entry(packet.opcode()).or_default() += 1;
+            match packet {
+                ArtPacket::Poll { .. } => *report.pollers.entry(datagram.source).or_default() += 1,
+                ArtPacket::PollReply(reply) => match report.nodes.iter_mut().find(|n| n.source == datagram.source && n.reply.bind_index == reply.bind_index) {
+                    Some(node) => {
+                        node.reply = reply;
+                        node.replies += 1;
+                    }
+                    None => report.nodes.push(ArtNode { reply, source: datagram.source, replies: 1 }),
+                },
+                ArtPacket::Dmx { sequence, physical, port_address, data, .. } => report.add_dmx(time, datagram, sequence, physical, port_address, data),
+                ArtPacket::Sync => report.syncs += 1,
+                ArtPacket::Other { .. } => {}
+            }
+        }
+        report.streams.sort_by_key(|s| (s.port_address, s.source));
+        report
+    }
+
+    fn add_dmx(&mut self, time: f64, datagram: &UdpDatagram, sequence: u8, physical: u8, port_address: PortAddress, data: Vec<u8>) {
+        let index = match self.streams.iter().position(|s| s.port_address == port_address && s.source == datagram.source) {
+            Some(index) => index,
+            None => {
+                self.streams.push(DmxStream {
+                    port_address,
+                    source: datagram.source,
+                    destination: datagram.destination,
+                    physical,
+                    packets: 0,
+                    first_time: time,
+                    last_time: time,
+                    sequence: None,
+                    gaps: Vec::new(),
+                    lost: 0,
+                    out_of_order: 0,
+                    levels: vec![0; 512],
+                    length: 0,
+                });
+                self.streams.len() - 1
+            }
+        };
+        let stream = &mut self.streams[index];
+        if !stream.accept(time, sequence) {
+            return;
+        }
+        stream.packets += 1;
+        stream.last_time = time;
+        stream.destination = datagram.destination;
+        stream.length = data.len();
+        let mut levels = data;
+        levels.resize(512, 0);
+        stream.levels = levels;
+    }
+
+    pub fn port_addresses(&self) -> Vec<PortAddress> {
+        let mut all: Vec<PortAddress> = self.streams.iter().map(|s| s.port_address).collect();
+        all.dedup();
+        all
+    }
+
+    /// Plusieurs émetteurs sur une Port-Address : le node fusionne les deux premiers en HTP et ignore les suivants.
+    pub fn senders(&self, port_address: PortAddress) -> Vec<&DmxStream> {
+        self.streams.iter().filter(|s| s.port_address == port_address).collect()
+    }
+}
+
+// --- Recoupement Art-Net / sACN ---
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CrossStatus {
+    /// Mêmes niveaux que la source sACN nommée.
+    Same(String),
+    /// Niveaux différents ; `elsewhere` : univers sACN qui porte exactement ces niveaux.
+    Different { slots: usize, elsewhere: Option<u16> },
+    ArtNetOnly { elsewhere: Option<u16> },
+    SacnOnly,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrossRow {
+    pub port_address: Option<PortAddress>,
+    pub sacn_universe: Option<u16>,
+    pub status: CrossStatus,
+}
+
+/// Met en regard chaque Port-Address et l'univers sACN correspondant selon la convention `offset`.
+pub fn cross_check(art: &ArtReport, sacn: &SacnReport, offset: u16) -> Vec<CrossRow> {
+    // Univers sACN dont une source porte exactement ces niveaux (hors univers éteints)
+    let find_elsewhere = |levels: &[u8], skip: Option<u16>| {
+        if levels.iter().all(|&l| l == 0) {
+            return None;
+        }
+        sacn.streams.iter().find(|s| Some(s.universe) != skip && s.levels == levels).map(|s| s.universe)
+    };
+    let mut rows = Vec::new();
+    for port_address in art.port_addresses() {
+        let universe = port_address.to_sacn(offset);
+        let levels = &art.senders(port_address)[0].levels;
+        let sacn_streams = universe.map(|u| sacn.streams_of(u)).unwrap_or_default();
+        let status = if sacn_streams.is_empty() {
+            CrossStatus::ArtNetOnly { elsewhere: find_elsewhere(levels, None) }
+        } else if let Some(same) = sacn_streams.iter().find(|s| &s.levels == levels) {
+            CrossStatus::Same(same.name.clone())
+        } else {
+            let slots = sacn_streams.iter().map(|s| s.levels.iter().zip(levels).filter(|(a, b)| a != b).count()).min().unwrap_or(0);
+            CrossStatus::Different { slots, elsewhere: find_elsewhere(levels, universe) }
+        };
+        rows.push(CrossRow { port_address: Some(port_address), sacn_universe: universe, status });
+    }
+    for universe in sacn.universes() {
+        let port_address = PortAddress::from_sacn(universe, offset);
+        if !rows.iter().any(|r| r.sacn_universe == Some(universe)) {
+            rows.push(CrossRow { port_address, sacn_universe: Some(universe), status: CrossStatus::SacnOnly });
+        }
+    }
+    rows.sort_by_key(|r| (r.sacn_universe, r.port_address));
+    rows
+}
+
+/// État d'une sortie de node : reçoit-elle des données dans le protocole qu'elle écoute ?
+fn port_status(port: &NodePort, art: &ArtReport, sacn: &SacnReport, offset: u16) -> String {
+    let Some(address) = port.output else { return String::new() };
+    let universe = address.to_sacn(offset);
+    let has_art = !art.senders(address).is_empty();
+    let has_sacn = universe.is_some_and(|u| !sacn.streams_of(u).is_empty());
+    let sacn_label = universe.map(|u| format!("sACN {}", u)).unwrap_or_else(|| "sACN hors plage".to_string());
+    match (port.sacn, has_art, has_sacn) {
+        (false, true, _) => "✅ Art-Net reçu".to_string(),
+        (true, _, true) => format!("✅ {} reçu", sacn_label),
+        (false, false, true) => format!("⚠️ pas d'Art-Net, mais {} présent : passer le port en sACN ?", sacn_label),
+        (true, true, false) => format!("⚠️ pas de {}, mais Art-Net présent : passer le port en Art-Net ?", sacn_label),
+        (false, false, false) => "⚠️ aucune donnée pour cette sortie".to_string(),
+        (true, false, false) => format!("⚠️ aucun paquet {}", sacn_label),
+    }
+}
+
+// --- Capture de démonstration ---
+
+/// Le pupitre double l'univers 1 en Art-Net 0:0:0 ; le serveur média envoie son univers sACN 3 en Art-Net 0:0:3,
+/// décalé d'un univers ; deux nodes répondent au poll, l'un avec un port en sACN.
+pub fn demo_datagrams() -> Vec<UdpDatagram> {
+    let console = Ipv4Addr::new(10, 101, 100, 101);
+    let media = Ipv4Addr::new(10, 101, 50, 10);
+    let broadcast = Ipv4Addr::new(10, 255, 255, 255);
+    let mut datagrams = Vec::new();
+    let mut push = |time: f64, source: Ipv4Addr, destination: Ipv4Addr, packet: ArtPacket| {
+        datagrams.push(UdpDatagram { time, source, source_port: ARTNET_PORT, destination, destination_port: ARTNET_PORT, payload: packet.encode() });
+    };
+    let dmx = |sequence: u8, port_address: PortAddress, data: Vec<u8>| ArtPacket::Dmx { version: PROTOCOL_VERSION, sequence, physical: 0, port_address, data };
+    for tick in 0..30u8 {
+        let time = DEMO_START + tick as f64 * 0.1 + 0.005;
+        push(time, console, broadcast, dmx(tick + 1, PortAddress(0), demo_console_levels(tick)));
+        push(time + 0.001, console, broadcast, ArtPacket::Sync);
+        // Le serveur média saute les paquets 12 et 13
+        if !(12..=13).contains(&tick) {
+            push(time + 0.002, media, Ipv4Addr::new(10, 101, 60, 2), dmx(tick + 1, PortAddress(3), demo_media_levels(tick)));
+        }
+    }
+    let node = |ip: Ipv4Addr, short: &str, long: &str, outputs: [(u16, bool); 2]| PollReply {
+        ip,
+        firmware: 0x0104,
+        oem: 0x00FF,
+        esta: 0x7FF0,
+        short_name: short.to_string(),
+        long_name: long.to_string(),
+        node_report: "#0001 [0042] Power On Tests successful".to_string(),
+        ports: outputs
+            .iter()
+            .enumerate()
+            .map(|(i, &(address, sacn))| NodePort { number: i as u8 + 1, protocol: 0, output: Some(PortAddress(address)), input: None, sacn, transmitting: true })
+            .collect(),
+        mac: [0x02, 0x00, ip.octets()[0], ip.octets()[1], ip.octets()[2], ip.octets()[3]],
+        bind_index: 1,
+    };
+    push(DEMO_START + 0.2, console, broadcast, ArtPacket::Poll { version: PROTOCOL_VERSION, flags: 0x02 });
+    push(DEMO_START + 0.21, Ipv4Addr::new(10, 101, 60, 1), console, ArtPacket::PollReply(node(Ipv4Addr::new(10, 101, 60, 1), "Node FOH", "Node 2 ports — passerelle", [(0, false), (1, true)])));
+    push(DEMO_START + 0.22, Ipv4Addr::new(10, 101, 60, 2), console, ArtPacket::PollReply(node(Ipv4Addr::new(10, 101, 60, 2), "Node Jardin", "Node 2 ports — jardin", [(2, false), (3, false)])));
+    datagrams
+}
+
+// --- Composant Panneau Art-Net ---
+
+#[function_component(ArtnetPanel)]
+pub fn artnet_panel() -> Html {
+    let loaded = use_state(|| None::<(String, Result<Capture, String>)>);
+    let offset = use_state(|| NUMBERING_OFFSETS[0].0);
+    let artnet_text = use_state(|| "0:0:0".to_string());
+    let sacn_text = use_state(|| "1".to_string());
+    let stream = use_state(|| 0usize);
+
+    let reports = use_memo(loaded.clone(), |loaded| match loaded.as_ref() {
+        Some((_, Ok(capture))) => Some((ArtReport::from_capture(capture), SacnReport::from_capture(capture))),
+        _ => None,
+    });
+
+    let on_load = {
+        let loaded = loaded.clone();
+        let stream = stream.clone();
+        Callback::from(move |result| {
+            loaded.set(Some(result));
+            stream.set(0);
+        })
+    };
+    let on_offset = {
+        let offset = offset.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                offset.set(NUMBERING_OFFSETS[select.selected_index().max(0) as usize].0);
+            }
+        })
+    };
+    let text_input = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                state.set(input.value());
+            }
+        })
+    };
+
+    // Calculateur
+    let from_artnet = PortAddress::parse(&artnet_text).and_then(|pa| pa.to_sacn(*offset).map(|u| (pa, u)).ok_or_else(|| "pas d'univers sACN équivalent".to_string()));
+    let from_sacn = sacn_text
+        .trim()
+        .parse::<u16>()
+        .ok()
+        .filter(|u| (1..=63999).contains(u))
+        .ok_or_else(|| format!("« {} » : univers sACN de 1 à 63999", sacn_text.trim()))
+        .and_then(|u| PortAddress::from_sacn(u, *offset).map(|pa| (pa, u)).ok_or_else(|| "pas de Port-Address équivalente".to_string()));
+    let conversion = |result: Result<(PortAddress, u16), String>| match result {
+        Ok((pa, universe)) => html! {
+            <table class="tc-table">
+                <tr><th>{ "Net : Sub-Net : Universe" }</th><td><code>{ pa.text() }</code></td></tr>
+                <tr><th>{ "Port-Address" }</th><td><code>{ format!("{} (0x{:04X})", pa.0, pa.0) }</code></td></tr>
+                <tr><th>{ "Univers sACN" }</th><td><code>{ universe }</code></td></tr>
+                <tr><th>{ "Multicast sACN" }</th><td><code>{ multicast_address(universe).to_string() }</code></td></tr>
+            </table>
+        },
+        Err(e) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+    };
+
+    let body = match (loaded.as_ref(), reports.as_ref()) {
+        (None, _) => html! { <p class="sim-message">{ "Chargez une capture pour lister les nodes, les univers ArtDmx et les recouper avec sACN." }</p> },
+        (Some((name, Err(e))), _) => html! { <p class="sim-message">{ format!("{} : {}", name, e) }</p> },
+        (Some((name, Ok(capture))), Some((art, sacn))) => {
+            let opcodes = art.opcodes.iter().map(|(op, n)| format!("{} × {}", opcode_name(*op), n)).collect::<Vec<_>>().join(", ");
+            html! {
+                <>
+                    <p class="sim-message">{ format!("{} ({}) : {} paquets Art-Net — {}", name, capture.format, art.packets, if opcodes.is_empty() { "aucun".to_string() } else { opcodes }) }</p>
+                    if !art.errors.is_empty() {
+                        <ul class="template-errors">{ for art.errors.iter().map(|e| html! { <li>{ e }</li> }) }</ul>
+                    }
+                    { nodes_table(art, sacn, *offset) }
+                    { streams_section(art, *stream, &stream) }
+                    { cross_table(art, sacn, *offset) }
+                </>
+            }
+        }
+        _ => html! {},
+    };
+
+    html! {
+        <div class="artnet-panel">
+            <h3>{ "Art-Net ↔ sACN" }</h3>
+            <div class="sim-input">
+                <select onchange={on_offset}>
+                    { for NUMBERING_OFFSETS.iter().map(|(o, label)| html! { <option selected={*o == *offset}>{ *label }</option> }) }
+                </select>
+            </div>
+            <div class="artnet-converter">
+                <div>
+                    <label>{ "Art-Net (net:sub:uni ou Port-Address) " }<input type="text" value={(*artnet_text).clone()} oninput={text_input(&artnet_text)} /></label>
+                    { conversion(from_artnet) }
+                </div>
+                <div>
+                    <label>{ "Univers sACN " }<input type="text" value={(*sacn_text).clone()} oninput={text_input(&sacn_text)} /></label>
+                    { conversion(from_sacn) }
+                </div>
+            </div>
+
+            <h3>{ "Capture" }</h3>
+            <CaptureLoader {on_load} />
+            { body }
+        </div>
+    }
+}
+
+fn nodes_table(art: &ArtReport, sacn: &SacnReport, offset: u16) -> Html {
+    if art.nodes.is_empty() {
+        let polled = if art.pollers.is_empty() { "aucun ArtPoll dans la capture" } else { "ArtPoll envoyé, mais aucune réponse" };
+        return html! { <p class="sim-message">{ format!("Aucun node découvert : {}.", polled) }</p> };
+    }
+    html! {
+        <>
+            <h3>{ format!("Nodes découverts ({})", art.nodes.len()) }</h3>
+            <table class="capture-table">
+                <tr><th>{ "IP" }</th><th>{ "Nom" }</th><th>{ "MAC" }</th><th>{ "Firmware" }</th><th>{ "Ports" }</th><th>{ "Rapport" }</th></tr>
+                { for art.nodes.iter().map(|node| {
+                    let r = &node.reply;
+                    let mac = r.mac.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(":");
+                    let ip = if r.ip == node.source { r.ip.to_string() } else { format!("{} (annonce {})", node.source, r.ip) };
+                    let name = if r.bind_index > 1 { format!("{} [{}]", r.short_name, r.bind_index) } else { r.short_name.clone() };
+                    html! {
+                        <tr>
+                            <td>{ ip }</td>
+                            <td title={r.long_name.clone()}>{ name }</td>
+                            <td><code>{ mac }</code></td>
+                            <td>{ format!("{}.{}", r.firmware >> 8, r.firmware & 0xFF) }</td>
+                            <td>
+                                { for r.ports.iter().map(|port| {
+                                    let direction = match (port.output, port.input) {
+                                        (Some(out), _) => format!("sortie {} ({})", out.text(), if port.sacn { "sACN" } else { "Art-Net" }),
+                                        (None, Some(input)) => format!("entrée {}", input.text()),
+                                        (None, None) => "inutilisé".to_string(),
+                                    };
+                                    html! { <div>{ format!("{} : {} ", port.number, direction) }<span class="sim-message">{ port_status(port, art, sacn, offset) }</span></div> }
+                                })}
+                            </td>
+                            <td class="sim-message">{ &r.node_report }</td>
+                        </tr>
+                    }
+                })}
+            </table>
+        </>
+    }
+}
+
+fn streams_section(art: &ArtReport, selected: usize, stream: &UseStateHandle<usize>) -> Html {
+    if art.streams.is_empty() {
+        return html! {};
+    }
+    let index = selected.min(art.streams.len() - 1);
+    let current = &art.streams[index];
+    html! {
+        <>
+            <h3>{ "Univers ArtDmx" }</h3>
+            <table class="capture-table">
+                <tr><th>{ "Port-Address" }</th><th>{ "Émetteur" }</th><th>{ "Destination" }</th><th>{ "Paquets" }</th><th>{ "Débit" }</th><th>{ "Perdus" }</th><th>{ "Hors ordre" }</th><th>{ "Alertes" }</th></tr>
+                { for art.streams.iter().enumerate().map(|(i, s)| {
+                    let senders = art.senders(s.port_address).len();
+                    let alert = if senders > 1 { format!("⚠️ {} émetteurs : fusion HTP des deux premiers", senders) } else { String::new() };
+                    let onclick = {
+                        let stream = stream.clone();
+                        Callback::from(move |_: MouseEvent| stream.set(i))
+                    };
+                    html! {
+                        <tr class={classes!("capture-row", (i == index).then_some("active"))} {onclick}>
+                            <td><code>{ s.port_address.text() }</code></td>
+                            <td>{ s.source.to_string() }</td>
+                            <td>{ s.destination.to_string() }</td>
+                            <td>{ s.packets }</td>
+                            <td>{ format!("{:.1} /s", s.rate()).replace('.', ",") }</td>
+                            <td>{ s.lost }</td>
+                            <td>{ s.out_of_order }</td>
+                            <td>{ alert }</td>
+                        </tr>
+                    }
+                })}
+            </table>
+            <p class="sim-message">{ format!("{} depuis {} : {} adresses", current.port_address.text(), current.source, current.length) }</p>
+            <DmxGrid levels={current.levels.clone()} count={current.length} />
+            if !current.gaps.is_empty() {
+                <ul class="tc-events">
+                    { for current.gaps.iter().map(|g| html! {
+                        <li>{ format!("{} : attendu {}, reçu {}", seconds_text(g.time), g.expected, g.received) }</li>
+                    })}
+                </ul>
+            }
+        </>
+    }
+}
+
+fn cross_table(art: &ArtReport, sacn: &SacnReport, offset: u16) -> Html {
+    let rows = cross_check(art, sacn, offset);
+    if rows.is_empty() {
+        return html! {};
+    }
+    let elsewhere_hint = |elsewhere: &Option<u16>| elsewhere.map(|u| format!(" — ces niveaux sont ceux de sACN {} : convention de numérotation différente ?", u)).unwrap_or_default();
+    html! {
+        <>
+            <h3>{ "Recoupement Art-Net / sACN" }</h3>
+            <table class="capture-table">
+                <tr><th>{ "Art-Net" }</th><th>{ "sACN" }</th><th>{ "Constat" }</th></tr>
+                { for rows.iter().map(|row| {
+                    let (ok, text) = match &row.status {
+                        CrossStatus::Same(name) => (true, format!("✅ mêmes niveaux que {}", name)),
+                        CrossStatus::Different { slots, elsewhere } => (false, format!("⚠️ {} adresse(s) différentes{}", slots, elsewhere_hint(elsewhere))),
+                        CrossStatus::ArtNetOnly { elsewhere } => (elsewhere.is_none(), format!("Art-Net seulement{}", elsewhere_hint(elsewhere))),
+                        CrossStatus::SacnOnly => (true, "sACN seulement".to_string()),
+                    };
+                    html! {
+                        <tr class={classes!((!ok).then_some("capture-alert"))}>
+                            <td><code>{ row.port_address.map(|pa| pa.text()).unwrap_or_else(|| "—".to_string()) }</code></td>
+                            <td>{ row.sacn_universe.map(|u| u.to_string()).unwrap_or_else(|| "—".to_string()) }</td>
+                            <td>{ text }</td>
+                        </tr>
+                    }
+                })}
+            </table>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn port_address_matches_guide_examples() {
+        let zero = PortAddress::new(0, 0, 0).unwrap();
+        // Convention Eos : Art-Net 0:0:0 = sACN 1 ; l'autre convention n'a pas d'univers sACN 0
+        assert_eq!(zero.to_sacn(1), Some(1));
+        assert_eq!(zero.to_sacn(0), None);
+        assert_eq!(PortAddress::from_sacn(1, 1), Some(zero));
+        assert_eq!(PortAddress::from_sacn(1, 0).map(PortAddress::text), Some("0:0:1".to_string()));
+        assert_eq!(PortAddress::parse("0:1:0").unwrap().to_sacn(1), Some(17));
+        assert_eq!(PortAddress::from_sacn(300, 1).map(|p| (p.0, p.text())), Some((299, "1:2:11".to_string())));
+        assert_eq!(PortAddress::parse("32767").unwrap().text(), "127:15:15");
+        assert!(PortAddress::parse("128:0:0").is_err());
+        assert!(PortAddress::parse("0:16:0").is_err());
+    }
+
+    #[test]
+    fn decodes_art_dmx_bytes() {
+        let mut bytes = b"Art-Net\0".to_vec();
+        bytes.extend_from_slice(&[0x00, 0x50, 0x00, 0x0E, 0x05, 0x00, 0x12, 0x01, 0x00, 0x04, 255, 128, 0, 1]);
+        let expected = ArtPacket::Dmx { version: 14, sequence: 5, physical: 0, port_address: PortAddress(0x0112), data: vec![255, 128, 0, 1] };
+        assert_eq!(decode(&bytes), Ok(expected.clone()));
+        assert_eq!(expected.encode(), bytes);
+        // SubUni 0x12, Net 1 : Art-Net 1:1:2 = univers sACN 275
+        assert_eq!(PortAddress(0x0112).text(), "1:1:2");
+        assert_eq!(PortAddress(0x0112).to_sacn(1), Some(275));
+        // Longueur nulle, données tronquées, identifiant absent
+        let mut empty = bytes[..18].to_vec();
+        empty[16..18].copy_from_slice(&[0, 0]);
+        assert!(decode(&empty).is_err());
+        assert!(decode(&bytes[..bytes.len() - 1]).is_err());
+        assert!(decode(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn decodes_poll_reply_bytes() {
+        let mut bytes = vec![0u8; POLL_REPLY_LENGTH];
+        bytes[..8].copy_from_slice(b"Art-Net\0");
+        bytes[8..10].copy_from_slice(&[0x00, 0x21]);
+        bytes[10..14].copy_from_slice(&[10, 101, 2, 101]);
+        bytes[14..16].copy_from_slice(&[0x36, 0x19]);
+        bytes[16..18].copy_from_slice(&[0x01, 0x02]);
+        bytes[19] = 0x01; // Sub-Net 1
+        bytes[26..37].copy_from_slice(b"Node jardin");
+        bytes[172..174].copy_from_slice(&[0x00, 0x02]);
+        bytes[174..176].copy_from_slice(&[0x80, 0x80]);
+        bytes[182..184].copy_from_slice(&[0x80, 0x81]);
+        bytes[190..192].copy_from_slice(&[0x00, 0x01]);
+        bytes[201..207].copy_from_slice(&[0x00, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E]);
+        bytes[211] = 1;
+        let Ok(ArtPacket::PollReply(reply)) = decode(&bytes) else { panic!("ArtPollReply attendu") };
+        assert_eq!(reply.ip, Ipv4Addr::new(10, 101, 2, 101));
+        assert_eq!((reply.firmware, reply.short_name.as_str(), reply.bind_index), (0x0102, "Node jardin", 1));
+        assert_eq!(reply.mac, [0x00, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E]);
+        let outputs: Vec<_> = reply.ports.iter().map(|p| (p.number, p.output.map(PortAddress::text), p.sacn, p.transmitting)).collect();
+        assert_eq!(outputs, vec![(1, Some("0:1:0".to_string()), false, true), (2, Some("0:1:1".to_string()), true, true)]);
+        assert_eq!(decode(&ArtPacket::PollReply(reply.clone()).encode()), Ok(ArtPacket::PollReply(reply)));
+        // Réponse tronquée avant l'adresse MAC
+        assert!(decode(&bytes[..POLL_REPLY_MIN - 1]).is_err());
+    }
+}
@@ -0,0 +1,426 @@
+use std::net::Ipv4Addr;
+
+use gloo::file::callbacks::{read_as_bytes, FileReader};
+use gloo::file::File;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+// --- Captures réseau (pcap / pcapng) ---
+// Lecture hors ligne des fichiers Wireshark / tcpdump : on n'en garde que les datagrammes UDP sur IPv4,
+// seuls utiles pour sACN et Art-Net.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UdpDatagram {
+    /// Secondes depuis l'epoch Unix, telles qu'horodatées par la capture.
+    pub time: f64,
+    pub source: Ipv4Addr,
+    pub source_port: u16,
+    pub destination: Ipv4Addr,
+    pub destination_port: u16,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Capture {
+    pub format: &'static str,
+    /// Trames lues dans le fichier.
+    pub frames: usize,
+    /// Trames ignorées : pas IPv4, pas UDP, fragment ou lien inconnu.
+    pub skipped: usize,
+    pub datagrams: Vec<UdpDatagram>,
+}
+
+impl Capture {
+    /// Instant de la première trame, pour afficher des temps relatifs.
+    pub fn start_time(&self) -> f64 {
+        self.datagrams.first().map(|d| d.time).unwrap_or(0.0)
+    }
+
+    fn push_frame(&mut self, link: u32, time: f64, frame: &[u8]) {
+        self.frames += 1;
+        match ipv4_packet(link, frame).and_then(udp_datagram) {
+            Some((source, destination, source_port, destination_port, payload)) => self.datagrams.push(UdpDatagram {
+                time,
+                source,
+                source_port,
+                destination,
+                destination_port,
+                payload: payload.to_vec(),
+            }),
+            None => self.skipped += 1,
+        }
+    }
+}
+
+// Types de lien (LINKTYPE_*) reconnus
+const LINK_NULL: u32 = 0;
+const LINK_ETHERNET: u32 = 1;
+const LINK_RAW: u32 = 101;
+const LINK_LINUX_SLL: u32 = 113;
+const LINK_IPV4: u32 = 228;
+const LINK_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+
+/// Lecture d'entiers dans l'ordre d'octets du fichier.
+#[derive(Clone, Copy)]
+struct Reader<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+}
+
+impl Reader<'_> {
+    fn u16(&self, at: usize) -> Option<u16> {
+        let b: [u8; 2] = self.bytes.get(at..at + 2)?.try_into().ok()?;
+        Some(if self.big_endian { u16::from_be_bytes(b) } else { u16::from_le_bytes(b) })
+    }
+
+    fn u32(&self, at: usize) -> Option<u32> {
+        let b: [u8; 4] = self.bytes.get(at..at + 4)?.try_into().ok()?;
+        Some(if self.big_endian { u32::from_be_bytes(b) } else { u32::from_le_bytes(b) })
+    }
+}
+
+fn be16(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+/// Lit un fichier pcap ou pcapng ; le format est reconnu à son nombre magique.
+pub fn read_capture(bytes: &[u8]) -> Result<Capture, String> {
+    match bytes.get(0..4) {
+        Some([0x0A, 0x0D, 0x0D, 0x0A]) => read_pcapng(bytes),
+        Some(magic) => {
+            let (big_endian, nanos) = match magic {
+                [0xA1, 0xB2, 0xC3, 0xD4] => (true, false),
+                [0xD4, 0xC3, 0xB2, 0xA1] => (false, false),
+                [0xA1, 0xB2, 0x3C, 0x4D] => (true, true),
+                [0x4D, 0x3C, 0xB2, 0xA1] => (false, true),
+                _ => return Err("Format inconnu : ni pcap ni pcapng".to_string()),
+            };
+            read_pcap(Reader { bytes, big_endian }, nanos)
+        }
+        None => Err("Fichier vide ou tronqué".to_string()),
+    }
+}
+
+fn read_pcap(r: Reader, nanos: bool) -> Result<Capture, String> {
+    let link = r.u32(20).ok_or("En-tête pcap tronqué")?;
+    let mut capture = Capture { format: "pcap", ..Capture::default() };
+    let mut at = 24;
+    while at < r.bytes.len() {
+        let (Some(seconds), Some(fraction), Some(length)) = (r.u32(at), r.u32(at + 4), r.u32(at + 8)) else {
+            return Err(format!("Enregistrement tronqué à l'octet {}", at));
+        };
+        let end = (at + 16).checked_add(length as usize);
+        let data = end.and_then(|end| r.bytes.get(at + 16..end)).ok_or_else(|| format!("Trame tronquée à l'octet {}", at))?;
+        let time = seconds as f64 + fraction as f64 / if nanos { 1e9 } else { 1e6 };
+        capture.push_frame(link, time, data);
+        at += 16 + length as usize;
+    }
+    Ok(capture)
+}
+
+fn read_pcapng(bytes: &[u8]) -> Result<Capture, String> {
+    let mut capture = Capture { format: "pcapng", ..Capture::default() };
+    // Par interface : type de lien et unités de temps par seconde
+    let mut interfaces: Vec<(u32, f64)> = Vec::new();
+    let mut r = Reader { bytes, big_endian: false };
+    let mut at = 0;
+    while at + 12 <= bytes.len() {
+        if bytes[at..at + 4] == [0x0A, 0x0D, 0x0D, 0x0A] {
+            // Section Header Block : son nombre magique fixe l'ordre des octets de la section
+            r.big_endian = bytes.get(at + 8..at + 12) == Some(&[0x1A, 0x2B, 0x3C, 0x4D][..]);
+            interfaces.clear();
+        }
+        let kind = r.u32(at).ok_or("Bloc tronqué")?;
+        let length = r.u32(at + 4).ok_or("Bloc tronqué")? as usize;
+        if length < 12 || at.checked_add(length).is_none_or(|end| end > bytes.len()) {
+            return Err(format!("Bloc de longueur invalide ({}) à l'octet {}", length, at));
+        }
+        let body = Reader { bytes: &bytes[at + 8..at + length - 4], big_endian: r.big_endian };
+        match kind {
+            // Interface Description Block
+            1 => {
+                let link = body.u16(0).ok_or("IDB tronqué")? as u32;
+                interfaces.push((link, interface_resolution(body)));
+            }
+            // Enhanced Packet Block
+            6 => {
+                let (Some(interface), Some(high), Some(low), Some(captured)) = (body.u32(0), body.u32(4), body.u32(8), body.u32(12)) else {
+                    return Err(format!("EPB tronqué à l'octet {}", at));
+                };
+                let &(link, units) = interfaces.get(interface as usize).ok_or_else(|| format!("Interface {} non décrite", interface))?;
+                let data = (captured as usize).checked_add(20).and_then(|end| body.bytes.get(20..end)).ok_or_else(|| format!("EPB tronqué à l'octet {}", at))?;
+                let time = (((high as u64) << 32) | low as u64) as f64 / units;
+                capture.push_frame(link, time, data);
+            }
+            // Simple Packet Block : interface 0, sans horodatage
+            3 => {
+                let &(link, _) = interfaces.first().ok_or("Interface 0 non décrite")?;
+                let original = body.u32(0).ok_or("SPB tronqué")? as usize;
+                let end = original.checked_add(4).ok_or("SPB tronqué")?.min(body.bytes.len());
+                let data = body.bytes.get(4..end).ok_or("SPB tronqué")?;
+                capture.push_frame(link, 0.0, data);
+            }
+            _ => {}
+        }
+        at += length;
+    }
+    Ok(capture)
+}
+
+/// Option if_tsresol d'un IDB : 10^-n (ou 2^-n si le bit de poids fort est mis), microseconde par défaut.
+fn interface_resolution(body: Reader) -> f64 {
+    let mut at = 8;
+    while let (Some(code), Some(length)) = (body.u16(at), body.u16(at + 2)) {
+        if code == 0 {
+            break;
+        }
+        if code == 9 {
+            if let Some(&value) = body.bytes.get(at + 4) {
+                let exponent = (value & 0x7F) as i32;
+                return if value & 0x80 != 0 { 2f64.powi(exponent) } else { 10f64.powi(exponent) };
+            }
+        }
+        at += 4 + (length as usize).div_ceil(4) * 4;
+    }
+    1e6
+}
+
+/// Retire l'en-tête de lien ; `None` si la trame ne porte pas d'IPv4.
+fn ipv4_packet(link: u32, frame: &[u8]) -> Option<&[u8]> {
+    match link {
+        LINK_ETHERNET => {
+            let mut at = 12;
+            let mut ethertype = be16(frame, at)?;
+            while ethertype == ETHERTYPE_VLAN {
+                at += 4;
+                ethertype = be16(frame, at)?;
+            }
+            (ethertype == ETHERTYPE_IPV4).then(|| frame.get(at + 2..)).flatten()
+        }
+        LINK_NULL => {
+            // Famille d'adresse dans l'ordre d'octets de la machine de capture ; AF_INET = 2
+            let family = frame.get(0..4)?;
+            (family == [2, 0, 0, 0] || family == [0, 0, 0, 2]).then(|| &frame[4..])
+        }
+        LINK_RAW | LINK_IPV4 => Some(frame),
+        LINK_LINUX_SLL => (be16(frame, 14)? == ETHERTYPE_IPV4).then(|| frame.get(16..)).flatten(),
+        LINK_LINUX_SLL2 => (be16(frame, 0)? == ETHERTYPE_IPV4).then(|| frame.get(20..)).flatten(),
+        _ => None,
+    }
+}
+
+type UdpParts<'a> = (Ipv4Addr, Ipv4Addr, u16, u16, &'a [u8]);
+
+fn udp_datagram(ip: &[u8]) -> Option<UdpParts<'_>> {
+    if ip.first()? >> 4 != 4 || *ip.get(9)? != 17 {
+        return None;
+    }
+    // Fragment suivant : pas d'en-tête UDP
+    if be16(ip, 6)? & 0x1FFF != 0 {
+        return None;
+    }
+    let header = ((ip[0] & 0x0F) as usize) * 4;
+    // IHL inférieur à 5 : en-tête invalide ; paquet tronqué : en-tête IP incomplet
+    if header < 20 || ip.len() < header {
+        return None;
+    }
+    let total = (be16(ip, 2)? as usize).min(ip.len());
+    let address = |at: usize| -> Option<Ipv4Addr> { Some(Ipv4Addr::from(<[u8; 4]>::try_from(ip.get(at..at + 4)?).ok()?)) };
+    let (source, destination) = (address(12)?, address(16)?);
+    let udp = ip.get(header..total)?;
+    if udp.len() < 8 {
+        return None;
+    }
+    let length = (be16(udp, 4)? as usize).clamp(8, udp.len());
+    Some((source, destination, be16(udp, 0)?, be16(udp, 2)?, udp.get(8..length)?))
+}
+
+/// Écrit des datagrammes en pcap Ethernet, pour la capture de démonstration.
+pub fn write_pcap(datagrams: &[UdpDatagram]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&0xA1B2C3D4u32.to_le_bytes());
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&4u16.to_le_bytes());
+    out.extend_from_slice(&[0; 8]);
+    out.extend_from_slice(&65535u32.to_le_bytes());
+    out.extend_from_slice(&LINK_ETHERNET.to_le_bytes());
+    for d in datagrams {
+        let mut frame = Vec::new();
+        let dst = d.destination.octets();
+        // Multicast : 01:00:5E suivi des 23 bits de poids faible de l'adresse IP
+        if d.destination.is_multicast() {
+            frame.extend_from_slice(&[0x01, 0x00, 0x5E, dst[1] & 0x7F, dst[2], dst[3]]);
+        } else {
+            frame.extend_from_slice(&[0x02, 0x00, dst[0], dst[1], dst[2], dst[3]]);
+        }
+        let src = d.source.octets();
+        frame.extend_from_slice(&[0x02, 0x00, src[0], src[1], src[2], src[3]]);
+        frame.extend_from_slice(&ETHERTYPE_IPV4.to_be_bytes());
+        let udp_length = 8 + d.payload.len() as u16;
+        let mut ip = vec![0x45, 0, 0, 0, 0, 0, 0x40, 0, 64, 17, 0, 0];
+        ip[2..4].copy_from_slice(&(20 + udp_length).to_be_bytes());
+        ip.extend_from_slice(&src);
+        ip.extend_from_slice(&dst);
+        let checksum = !ip.chunks(2).fold(0u32, |sum, w| {
+            let sum = sum + u16::from_be_bytes([w[0], w[1]]) as u32;
+            (sum & 0xFFFF) + (sum >> 16)
+        }) as u16;
+        ip[10..12].copy_from_slice(&checksum.to_be_bytes());
+        frame.extend_from_slice(&ip);
+        frame.extend_from_slice(&d.source_port.to_be_bytes());
+        frame.extend_from_slice(&d.destination_port.to_be_bytes());
+        frame.extend_from_slice(&udp_length.to_be_bytes());
+        frame.extend_from_slice(&[0, 0]);
+        frame.extend_from_slice(&d.payload);
+
+        out.extend_from_slice(&(d.time.trunc() as u32).to_le_bytes());
+        out.extend_from_slice(&((d.time.fract() * 1e6).round() as u32).to_le_bytes());
+        out.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        out.extend_from_slice(&frame);
+    }
+    out
+}
+
+/// Capture de démonstration : trois secondes de trafic sACN et Art-Net avec leurs défauts typiques.
+pub fn demo_capture() -> Vec<u8> {
+    let mut datagrams = crate::sacn::demo_datagrams();
+    datagrams.extend(crate::artnet::demo_datagrams());
+    datagrams.sort_by(|a, b| a.time.total_cmp(&b.time));
+    write_pcap(&datagrams)
+}
+
+// --- Composant Chargement de capture ---
+
+#[derive(Properties, PartialEq)]
+pub struct CaptureLoaderProps {
+    /// Reçoit le nom du fichier et la capture lue (ou l'erreur).
+    pub on_load: Callback<(String, Result<Capture, String>)>,
+}
+
+#[function_component(CaptureLoader)]
+pub fn capture_loader(props: &CaptureLoaderProps) -> Html {
+    // Le lecteur doit vivre jusqu'à la fin de la lecture
+    let reader = use_mut_ref(|| None::<FileReader>);
+
+    let on_file = {
+        let reader = reader.clone();
+        let on_load = props.on_load.clone();
+        Callback::from(move |e: Event| {
+            let Some(input) = e.target_dyn_into::<HtmlInputElement>() else { return };
+            let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+            let file = File::from(file);
+            let name = file.name();
+            let on_load = on_load.clone();
+            *reader.borrow_mut() = Some(read_as_bytes(&file, move |result| {
+                on_load.emit((name, result.map_err(|e| format!("Lecture impossible : {}", e)).and_then(|bytes| read_capture(&bytes))));
+            }));
+        })
+    };
+    let on_demo = {
+        let on_load = props.on_load.clone();
+        Callback::from(move |_: MouseEvent| on_load.emit(("démonstration.pcap".to_string(), read_capture(&demo_capture()))))
+    };
+
+    html! {
+        <div class="sim-input capture-loader">
+            <input type="file" accept=".pcap,.pcapng,.cap" onchange={on_file} />
+            <button class="back-btn" onclick={on_demo}>{ "🧪 Capture de démonstration" }</button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datagram() -> UdpDatagram {
+        UdpDatagram {
+            time: 1.5,
+            source: Ipv4Addr::new(10, 0, 0, 1),
+            source_port: 5568,
+            destination: Ipv4Addr::new(239, 255, 0, 1),
+            destination_port: 5568,
+            payload: b"ASC-E1.17".to_vec(),
+        }
+    }
+
+    #[test]
+    fn reads_written_pcap() {
+        let capture = read_capture(&write_pcap(&[datagram()])).unwrap();
+        assert_eq!((capture.frames, capture.skipped), (1, 0));
+        assert_eq!(capture.datagrams, vec![datagram()]);
+    }
+
+    #[test]
+    fn truncated_ip_packets_are_skipped() {
+        let pcap = write_pcap(&[datagram()]);
+        // Trame Ethernet seule : 14 octets d'en-tête de lien, puis IP + UDP + données
+        let ip = &pcap[24 + 16 + 14..];
+        for len in 0..28 {
+            assert_eq!(udp_datagram(&ip[..len]), None, "paquet IP de {} octets", len);
+        }
+        // En-tête UDP incomplet malgré une longueur totale IP cohérente
+        let mut short = ip[..26].to_vec();
+        short[2..4].copy_from_slice(&26u16.to_be_bytes());
+        assert_eq!(udp_datagram(&short), None);
+        // IHL annonçant 60 octets d'en-tête dans un paquet de 37
+        let mut options = ip.to_vec();
+        options[0] = 0x4F;
+        assert_eq!(udp_datagram(&options), None);
+        assert!(udp_datagram(ip).is_some());
+    }
+
+    #[test]
+    fn malformed_ip_header_is_rejected() {
+        let pcap = write_pcap(&[datagram()]);
+        let ip = &pcap[24 + 16 + 14..];
+        // IHL de 0 à 4 : l'en-tête IP serait lu comme en-tête UDP
+        for ihl in 0..5 {
+            let mut malformed = ip.to_vec();
+            malformed[0] = 0x40 | ihl;
+            assert_eq!(udp_datagram(&malformed), None, "IHL {}", ihl);
+        }
+    }
+
+    #[test]
+    fn truncated_files_do_not_panic() {
+        let pcap = write_pcap(&[datagram(), datagram()]);
+        for len in 0..pcap.len() {
+            let _ = read_capture(&pcap[..len]);
+        }
+        // Longueur de trame démesurée
+        let mut huge = pcap.clone();
+        huge[24 + 8..24 + 12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(read_capture(&huge).is_err());
+    }
+
+    #[test]
+    fn pcapng_simple_packet_with_huge_length() {
+        let mut file = Vec::new();
+        // Section Header Block
+        file.extend_from_slice(&[0x0A, 0x0D, 0x0D, 0x0A]);
+        file.extend_from_slice(&28u32.to_le_bytes());
+        file.extend_from_slice(&0x1A2B3C4Du32.to_le_bytes());
+        file.extend_from_slice(&[1, 0, 0, 0]);
+        file.extend_from_slice(&u64::MAX.to_le_bytes());
+        file.extend_from_slice(&28u32.to_le_bytes());
+        // Interface Description Block : Ethernet
+        file.extend_from_slice(&1u32.to_le_bytes());
+        file.extend_from_slice(&20u32.to_le_bytes());
+        file.extend_from_slice(&[1, 0, 0, 0]);
+        file.extend_from_slice(&0u32.to_le_bytes());
+        file.extend_from_slice(&20u32.to_le_bytes());
+        // Simple Packet Block annonçant 4 Gio pour 4 octets de données
+        file.extend_from_slice(&3u32.to_le_bytes());
+        file.extend_from_slice(&20u32.to_le_bytes());
+        file.extend_from_slice(&u32::MAX.to_le_bytes());
+        file.extend_from_slice(&[0xAA; 4]);
+        file.extend_from_slice(&20u32.to_le_bytes());
+        let capture = read_capture(&file).unwrap();
+        assert_eq!((capture.frames, capture.skipped), (1, 1));
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::cues::format_cue_number;
+use crate::simulator::{ParamKind, Simulator, SimulatorView};
+use crate::{Level, CHALLENGES_JSON};
+
+// --- Défis ---
+// Le défi est réussi quand l'état du simulateur correspond à la cible,
+// quelle que soit la suite de touches employée pour y arriver.
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Hint {
+    pub text: String,
+    /// Fiche du guide à ouvrir depuis l'indice.
+    pub command: Option<String>,
+}
+
+fn intensity() -> ParamKind {
+    ParamKind::Intensity
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Target {
+    /// Niveau sur scène.
+    Live {
+        channels: Vec<u32>,
+        #[serde(default = "intensity")]
+        param: ParamKind,
+        level: f32,
+    },
+    /// Niveau complet à la cue, tracking compris.
+    Cue {
+        cue: f32,
+        channels: Vec<u32>,
+        #[serde(default = "intensity")]
+        param: ParamKind,
+        level: f32,
+    },
+    /// La cue contient (ou non) un move pour le canal.
+    Stored { cue: f32, channel: u32, stored: bool },
+    CueExists { cue: f32, exists: bool },
+}
+
+fn same_level(value: Option<f32>, level: f32) -> bool {
+    value.is_some_and(|v| (v - level).abs() < 0.5)
+}
+
+impl Target {
+    pub fn describe(&self) -> String {
+        let channels = |channels: &[u32]| channels.iter().map(u32::to_string).collect::<Vec<_>>().join(", ");
+        match self {
+            Target::Live { channels: chs, param, level } => {
+                format!("Sur scène : {} {} à {}%", channels(chs), param.label(), level)
+            }
+            Target::Cue { cue, channels: chs, param, level } => {
+                format!("Cue {} : {} {} à {}%", format_cue_number(*cue), channels(chs), param.label(), level)
+            }
+            Target::Stored { cue, channel, stored } => format!(
+                "Cue {} : canal {} {}",
+                format_cue_number(*cue),
+                channel,
+                if *stored { "enregistré dans la cue" } else { "non enregistré dans la cue" }
+            ),
+            Target::CueExists { cue, exists } => {
+                format!("Cue {} {}", format_cue_number(*cue), if *exists { "présente" } else { "absente" })
+            }
+        }
+    }
+
+    pub fn check(&self, sim: &Simulator) -> bool {
+        match self {
+            Target::Live { channels, param, level } => channels.iter().all(|ch| same_level(sim.value(*ch, *param), *level)),
+            Target::Cue { cue, channels, param, level } => {
+                if sim.cues.get(*cue).is_none() {
+                    return false;
+                }
+                let state = sim.cues.state_at(&sim.home_levels(), *cue, true);
+                channels.iter().all(|ch| same_level(state.get(ch).and_then(|p| p.get(param)).copied(), *level))
+            }
+            Target::Stored { cue, channel, stored } => {
+                sim.cues.get(*cue).is_some_and(|c| c.moves.contains_key(channel) == *stored)
+            }
+            Target::CueExists { cue, exists } => sim.cues.get(*cue).is_some() == *exists,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Challenge {
+    pub id: String,
+    pub level: Level,
+    pub title: String,
+    pub goal: String,
+    /// Séquences tapées sur un simulateur vierge pour obtenir l'état de départ.
+    pub setup: Vec<String>,
+    pub targets: Vec<Target>,
+    pub hints: Vec<Hint>,
+}
+
+impl Challenge {
+    pub fn start_state(&self) -> Simulator {
+        let mut sim = Simulator::default();
+        for line in &self.setup {
+            sim.type_notation(line);
+        }
+        // L'état de départ n'est pas annulable.
+        sim.history.clear();
+        sim.message = Some(format!("Défi : {}", self.goal));
+        sim
+    }
+
+    pub fn is_solved(&self, sim: &Simulator) -> bool {
+        self.targets.iter().all(|t| t.check(sim))
+    }
+}
+
+pub fn load_challenges() -> Vec<Challenge> {
+    serde_json::from_str(CHALLENGES_JSON).unwrap_or_else(|e| {
+        web_sys::console::error_1(&format!("Erreur de parsing de CHALLENGES_JSON: {:?}", e).into());
+        Vec::new()
+    })
+}
+
+// --- Composant Panneau Défis ---
+#[derive(Properties, PartialEq)]
+pub struct ChallengesPanelProps {
+    pub completed: BTreeSet<String>,
+    pub on_complete: Callback<String>,
+    pub on_show_command: Callback<String>,
+}
+
+#[function_component(ChallengesPanel)]
+pub fn challenges_panel(props: &ChallengesPanelProps) -> Html {
+    let challenges = use_memo((), |_| load_challenges());
+    let current = use_state(|| None::<Challenge>);
+    let simulator = use_state(Simulator::default);
+    let checked = use_state(|| false);
+    let shown_hints = use_state(|| 0usize);
+
+    let Some(challenge) = (*current).clone() else {
+        return html! {
+            <div class="challenges-panel">
+                { for Level::ALL.iter().map(|level| html! {
+                    <div class="challenge-level">
+                        <h3>{ level.label() }</h3>
+                        <ul class="command-list">
+                            { for challenges.iter().filter(|c| c.level == *level).map(|c| {
+                                let open = {
+                                    let current = current.clone();
+                                    let simulator = simulator.clone();
+                                    let checked = checked.clone();
+                                    let shown_hints = shown_hints.clone();
+                                    let c = c.clone();
+                                    Callback::from(move |_: MouseEvent| {
+                                        simulator.set(c.start_state());
+                                        checked.set(false);
+                                        shown_hints.set(0);
+                                        current.set(Some(c.clone()));
+                                    })
+                                };
+                                let done = props.completed.contains(&c.id);
+                                html! {
+                                    <li class="command-item" onclick={open}>
+                                        <strong>{ if done { "✅ " } else { "⬜ " } }{ &c.title }</strong>
+                                        <div class="undo-command">{ &c.goal }</div>
+                                    </li>
+                                }
+                            })}
+                        </ul>
+                    </div>
+                })}
+            </div>
+        };
+    };
+
+    let solved = challenge.is_solved(&simulator);
+
+    let on_change = {
+        let simulator = simulator.clone();
+        let checked = checked.clone();
+        Callback::from(move |sim: Simulator| {
+            simulator.set(sim);
+            checked.set(false);
+        })
+    };
+
+    let check = {
+        let checked = checked.clone();
+        let on_complete = props.on_complete.clone();
+        let id = challenge.id.clone();
+        Callback::from(move |_: MouseEvent| {
+            checked.set(true);
+            if solved {
+                on_complete.emit(id.clone());
+            }
+        })
+    };
+
+    let restart = {
+        let simulator = simulator.clone();
+        let checked = checked.clone();
+        let challenge = challenge.clone();
+        Callback::from(move |_: MouseEvent| {
+            simulator.set(challenge.start_state());
+            checked.set(false);
+        })
+    };
+
+    let more_hint = {
+        let shown_hints = shown_hints.clone();
+        Callback::from(move |_: MouseEvent| shown_hints.set(*shown_hints + 1))
+    };
+
+    let back = {
+        let current = current.clone();
+        Callback::from(move |_: MouseEvent| current.set(None))
+    };
+
+    html! {
+        <div class="challenges-panel">
+            <div class="form-actions">
+                <button class="back-btn" onclick={back}>{ "← Défis" }</button>
+                <span class="sim-message">{ challenge.level.label() }</span>
+            </div>
+            <div class="flashcard">
+                <p class="flashcard-prompt">{ &challenge.title }</p>
+                <div class="flashcard-front">{ &challenge.goal }</div>
+                if *checked {
+                    <ul class="challenge-targets">
+                        { for challenge.targets.iter().map(|t| html! {
+                            <li>{ if t.check(&simulator) { "✅ " } else { "❌ " } }{ t.describe() }</li>
+                        })}
+                    </ul>
+                    <p class={classes!("drill-verdict", solved.then_some("ok"))}>
+                        { if solved { "🎉 Défi réussi !" } else { "Pas encore : l'état ne correspond pas à la cible." } }
+                    </p>
+                }
+                <ul class="challenge-hints">
+                    { for challenge.hints.iter().take(*shown_hints).map(|hint| html! {
+                        <li>
+                            { "💡 " }{ &hint.text }
+                            if let Some(command) = &hint.command {
+                                { " " }
+                                <button class="back-btn" onclick={{
+                                    let on_show_command = props.on_show_command.clone();
+                                    let command = command.clone();
+                                    Callback::from(move |_: MouseEvent| on_show_command.emit(command.clone()))
+                                }}>{ "Voir la fiche →" }</button>
+                            }
+                        </li>
+                    })}
+                </ul>
+                <div class="action-buttons">
+                    <button class="back-btn" onclick={check}>{ "Vérifier" }</button>
+                    if *shown_hints < challenge.hints.len() {
+                        <button class="back-btn" onclick={more_hint}>{ "Indice" }</button>
+                    }
+                    <button class="back-btn" onclick={restart}>{ "Recommencer" }</button>
+                </div>
+            </div>
+            <SimulatorView simulator={(*simulator).clone()} on_change={on_change} />
+        </div>
+    }
+}
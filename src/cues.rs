@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::simulator::ParamKind;
+
+// --- Cues en mode Tracking ---
+// Comme sur la console, une cue ne stocke que les changements (« moves ») par rapport
+// à l'état atteint par les cues précédentes ; le reste est « tracké » depuis ces cues.
+
+/// Valeurs par canal et par paramètre.
+pub type Levels = BTreeMap<u32, BTreeMap<ParamKind, f32>>;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Cue {
+    pub number: f32,
+    pub label: String,
+    pub moves: Levels,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct CueList {
+    pub cues: Vec<Cue>,
+}
+
+pub fn format_cue_number(number: f32) -> String {
+    if number.fract() == 0.0 { format!("{:.0}", number) } else { format!("{}", number) }
+}
+
+fn same_number(a: f32, b: f32) -> bool {
+    (a - b).abs() < 0.001
+}
+
+fn apply(levels: &mut Levels, moves: &Levels) {
+    for (ch, params) in moves {
+        let target = levels.entry(*ch).or_default();
+        for (kind, value) in params {
+            target.insert(*kind, *value);
+        }
+    }
+}
+
+/// Valeurs de `live` qui diffèrent de `reference`.
+pub fn diff(live: &Levels, reference: &Levels) -> Levels {
+    let mut moves = Levels::new();
+    for (ch, params) in live {
+        for (kind, value) in params {
+            let before = reference.get(ch).and_then(|p| p.get(kind));
+            if before != Some(value) {
+                moves.entry(*ch).or_default().insert(*kind, *value);
+            }
+        }
+    }
+    moves
+}
+
+impl CueList {
+    pub fn get(&self, number: f32) -> Option<&Cue> {
+        self.cues.iter().find(|c| same_number(c.number, number))
+    }
+
+    /// État complet à la fin de la cue `number` (ou juste avant si `inclusive` est faux).
+    pub fn state_at(&self, base: &Levels, number: f32, inclusive: bool) -> Levels {
+        let mut levels = base.clone();
+        for cue in &self.cues {
+            let before = cue.number < number && !same_number(cue.number, number);
+            if before || (inclusive && same_number(cue.number, number)) {
+                apply(&mut levels, &cue.moves);
+            }
+        }
+        levels
+    }
+
+    /// Enregistre (ou remplace) la cue avec les seules valeurs qui changent par rapport à la cue précédente.
+    /// Seuls les canaux `allowed` (ceux de la partition) sont écrits ; les autres gardent leur contenu.
+    /// Renvoie vrai si une cue existante a été remplacée.
+    pub fn record(&mut self, number: f32, live: &Levels, base: &Levels, allowed: impl Fn(u32) -> bool) -> bool {
+        let previous = self.state_at(base, number, false);
+        let mut moves = diff(live, &previous);
+        moves.retain(|ch, _| allowed(*ch));
+        if let Some(cue) = self.cues.iter_mut().find(|c| same_number(c.number, number)) {
+            for (ch, params) in &cue.moves {
+                if !allowed(*ch) {
+                    moves.insert(*ch, params.clone());
+                }
+            }
+            cue.moves = moves;
+            return true;
+        }
+        let index = self.cues.iter().position(|c| c.number > number).unwrap_or(self.cues.len());
+        self.cues.insert(index, Cue { number, label: String::new(), moves });
+        false
+    }
+
+    /// `[Q Only]` : les valeurs `changed` par l'enregistrement de la cue ne trackent pas dans la suivante,
+    /// qui reçoit un move ramenant la valeur d'avant (sauf si elle en a déjà un).
+    pub fn stop_tracking(&mut self, number: f32, changed: &Levels, base: &Levels) {
+        let previous = self.state_at(base, number, false);
+        let Some(next) = self.cues.iter_mut().find(|c| c.number > number && !same_number(c.number, number)) else { return };
+        for (ch, params) in changed {
+            for kind in params.keys() {
+                if let Some(value) = previous.get(ch).and_then(|p| p.get(kind)) {
+                    next.moves.entry(*ch).or_default().entry(*kind).or_insert(*value);
+                }
+            }
+        }
+    }
+
+    pub fn delete(&mut self, number: f32) -> bool {
+        let before = self.cues.len();
+        self.cues.retain(|c| !same_number(c.number, number));
+        self.cues.len() != before
+    }
+
+    /// Numéro proposé par `[Record][Enter]` : l'entier suivant la dernière cue.
+    pub fn next_number(&self) -> f32 {
+        self.cues.last().map(|c| c.number.floor() + 1.0).unwrap_or(1.0)
+    }
+}
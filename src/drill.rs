@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::flashcards::StudyProgress;
+use crate::notation::{canonicalize, render_keys, tokenize, KeyToken};
+use crate::shortcuts::key_for_event;
+use crate::InitialData;
+
+// --- Exercice de frappe ---
+// Une consigne (« met le canal 1 à 50% ») et la ou les séquences attendues, tirées du guide.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DrillTask {
+    pub command_id: String,
+    pub prompt: String,
+    pub answers: Vec<Vec<KeyToken>>,
+}
+
+/// Consignes tirées des exemples « séquence → effet », ou à défaut de l'action et des syntaxes.
+pub fn build_tasks(data: &InitialData) -> Vec<DrillTask> {
+    let mut tasks = Vec::new();
+    for cmd in &data.commands {
+        let mut from_examples = false;
+        for example in cmd.examples.iter().flatten() {
+            if let Some((sequence, meaning)) = example.split_once('→') {
+                let keys = tokenize(sequence);
+                if keys.len() >= 2 {
+                    tasks.push(DrillTask { command_id: cmd.id.clone(), prompt: capitalize(meaning.trim()), answers: vec![keys] });
+                    from_examples = true;
+                }
+            }
+        }
+        if !from_examples {
+            let answers: Vec<Vec<KeyToken>> = cmd
+                .syntaxes
+                .iter()
+                .flatten()
+                .map(|s| tokenize(s))
+                // Les syntaxes génériques (`[Help][touche]`) ne se tapent pas telles quelles.
+                .filter(|keys| keys.len() >= 2 && !keys.iter().any(|k| k.label.starts_with(char::is_lowercase)))
+                .collect();
+            if !answers.is_empty() {
+                tasks.push(DrillTask { command_id: cmd.id.clone(), prompt: cmd.action.clone(), answers });
+            }
+        }
+    }
+    tasks
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
+
+// --- Correction touche par touche ---
+#[derive(Clone, Debug, PartialEq)]
+pub enum TokenMark {
+    Correct(KeyToken),
+    Wrong { got: KeyToken, expected: KeyToken },
+    Missing(KeyToken),
+    Extra(KeyToken),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grade {
+    pub marks: Vec<TokenMark>,
+    pub correct: bool,
+    /// Part des touches attendues correctement placées (0..1).
+    pub score: f32,
+    pub expected: Vec<KeyToken>,
+}
+
+fn same_key(a: &KeyToken, b: &KeyToken) -> bool {
+    // La nature de la touche est ignorée : au clavier, `{Wait}` et `[Wait]` se tapent pareil.
+    a.is(&b.label)
+}
+
+/// Aligne la saisie sur une réponse (distance d'édition) et marque chaque touche.
+fn align(input: &[KeyToken], expected: &[KeyToken]) -> (usize, Vec<TokenMark>) {
+    let (n, m) = (input.len(), expected.len());
+    let mut cost = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in cost.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in cost[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let sub = cost[i - 1][j - 1] + usize::from(!same_key(&input[i - 1], &expected[j - 1]));
+            cost[i][j] = sub.min(cost[i - 1][j] + 1).min(cost[i][j - 1] + 1);
+        }
+    }
+    let mut marks = Vec::new();
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let same = same_key(&input[i - 1], &expected[j - 1]);
+            if cost[i][j] == cost[i - 1][j - 1] + usize::from(!same) {
+                marks.push(if same {
+                    TokenMark::Correct(input[i - 1].clone())
+                } else {
+                    TokenMark::Wrong { got: input[i - 1].clone(), expected: expected[j - 1].clone() }
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && cost[i][j] == cost[i - 1][j] + 1 {
+            marks.push(TokenMark::Extra(input[i - 1].clone()));
+            i -= 1;
+        } else {
+            marks.push(TokenMark::Missing(expected[j - 1].clone()));
+            j -= 1;
+        }
+    }
+    marks.reverse();
+    (cost[n][m], marks)
+}
+
+/// Compare la saisie à la plus proche des réponses acceptées, sous forme canonique.
+pub fn grade(input: &[KeyToken], answers: &[Vec<KeyToken>]) -> Grade {
+    let input = canonicalize(input);
+    answers
+        .iter()
+        .map(|answer| {
+            let expected = canonicalize(answer);
+            let (distance, marks) = align(&input, &expected);
+            let hits = marks.iter().filter(|m| matches!(m, TokenMark::Correct(_))).count();
+            let score = if expected.is_empty() { 0.0 } else { hits as f32 / expected.len().max(input.len()) as f32 };
+            (distance, Grade { marks, correct: distance == 0, score, expected })
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, grade)| grade)
+        .unwrap_or(Grade { marks: Vec::new(), correct: false, score: 0.0, expected: Vec::new() })
+}
+
+// --- Statistiques ---
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DrillRecord {
+    pub attempts: u32,
+    pub correct: u32,
+    /// Temps cumulé des réponses justes, en millisecondes.
+    pub total_ms: f64,
+    pub best_ms: Option<f64>,
+}
+
+impl DrillRecord {
+    pub fn record(&mut self, correct: bool, elapsed_ms: f64) {
+        self.attempts += 1;
+        if correct {
+            self.correct += 1;
+            self.total_ms += elapsed_ms;
+            self.best_ms = Some(self.best_ms.map_or(elapsed_ms, |b| b.min(elapsed_ms)));
+        }
+    }
+
+    pub fn accuracy(&self) -> f32 {
+        if self.attempts == 0 { 0.0 } else { self.correct as f32 / self.attempts as f32 }
+    }
+
+    pub fn average_ms(&self) -> Option<f64> {
+        (self.correct > 0).then(|| self.total_ms / self.correct as f64)
+    }
+}
+
+/// Seuil sous lequel une commande est considérée comme un point faible.
+pub const WEAK_ACCURACY: f32 = 0.8;
+
+/// Commandes à retravailler : précision insuffisante, les pires en premier.
+pub fn weak_spots(records: &BTreeMap<String, DrillRecord>) -> Vec<(&String, &DrillRecord)> {
+    let mut weak: Vec<(&String, &DrillRecord)> = records.iter().filter(|(_, r)| r.accuracy() < WEAK_ACCURACY).collect();
+    weak.sort_by(|a, b| a.1.accuracy().total_cmp(&b.1.accuracy()));
+    weak
+}
+
+// --- Composant Panneau Exercice ---
+const DRILL_DIGITS: [&str; 12] = ["7", "8", "9", "4", "5", "6", "1", "2", "3", "0", ".", "+"];
+
+#[derive(Properties, PartialEq)]
+pub struct DrillPanelProps {
+    pub data: InitialData,
+    pub progress: StudyProgress,
+    pub on_progress: Callback<StudyProgress>,
+}
+
+#[function_component(DrillPanel)]
+pub fn drill_panel(props: &DrillPanelProps) -> Html {
+    let tasks = use_memo(props.data.clone(), build_tasks);
+    let weak_only = use_state(|| false);
+    let current = use_state(|| 0usize);
+    let input = use_state(Vec::<KeyToken>::new);
+    let result = use_state(|| None::<Grade>);
+    let started = use_state(js_sys::Date::now);
+
+    // Toutes les touches utilisées par le guide, pour le pavé à l'écran.
+    let palette = use_memo(tasks.clone(), |tasks| {
+        let labels: BTreeSet<String> = tasks
+            .iter()
+            .flat_map(|t| t.answers.iter().flatten())
+            .filter(|k| !k.is_number())
+            .map(|k| k.label.clone())
+            .collect();
+        labels.into_iter().collect::<Vec<_>>()
+    });
+
+    let weak: Vec<String> = weak_spots(&props.progress.drill).into_iter().map(|(id, _)| id.clone()).collect();
+    let pool: Vec<&DrillTask> = tasks.iter().filter(|t| !*weak_only || weak.contains(&t.command_id)).collect();
+
+    let next_task = {
+        let current = current.clone();
+        let input = input.clone();
+        let result = result.clone();
+        let started = started.clone();
+        let len = pool.len();
+        Callback::from(move |_: MouseEvent| {
+            if len > 0 {
+                current.set((js_sys::Math::random() * len as f64) as usize % len);
+            }
+            input.set(Vec::new());
+            result.set(None);
+            started.set(js_sys::Date::now());
+        })
+    };
+
+    let Some(task) = pool.get(*current % pool.len().max(1)).map(|t| (*t).clone()) else {
+        return html! {
+            <div class="drill-panel">
+                <p>{ "Aucun point faible pour le moment 👍" }</p>
+                <button class="back-btn" onclick={{ let w = weak_only.clone(); Callback::from(move |_| w.set(false)) }}>{ "Tous les exercices" }</button>
+            </div>
+        };
+    };
+
+    let press = {
+        let input = input.clone();
+        let result = result.clone();
+        move |key: KeyToken| {
+            let input = input.clone();
+            let result = result.clone();
+            Callback::from(move |_: MouseEvent| {
+                if result.is_none() {
+                    let mut keys = (*input).clone();
+                    keys.push(key.clone());
+                    input.set(keys);
+                }
+            })
+        }
+    };
+
+    let submit = {
+        let input = input.clone();
+        let result = result.clone();
+        let started = started.clone();
+        let progress = props.progress.clone();
+        let on_progress = props.on_progress.clone();
+        let task = task.clone();
+        move || {
+            if result.is_some() || input.is_empty() {
+                return;
+            }
+            let mut keys = (*input).clone();
+            if !keys.last().is_some_and(|k| k.is("Enter")) && task.answers.iter().any(|a| a.last().is_some_and(|k| k.is("Enter"))) {
+                keys.push(KeyToken::hard("Enter"));
+            }
+            let g = grade(&keys, &task.answers);
+            let mut progress = progress.clone();
+            progress.drill.entry(task.command_id.clone()).or_default().record(g.correct, js_sys::Date::now() - *started);
+            on_progress.emit(progress);
+            input.set(keys);
+            result.set(Some(g));
+        }
+    };
+
+    let on_keydown = {
+        let input = input.clone();
+        let result = result.clone();
+        let submit = submit.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if result.is_some() {
+                return;
+            }
+            let key = e.key();
+            if key == "Backspace" {
+                e.prevent_default();
+                let mut keys = (*input).clone();
+                keys.pop();
+                input.set(keys);
+            } else if key == "Enter" && e.shift_key() {
+                e.prevent_default();
+                submit();
+            } else if let Some(token) = key_for_event(&e) {
+                e.prevent_default();
+                let mut keys = (*input).clone();
+                keys.push(token);
+                input.set(keys);
+            }
+        })
+    };
+
+    let on_submit = {
+        let submit = submit.clone();
+        Callback::from(move |_: MouseEvent| submit())
+    };
+
+    let toggle_weak = {
+        let weak_only = weak_only.clone();
+        Callback::from(move |_: MouseEvent| weak_only.set(!*weak_only))
+    };
+
+    let record = props.progress.drill.get(&task.command_id).cloned().unwrap_or_default();
+
+    html! {
+        <div class="drill-panel">
+            <div class="form-actions">
+                <button class={classes!("back-btn", (*weak_only).then_some("active"))} onclick={toggle_weak}>
+                    { format!("🎯 Points faibles ({})", weak.len()) }
+                </button>
+                <button class="back-btn" onclick={next_task.clone()}>{ "⏭ Autre consigne" }</button>
+            </div>
+            <div class="flashcard">
+                <p class="flashcard-prompt">{ "Tapez la séquence de touches :" }</p>
+                <div class="flashcard-front">{ &task.prompt }</div>
+                <div class="sim-command-line drill-input" tabindex="0" onkeydown={on_keydown}>
+                    if let Some(g) = &*result {
+                        { for g.marks.iter().map(render_mark) }
+                    } else {
+                        { render_keys(&input) }
+                        <span class="drill-caret">{ "▌" }</span>
+                    }
+                </div>
+                if let Some(g) = &*result {
+                    <p class={classes!("drill-verdict", g.correct.then_some("ok"))}>
+                        { if g.correct { "✅ Correct !".to_string() } else { format!("❌ {:.0}% des touches justes", g.score * 100.0) } }
+                    </p>
+                    if !g.correct {
+                        <p>{ "Réponse attendue : " }{ render_keys(&g.expected) }</p>
+                    }
+                    <button class="back-btn" onclick={next_task}>{ "Consigne suivante →" }</button>
+                } else {
+                    <p class="sim-message">{ "Clavier : raccourcis Eos offline (aide-mémoire dans 🧰 Outils), ⌫ = effacer, Maj+Entrée = valider." }</p>
+                    <div class="sim-keypad">
+                        { for DRILL_DIGITS.iter().map(|d| html! {
+                            <button class="key key-hard" onclick={press(KeyToken::hard(d))}>{ *d }</button>
+                        })}
+                    </div>
+                    <div class="sim-param-keys">
+                        { for palette.iter().map(|label| html! {
+                            <button class="key key-hard" onclick={press(KeyToken::hard(label))}>{ label }</button>
+                        })}
+                    </div>
+                    <div class="action-buttons">
+                        <button class="back-btn" onclick={{ let i = input.clone(); Callback::from(move |_| i.set(Vec::new())) }}>{ "Effacer" }</button>
+                        <button class="back-btn" onclick={on_submit}>{ "Valider" }</button>
+                    </div>
+                }
+                <p class="deck-stats">
+                    { format!("Cette commande : {} essai(s), {:.0}% de réussite", record.attempts, record.accuracy() * 100.0) }
+                    { record.average_ms().map(|ms| format!(", {:.1} s en moyenne", ms / 1000.0)).unwrap_or_default() }
+                    { record.best_ms.map(|ms| format!(", record {:.1} s", ms / 1000.0)).unwrap_or_default() }
+                </p>
+            </div>
+            if !weak.is_empty() {
+                <div class="tips">
+                    <h4>{ "Points faibles" }</h4>
+                    <ul>
+                        { for weak_spots(&props.progress.drill).into_iter().take(5).map(|(id, r)| {
+                            let action = props.data.commands.iter().find(|c| &c.id == id).map(|c| c.action.clone()).unwrap_or_else(|| id.clone());
+                            html! { <li>{ format!("{} — {:.0}% sur {} essai(s)", action, r.accuracy() * 100.0, r.attempts) }</li> }
+                        })}
+                    </ul>
+                </div>
+            }
+            <p class="sim-message">{ format!("{} consigne(s) disponible(s)", pool.len()) }</p>
+        </div>
+    }
+}
+
+fn render_mark(mark: &TokenMark) -> Html {
+    match mark {
+        TokenMark::Correct(k) => html! { <span class={classes!(k.kind.css_class(), "mark-ok")}>{ k.to_notation() }</span> },
+        TokenMark::Wrong { got, expected } => html! {
+            <span class={classes!(got.kind.css_class(), "mark-wrong")} title={format!("attendu : {}", expected.to_notation())}>{ got.to_notation() }</span>
+        },
+        TokenMark::Missing(k) => html! { <span class={classes!(k.kind.css_class(), "mark-missing")}>{ k.to_notation() }</span> },
+        TokenMark::Extra(k) => html! { <span class={classes!(k.kind.css_class(), "mark-extra")}>{ k.to_notation() }</span> },
+    }
+}
@@ -0,0 +1,544 @@
+use std::f64::consts::PI;
+
+use gloo::timers::callback::Interval;
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::simulator::{ParamKind, Simulator, PARAM_KINDS};
+
+// --- Moteur d'effets ---
+// Le calcul est une fonction pure du temps : pour un même effet, les mêmes canaux
+// et le même instant `t` (en secondes depuis le lancement), la valeur est toujours identique.
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EffectKind {
+    /// Chenillard : les groupes de canaux s'allument l'un après l'autre.
+    Step,
+    /// Oscille entre deux valeurs absolues (`low` / `high`).
+    Absolute,
+    /// Oscille autour de la valeur courante de chaque canal.
+    Relative,
+}
+
+pub const EFFECT_KINDS: [EffectKind; 3] = [EffectKind::Step, EffectKind::Absolute, EffectKind::Relative];
+
+impl EffectKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            EffectKind::Step => "Step",
+            EffectKind::Absolute => "Absolute",
+            EffectKind::Relative => "Relative",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    Triangle,
+    Square,
+    RampUp,
+    RampDown,
+}
+
+pub const WAVEFORMS: [Waveform; 5] = [
+    Waveform::Sine,
+    Waveform::Triangle,
+    Waveform::Square,
+    Waveform::RampUp,
+    Waveform::RampDown,
+];
+
+impl Waveform {
+    pub fn label(self) -> &'static str {
+        match self {
+            Waveform::Sine => "Sinus",
+            Waveform::Triangle => "Triangle",
+            Waveform::Square => "Carré",
+            Waveform::RampUp => "Rampe montante",
+            Waveform::RampDown => "Rampe descendante",
+        }
+    }
+
+    /// Valeur unipolaire (0..1) pour une phase 0..1. Sinus, triangle et rampe montante démarrent à 0 ;
+    /// carré et rampe descendante démarrent à 1.
+    pub fn at(self, phase: f64) -> f64 {
+        let p = phase.rem_euclid(1.0);
+        match self {
+            Waveform::Sine => 0.5 - 0.5 * (2.0 * PI * p).cos(),
+            Waveform::Triangle => {
+                if p < 0.5 { 2.0 * p } else { 2.0 - 2.0 * p }
+            }
+            Waveform::Square => {
+                if p < 0.5 { 1.0 } else { 0.0 }
+            }
+            Waveform::RampUp => p,
+            Waveform::RampDown => 1.0 - p,
+        }
+    }
+}
+
+/// Mode d'entrée / de sortie de l'effet.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transition {
+    /// L'effet démarre (ou s'arrête) d'un coup.
+    Immediate,
+    /// La taille de l'effet croît (ou décroît) sur la durée de transition.
+    Size,
+    /// Les canaux entrent (ou sortent) l'un après l'autre, dans l'ordre de leur décalage.
+    Cascade,
+}
+
+pub const TRANSITIONS: [Transition; 3] = [Transition::Immediate, Transition::Size, Transition::Cascade];
+
+impl Transition {
+    pub fn label(self) -> &'static str {
+        match self {
+            Transition::Immediate => "Immédiat",
+            Transition::Size => "Size",
+            Transition::Cascade => "Cascade",
+        }
+    }
+
+    /// Part de l'effet appliquée (0..1), `elapsed` secondes après le début de la transition.
+    fn factor(self, elapsed: f64, duration: f64, offset: f64) -> f64 {
+        if duration <= 0.0 {
+            return 1.0;
+        }
+        match self {
+            Transition::Immediate => 1.0,
+            Transition::Size => (elapsed / duration).clamp(0.0, 1.0),
+            Transition::Cascade => {
+                if elapsed >= offset * duration { 1.0 } else { 0.0 }
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Effect {
+    pub id: u32,
+    pub label: String,
+    pub kind: EffectKind,
+    pub waveform: Waveform,
+    pub parameter: ParamKind,
+    /// Durée d'un cycle en secondes à rate 100.
+    pub cycle_time: f64,
+    /// Vitesse en pourcentage (200 = deux fois plus rapide).
+    pub rate: f64,
+    /// Amplitude des effets relatifs, en points de pourcentage.
+    pub size: f32,
+    pub low: f32,
+    pub high: f32,
+    /// Nombre de canaux consécutifs partageant la même phase.
+    pub grouping: u32,
+    /// Nombre de phases distinctes ; 0 = répartition sur tous les groupes.
+    pub offset: u32,
+    pub entry: Transition,
+    pub entry_time: f64,
+    pub exit: Transition,
+    pub exit_time: f64,
+}
+
+impl Effect {
+    pub fn new(id: u32) -> Self {
+        Effect {
+            id,
+            label: format!("Effect {}", id),
+            kind: EffectKind::Relative,
+            waveform: Waveform::Sine,
+            parameter: ParamKind::Intensity,
+            cycle_time: 2.0,
+            rate: 100.0,
+            size: 50.0,
+            low: 0.0,
+            high: 100.0,
+            grouping: 1,
+            offset: 0,
+            entry: Transition::Immediate,
+            entry_time: 0.0,
+            exit: Transition::Immediate,
+            exit_time: 0.0,
+        }
+    }
+
+    /// Durée réelle d'un cycle, rate appliqué.
+    pub fn period(&self) -> f64 {
+        if self.rate <= 0.0 {
+            return f64::INFINITY;
+        }
+        self.cycle_time.max(0.01) * 100.0 / self.rate
+    }
+
+    fn group_count(&self, count: usize) -> usize {
+        count.div_ceil(self.grouping.max(1) as usize).max(1)
+    }
+
+    /// Décalage de phase (0..1) du canal d'indice `index` parmi `count` canaux.
+    pub fn phase_offset(&self, index: usize, count: usize) -> f64 {
+        let group = index / self.grouping.max(1) as usize;
+        let phases = match self.offset {
+            0 => self.group_count(count),
+            n => n as usize,
+        };
+        (group % phases) as f64 / phases as f64
+    }
+
+    /// Valeur de l'effet seul, sans les transitions d'entrée/sortie.
+    fn raw_value(&self, index: usize, count: usize, base: f32, t: f64) -> f32 {
+        let cycle = t / self.period();
+        match self.kind {
+            EffectKind::Step => {
+                // Chaque groupe est un pas ; un seul pas est actif à la fois.
+                let steps = self.group_count(count);
+                let current = ((cycle.rem_euclid(1.0)) * steps as f64).floor() as usize;
+                let group = index / self.grouping.max(1) as usize;
+                if group % steps == current { self.high } else { self.low }
+            }
+            EffectKind::Absolute => {
+                let w = self.waveform.at(cycle - self.phase_offset(index, count)) as f32;
+                self.low + (self.high - self.low) * w
+            }
+            EffectKind::Relative => {
+                let w = self.waveform.at(cycle - self.phase_offset(index, count)) as f32;
+                base + self.size * (w - 0.5)
+            }
+        }
+    }
+
+    /// Valeur d'un canal à l'instant `t`. `stop` = instant où l'effet a été arrêté.
+    pub fn value_at(&self, index: usize, count: usize, base: f32, t: f64, stop: Option<f64>) -> f32 {
+        if t < 0.0 {
+            return base;
+        }
+        let offset = self.phase_offset(index, count);
+        let mut factor = self.entry.factor(t, self.entry_time, offset);
+        if let Some(stop) = stop.filter(|s| t >= *s) {
+            let remaining = 1.0 - self.exit.factor(t - stop, self.exit_time, offset);
+            factor = factor.min(if self.exit_time <= 0.0 { 0.0 } else { remaining });
+        }
+        let value = base + (self.raw_value(index, count, base, t) - base) * factor as f32;
+        value.clamp(0.0, 100.0)
+    }
+
+    /// Valeurs de tous les canaux `(canal, valeur de base)` à l'instant `t`.
+    pub fn sample(&self, bases: &[(u32, f32)], t: f64, stop: Option<f64>) -> Vec<(u32, f32)> {
+        bases
+            .iter()
+            .enumerate()
+            .map(|(i, (ch, base))| (*ch, self.value_at(i, bases.len(), *base, t, stop)))
+            .collect()
+    }
+
+    /// Courbe de chaque canal sur `duration` secondes, échantillonnée `samples + 1` fois.
+    pub fn timeline(&self, bases: &[(u32, f32)], duration: f64, samples: usize) -> Vec<(u32, Vec<(f64, f32)>)> {
+        let samples = samples.max(1);
+        bases
+            .iter()
+            .enumerate()
+            .map(|(i, (ch, base))| {
+                let points = (0..=samples)
+                    .map(|s| {
+                        let t = duration * s as f64 / samples as f64;
+                        (t, self.value_at(i, bases.len(), *base, t, None))
+                    })
+                    .collect();
+                (*ch, points)
+            })
+            .collect()
+    }
+}
+
+/// Canaux sélectionnés dans le simulateur qui possèdent le paramètre de l'effet, avec leur valeur courante.
+pub fn effect_bases(sim: &Simulator, effect: &Effect) -> Vec<(u32, f32)> {
+    sim.selection
+        .iter()
+        .filter_map(|ch| sim.value(*ch, effect.parameter).map(|v| (*ch, v)))
+        .collect()
+}
+
+// --- Composant Panneau Effets ---
+const TICK_MS: u32 = 50;
+const PLOT_WIDTH: f64 = 600.0;
+const PLOT_ROW: f64 = 40.0;
+
+#[derive(Properties, PartialEq)]
+pub struct EffectsPanelProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(EffectsPanel)]
+pub fn effects_panel(props: &EffectsPanelProps) -> Html {
+    let selected = use_state(|| 0usize);
+    // Horodatage (ms) du lancement et instant d'arrêt (s depuis le lancement).
+    let started = use_state(|| None::<f64>);
+    let stop_at = use_state(|| None::<f64>);
+    let redraw = use_force_update();
+
+    {
+        let running = started.is_some();
+        use_effect_with(running, move |running| {
+            let interval = running.then(|| Interval::new(TICK_MS, move || redraw.force_update()));
+            move || drop(interval)
+        });
+    }
+    let clock = started.map(|start| (js_sys::Date::now() - start) / 1000.0).unwrap_or(0.0);
+
+    let sim = &props.simulator;
+    let Some(effect) = sim.effects.get(*selected).cloned() else {
+        return html! { <p>{ "Aucun effet." }</p> };
+    };
+
+    // Modifie l'effet courant et renvoie le nouvel état du simulateur.
+    let update = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        let index = *selected;
+        move |apply: fn(&mut Effect, String)| {
+            let simulator = simulator.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |e: Event| {
+                let value = e
+                    .target_dyn_into::<HtmlInputElement>()
+                    .map(|i| i.value())
+                    .or_else(|| e.target_dyn_into::<HtmlSelectElement>().map(|s| s.value()))
+                    .unwrap_or_default();
+                let mut sim = simulator.clone();
+                if let Some(effect) = sim.effects.get_mut(index) {
+                    apply(effect, value);
+                }
+                on_change.emit(sim);
+            })
+        }
+    };
+
+    let add_effect = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        let selected = selected.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut sim = simulator.clone();
+            let id = sim.effects.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+            sim.effects.push(Effect::new(id));
+            selected.set(sim.effects.len() - 1);
+            on_change.emit(sim);
+        })
+    };
+
+    let on_select_effect = {
+        let selected = selected.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                selected.set(select.value().parse().unwrap_or(0));
+            }
+        })
+    };
+
+    let toggle_run = {
+        let started = started.clone();
+        let stop_at = stop_at.clone();
+        Callback::from(move |_: MouseEvent| {
+            if started.is_some() && stop_at.is_none() {
+                stop_at.set(Some(clock));
+            } else {
+                stop_at.set(None);
+                started.set(Some(js_sys::Date::now()));
+            }
+        })
+    };
+
+    let bases = effect_bases(sim, &effect);
+    let values = effect.sample(&bases, clock, *stop_at);
+    let period = effect.period().min(60.0);
+    let duration = (effect.entry_time + period).max(2.0 * period);
+    let timeline = effect.timeline(&bases, duration, 120);
+    let cursor = (clock % duration) / duration * PLOT_WIDTH;
+    let playing = started.is_some() && stop_at.is_none();
+
+    html! {
+        <div class="effects-panel">
+            <div class="form-actions">
+                <select onchange={on_select_effect}>
+                    { for sim.effects.iter().enumerate().map(|(i, e)| html! {
+                        <option value={i.to_string()} selected={i == *selected}>{ &e.label }</option>
+                    })}
+                </select>
+                <button class="back-btn" onclick={add_effect}>{ "➕ Nouvel effet" }</button>
+                <button class="back-btn" onclick={toggle_run}>{ if playing { "■ Arrêter" } else { "▶ Lancer" } }</button>
+            </div>
+            <div class="effect-form">
+                <div class="form-group">
+                    <label>{ "Type" }</label>
+                    <select onchange={update(|e, v| e.kind = EFFECT_KINDS.into_iter().find(|k| k.label() == v).unwrap_or(e.kind))}>
+                        { for EFFECT_KINDS.iter().map(|k| html! { <option selected={*k == effect.kind}>{ k.label() }</option> }) }
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label>{ "Forme d'onde" }</label>
+                    <select onchange={update(|e, v| e.waveform = WAVEFORMS.into_iter().find(|w| w.label() == v).unwrap_or(e.waveform))}>
+                        { for WAVEFORMS.iter().map(|w| html! { <option selected={*w == effect.waveform}>{ w.label() }</option> }) }
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label>{ "Paramètre" }</label>
+                    <select onchange={update(|e, v| e.parameter = PARAM_KINDS.into_iter().find(|p| p.label() == v).unwrap_or(e.parameter))}>
+                        { for PARAM_KINDS.iter().map(|p| html! { <option selected={*p == effect.parameter}>{ p.label() }</option> }) }
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label>{ "Durée de cycle (s)" }</label>
+                    <input type="number" step="0.1" value={effect.cycle_time.to_string()}
+                        onchange={update(|e, v| e.cycle_time = parse_number(&v))} />
+                </div>
+                <div class="form-group">
+                    <label>{ "Rate (%)" }</label>
+                    <input type="number" value={effect.rate.to_string()} onchange={update(|e, v| e.rate = parse_number(&v))} />
+                </div>
+                if effect.kind == EffectKind::Relative {
+                    <div class="form-group">
+                        <label>{ "Size (%)" }</label>
+                        <input type="number" value={effect.size.to_string()} onchange={update(|e, v| e.size = parse_number(&v) as f32)} />
+                    </div>
+                } else {
+                    <div class="form-group">
+                        <label>{ "Niveau bas / haut (%)" }</label>
+                        <input type="number" value={effect.low.to_string()} onchange={update(|e, v| e.low = parse_number(&v) as f32)} />
+                        <input type="number" value={effect.high.to_string()} onchange={update(|e, v| e.high = parse_number(&v) as f32)} />
+                    </div>
+                }
+                <div class="form-group">
+                    <label>{ "Grouping / Offset" }</label>
+                    <input type="number" min="1" value={effect.grouping.to_string()} onchange={update(|e, v| e.grouping = v.parse().unwrap_or(1).max(1))} />
+                    <input type="number" min="0" value={effect.offset.to_string()} onchange={update(|e, v| e.offset = v.parse().unwrap_or(0))} />
+                </div>
+                <div class="form-group">
+                    <label>{ "Entrée (mode / durée s)" }</label>
+                    <select onchange={update(|e, v| e.entry = TRANSITIONS.into_iter().find(|t| t.label() == v).unwrap_or(e.entry))}>
+                        { for TRANSITIONS.iter().map(|t| html! { <option selected={*t == effect.entry}>{ t.label() }</option> }) }
+                    </select>
+                    <input type="number" step="0.1" value={effect.entry_time.to_string()} onchange={update(|e, v| e.entry_time = parse_number(&v))} />
+                </div>
+                <div class="form-group">
+                    <label>{ "Sortie (mode / durée s)" }</label>
+                    <select onchange={update(|e, v| e.exit = TRANSITIONS.into_iter().find(|t| t.label() == v).unwrap_or(e.exit))}>
+                        { for TRANSITIONS.iter().map(|t| html! { <option selected={*t == effect.exit}>{ t.label() }</option> }) }
+                    </select>
+                    <input type="number" step="0.1" value={effect.exit_time.to_string()} onchange={update(|e, v| e.exit_time = parse_number(&v))} />
+                </div>
+            </div>
+            if bases.is_empty() {
+                <p class="sim-message">{ format!("Sélectionnez des canaux possédant le paramètre {} dans l'onglet Canaux.", effect.parameter.label()) }</p>
+            } else {
+                <div class="effect-preview">
+                    { for values.iter().map(|(ch, v)| {
+                        let light = (v / 100.0 * 255.0).round() as u8;
+                        html! {
+                            <div class="effect-cell" style={format!("background: rgb({0},{0},{1});", light, light / 2)}>
+                                <span>{ ch }</span>
+                                <span>{ format!("{:.0}", v) }</span>
+                            </div>
+                        }
+                    })}
+                </div>
+                <svg class="effect-timeline" viewBox={format!("0 0 {} {}", PLOT_WIDTH, PLOT_ROW * timeline.len() as f64)}>
+                    { for timeline.iter().enumerate().map(|(row, (ch, points))| {
+                        let top = row as f64 * PLOT_ROW;
+                        let polyline = points
+                            .iter()
+                            .map(|(t, v)| format!("{:.1},{:.1}", t / duration * PLOT_WIDTH, top + PLOT_ROW - 4.0 - (*v as f64 / 100.0) * (PLOT_ROW - 8.0)))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        html! {
+                            <g>
+                                <line x1="0" x2={PLOT_WIDTH.to_string()} y1={(top + PLOT_ROW).to_string()} y2={(top + PLOT_ROW).to_string()} class="effect-axis" />
+                                <text x="2" y={(top + 12.0).to_string()} class="effect-label">{ ch }</text>
+                                <polyline points={polyline} class="effect-curve" />
+                            </g>
+                        }
+                    })}
+                    <line x1={cursor.to_string()} x2={cursor.to_string()} y1="0" y2={(PLOT_ROW * timeline.len() as f64).to_string()} class="effect-cursor" />
+                </svg>
+                <p class="sim-message">{ format!("t = {:.1} s — période {:.2} s", clock, effect.period()) }</p>
+            }
+        </div>
+    }
+}
+
+fn parse_number(value: &str) -> f64 {
+    value.replace(',', ".").parse::<f64>().unwrap_or(0.0).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn waveforms_at_quarter_phases() {
+        let expected = [
+            (Waveform::Sine, [0.0, 0.5, 1.0, 0.5]),
+            (Waveform::Triangle, [0.0, 0.5, 1.0, 0.5]),
+            (Waveform::Square, [1.0, 1.0, 0.0, 0.0]),
+            (Waveform::RampUp, [0.0, 0.25, 0.5, 0.75]),
+            (Waveform::RampDown, [1.0, 0.75, 0.5, 0.25]),
+        ];
+        for (waveform, values) in expected {
+            for (phase, value) in [0.0, 0.25, 0.5, 0.75].into_iter().zip(values) {
+                assert!(close(waveform.at(phase), value), "{:?} à {} : {}", waveform, phase, waveform.at(phase));
+            }
+            // La phase boucle : 1.25 et -0.75 valent 0.25.
+            assert!(close(waveform.at(1.25), waveform.at(0.25)));
+            assert!(close(waveform.at(-0.75), waveform.at(0.25)));
+        }
+    }
+
+    #[test]
+    fn rate_scales_period() {
+        let mut effect = Effect::new(1);
+        effect.cycle_time = 4.0;
+        assert!(close(effect.period(), 4.0));
+        effect.rate = 200.0;
+        assert!(close(effect.period(), 2.0));
+        effect.rate = 50.0;
+        assert!(close(effect.period(), 8.0));
+        effect.rate = 0.0;
+        assert!(effect.period().is_infinite());
+    }
+
+    #[test]
+    fn offset_spreads_phases() {
+        let mut effect = Effect::new(1);
+        // Répartition sur tous les canaux : 4 canaux → 0, 1/4, 1/2, 3/4.
+        let phases: Vec<f64> = (0..4).map(|i| effect.phase_offset(i, 4)).collect();
+        assert_eq!(phases, vec![0.0, 0.25, 0.5, 0.75]);
+        // Deux phases seulement : les canaux alternent.
+        effect.offset = 2;
+        let phases: Vec<f64> = (0..4).map(|i| effect.phase_offset(i, 4)).collect();
+        assert_eq!(phases, vec![0.0, 0.5, 0.0, 0.5]);
+        // Groupes de deux canaux consécutifs sur la même phase.
+        effect.offset = 0;
+        effect.grouping = 2;
+        let phases: Vec<f64> = (0..4).map(|i| effect.phase_offset(i, 4)).collect();
+        assert_eq!(phases, vec![0.0, 0.0, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn channels_follow_their_phase() {
+        let mut effect = Effect::new(1);
+        effect.kind = EffectKind::Absolute;
+        effect.waveform = Waveform::RampUp;
+        effect.cycle_time = 1.0;
+        let bases = [(1, 0.0), (2, 0.0), (3, 0.0), (4, 0.0)];
+        // À t = 0, chaque canal est en retard de son décalage : 0, 0.75, 0.5, 0.25 du cycle.
+        let values: Vec<f32> = effect.sample(&bases, 0.0, None).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(values, vec![0.0, 75.0, 50.0, 25.0]);
+        // Rate 200 : à t = 0.25 s, le premier canal a fait la moitié du cycle.
+        effect.rate = 200.0;
+        assert_eq!(effect.sample(&bases, 0.25, None)[0], (1, 50.0));
+    }
+}
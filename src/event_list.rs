@@ -0,0 +1,715 @@
+use gloo::storage::{LocalStorage, Storage};
+use gloo::timers::callback::Timeout;
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::cues::format_cue_number;
+use crate::macros::{compile, MacroRun};
+use crate::quiz::{csv_field, data_url};
+use crate::simulator::Simulator;
+use crate::timecode::{Timecode, TimecodeRate, TIMECODE_RATES};
+
+// --- Listes d'événements time code ---
+// Comme sur Eos : chaque événement associe un time code à une action (cue, macro, submaster).
+// La liste suit le time code reçu (chase) ; si le signal disparaît, elle continue seule pendant
+// `freewheel_frames` images puis s'arrête jusqu'au retour du signal.
+
+pub const EVENT_LISTS_STORAGE_KEY: &str = "eos_guide_event_lists";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum EventAction {
+    Cue { list: u32, cue: f32 },
+    Macro(u32),
+    Sub { number: u32, on: bool },
+}
+
+/// Libellés des types d'action, tels qu'ils apparaissent dans le CSV.
+const ACTION_KINDS: [&str; 4] = ["Cue", "Macro", "Sub On", "Sub Off"];
+
+impl EventAction {
+    fn kind(&self) -> &'static str {
+        match self {
+            EventAction::Cue { .. } => ACTION_KINDS[0],
+            EventAction::Macro(_) => ACTION_KINDS[1],
+            EventAction::Sub { on: true, .. } => ACTION_KINDS[2],
+            EventAction::Sub { on: false, .. } => ACTION_KINDS[3],
+        }
+    }
+
+    /// Cible en texte : `liste/cue` pour une cue, le numéro sinon.
+    fn target(&self) -> String {
+        match self {
+            EventAction::Cue { list, cue } => format!("{}/{}", list, format_cue_number(*cue)),
+            EventAction::Macro(n) | EventAction::Sub { number: n, .. } => n.to_string(),
+        }
+    }
+
+    fn parse(kind: &str, target: &str) -> Result<Self, String> {
+        let target = target.trim();
+        let number = |text: &str| text.trim().parse::<u32>().map_err(|_| format!("« {} » n'est pas un numéro", text.trim()));
+        match kind.trim().to_lowercase().as_str() {
+            "cue" => {
+                let (list, cue) = target.split_once('/').unwrap_or(("1", target));
+                let cue = cue.trim().replace(',', ".").parse::<f32>().map_err(|_| format!("« {} » n'est pas un numéro de cue", cue.trim()))?;
+                Ok(EventAction::Cue { list: number(list)?, cue })
+            }
+            "macro" => Ok(EventAction::Macro(number(target)?)),
+            "sub on" => Ok(EventAction::Sub { number: number(target)?, on: true }),
+            "sub off" => Ok(EventAction::Sub { number: number(target)?, on: false }),
+            other => Err(format!("action « {} » inconnue (Cue, Macro, Sub On, Sub Off)", other)),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            EventAction::Cue { list, cue } => format!("Cue {}/{}", list, format_cue_number(*cue)),
+            EventAction::Macro(n) => format!("Macro {}", n),
+            EventAction::Sub { number, on } => format!("Sub {} {}", number, if *on { "On" } else { "Off" }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TimecodeEvent {
+    /// Rang d'image à la cadence de la liste.
+    pub frame: i64,
+    pub action: EventAction,
+    pub enabled: bool,
+    #[serde(default)]
+    pub label: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct EventList {
+    pub number: u32,
+    pub label: String,
+    pub rate: TimecodeRate,
+    /// Images jouées sans signal avant l'arrêt de la liste.
+    pub freewheel_frames: u32,
+    pub events: Vec<TimecodeEvent>,
+}
+
+impl EventList {
+    pub fn new(number: u32) -> Self {
+        EventList { number, label: String::new(), rate: TimecodeRate::Fps30, freewheel_frames: 30, events: Vec::new() }
+    }
+
+    pub fn timecode(&self, frame: i64) -> Timecode {
+        Timecode::from_frames(frame, self.rate)
+    }
+
+    pub fn sort(&mut self) {
+        self.events.sort_by_key(|e| e.frame);
+    }
+
+    /// Change de cadence en gardant l'instant réel de chaque événement.
+    pub fn set_rate(&mut self, rate: TimecodeRate) {
+        let from = self.rate;
+        for event in &mut self.events {
+            event.frame = Timecode::from_frames(event.frame, from).convert(rate).to_frames();
+        }
+        self.rate = rate;
+    }
+
+    /// Export CSV : `temps;action;cible;actif;libellé`, une ligne par événement.
+    /// Le temps est entre guillemets : en drop-frame il contient lui-même un `;` (`HH:MM:SS;FF`).
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec!["temps;action;cible;actif;libellé".to_string()];
+        for event in &self.events {
+            lines.push(
+                [
+                    csv_field(&self.timecode(event.frame).text()),
+                    event.action.kind().to_string(),
+                    event.action.target(),
+                    (if event.enabled { "oui" } else { "non" }).to_string(),
+                    csv_field(&event.label),
+                ]
+                .join(";"),
+            );
+        }
+        lines.join("\n")
+    }
+
+    /// Remplace les événements par ceux d'un CSV ; `;` ou `,` comme séparateur, en-tête facultatif.
+    pub fn import_csv(&mut self, text: &str) -> Result<usize, Vec<String>> {
+        let mut events = Vec::new();
+        let mut errors = Vec::new();
+        for (i, line) in text.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let fields = split_csv_line(line);
+            if i == 0 && fields.first().is_some_and(|f| f.trim().eq_ignore_ascii_case("temps")) {
+                continue;
+            }
+            let row = || -> Result<TimecodeEvent, String> {
+                let [time, kind, target, rest @ ..] = fields.as_slice() else {
+                    return Err("attendu : temps;action;cible;actif;libellé".to_string());
+                };
+                let enabled = !matches!(rest.first().map(|s| s.trim().to_lowercase()).as_deref(), Some("non" | "no" | "0" | "false"));
+                Ok(TimecodeEvent {
+                    frame: Timecode::parse(time, self.rate)?.to_frames(),
+                    action: EventAction::parse(kind, target)?,
+                    enabled,
+                    label: rest.get(1).cloned().unwrap_or_default(),
+                })
+            };
+            match row() {
+                Ok(event) => events.push(event),
+                Err(e) => errors.push(format!("ligne {} : {}", i + 1, e)),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        self.events = events;
+        self.sort();
+        Ok(self.events.len())
+    }
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    // Le séparateur se cherche hors guillemets : un temps drop-frame cité contient un `;`.
+    let unquoted: String = line.split('"').step_by(2).collect();
+    let separator = if unquoted.contains(';') { ';' } else { ',' };
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+pub fn default_event_lists() -> Vec<EventList> {
+    let mut list = EventList::new(1);
+    list.label = "Démo".to_string();
+    let at = |seconds: i64| seconds * 30;
+    list.events = vec![
+        TimecodeEvent { frame: at(5), action: EventAction::Macro(1), enabled: true, label: "Clignotement".to_string() },
+        TimecodeEvent { frame: at(10), action: EventAction::Cue { list: 1, cue: 1.0 }, enabled: true, label: "Ouverture".to_string() },
+        TimecodeEvent { frame: at(15), action: EventAction::Sub { number: 1, on: true }, enabled: false, label: "Public".to_string() },
+    ];
+    vec![list]
+}
+
+// --- Lecture ---
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncState {
+    /// Pas encore de time code reçu.
+    Waiting,
+    Chasing,
+    Freewheel,
+    /// Signal perdu au-delà du freewheel : la liste attend son retour.
+    Stopped,
+}
+
+impl SyncState {
+    fn label(self) -> &'static str {
+        match self {
+            SyncState::Waiting => "⚪ En attente de time code",
+            SyncState::Chasing => "🟢 Suit le time code",
+            SyncState::Freewheel => "🟡 Freewheel : signal absent",
+            SyncState::Stopped => "🔴 Arrêtée : signal perdu",
+        }
+    }
+}
+
+/// Horloge simulée d'une source time code et position de la liste qui la suit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Playback {
+    /// Position de la source, en images (fractionnaires pour avancer au rythme réel).
+    pub source: f64,
+    pub running: bool,
+    /// Faux pendant une coupure simulée du signal.
+    pub signal: bool,
+    pub position: Option<f64>,
+    pub freewheel_left: f64,
+    pub state: SyncState,
+    pub log: Vec<String>,
+}
+
+/// Écart au-delà duquel un changement de time code est un saut, pas une lecture : une seconde.
+fn jump_threshold(rate: TimecodeRate) -> f64 {
+    rate.nominal_fps() as f64
+}
+
+const LOG_SIZE: usize = 20;
+
+impl Playback {
+    pub fn new(start: i64) -> Self {
+        Playback { source: start as f64, running: false, signal: true, position: None, freewheel_left: 0.0, state: SyncState::Waiting, log: Vec::new() }
+    }
+
+    fn note(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > LOG_SIZE {
+            self.log.remove(0);
+        }
+    }
+
+    /// Déplace la source (locate) : la liste se recalera sans jouer les événements sautés.
+    pub fn locate(&mut self, frame: i64) {
+        self.source = frame as f64;
+    }
+
+    /// Avance de `dt` secondes ; renvoie le nombre d'événements déclenchés.
+    pub fn advance(&mut self, dt: f64, list: &EventList, sim: &mut Simulator) -> usize {
+        let frames = dt * list.rate.real_fps();
+        if self.running {
+            self.source += frames;
+        }
+        let receiving = self.running && self.signal;
+        match (receiving, self.position) {
+            (true, Some(previous)) if self.source >= previous && self.source - previous <= jump_threshold(list.rate) => {
+                self.position = Some(self.source);
+                self.freewheel_left = list.freewheel_frames as f64;
+                self.state = SyncState::Chasing;
+                self.fire_between(previous, self.source, list, sim)
+            }
+            (true, _) => {
+                // Premier verrouillage ou saut : on se cale sans rattraper les événements intermédiaires.
+                let from = if self.state == SyncState::Waiting { "Verrouillage" } else { "Saut" };
+                self.note(format!("{} sur {}", from, list.timecode(self.source as i64).text()));
+                self.position = Some(self.source);
+                self.freewheel_left = list.freewheel_frames as f64;
+                self.state = SyncState::Chasing;
+                0
+            }
+            (false, Some(previous)) if matches!(self.state, SyncState::Chasing | SyncState::Freewheel) => {
+                if self.state == SyncState::Chasing {
+                    self.note(format!("Signal absent à {} : freewheel {} image(s)", list.timecode(previous as i64).text(), list.freewheel_frames));
+                }
+                let step = frames.min(self.freewheel_left);
+                self.freewheel_left -= step;
+                let next = previous + step;
+                self.position = Some(next);
+                self.state = SyncState::Freewheel;
+                let fired = self.fire_between(previous, next, list, sim);
+                if self.freewheel_left <= 0.0 {
+                    self.state = SyncState::Stopped;
+                    self.note(format!("Liste arrêtée à {}", list.timecode(next as i64).text()));
+                }
+                fired
+            }
+            _ => 0,
+        }
+    }
+
+    /// Déclenche les événements actifs dont l'image est dans ]from, to].
+    fn fire_between(&mut self, from: f64, to: f64, list: &EventList, sim: &mut Simulator) -> usize {
+        let (from, to) = (from.floor() as i64, to.floor() as i64);
+        let due: Vec<&TimecodeEvent> = list.events.iter().filter(|e| e.enabled && e.frame > from && e.frame <= to).collect();
+        for event in &due {
+            let result = fire(&event.action, sim);
+            let line = format!("{} — {} : {}", list.timecode(event.frame).text(), event.action.describe(), result);
+            sim.message = Some(format!("⏱️ {}", line));
+            self.note(line);
+        }
+        due.len()
+    }
+
+    /// Prochain événement actif après la position de la liste (ou de la source).
+    pub fn next_event<'a>(&self, list: &'a EventList) -> Option<&'a TimecodeEvent> {
+        let now = self.position.unwrap_or(self.source).floor() as i64;
+        list.events.iter().filter(|e| e.enabled && e.frame > now).min_by_key(|e| e.frame)
+    }
+}
+
+/// Tape une ligne sur un command line à part, sans toucher à la saisie de l'utilisateur.
+fn run_line(sim: &mut Simulator, notation: &str) -> String {
+    let saved = std::mem::take(&mut sim.command_line);
+    sim.type_notation(notation);
+    sim.command_line = saved;
+    sim.message.clone().unwrap_or_default()
+}
+
+/// Exécute une action dans le simulateur et renvoie son compte rendu.
+fn fire(action: &EventAction, sim: &mut Simulator) -> String {
+    match action {
+        EventAction::Cue { list, cue } => {
+            let result = run_line(sim, &format!("[Go To Cue][{}][Enter]", format_cue_number(*cue)));
+            if *list == 1 { result } else { format!("{} (le simulateur n'a qu'une liste : liste {} jouée sur la 1)", result, list) }
+        }
+        EventAction::Macro(n) => {
+            let Some(text) = sim.macros.get(n).map(|m| m.text.clone()) else {
+                return format!("Erreur : la macro {} n'existe pas", n);
+            };
+            match compile(&text) {
+                Ok(steps) => {
+                    // Jouée d'un bloc, sans ses {Wait}
+                    let mut run = MacroRun::new(*n, steps, true);
+                    while !run.finished {
+                        run.step(sim);
+                    }
+                    run.error.unwrap_or_else(|| format!("macro {} exécutée", n))
+                }
+                Err(e) => format!("Erreur : {}", e),
+            }
+        }
+        EventAction::Sub { number, on } => {
+            format!("submaster {} {} (pas de submasters dans le simulateur)", number, if *on { "monté" } else { "descendu" })
+        }
+    }
+}
+
+// --- Composant Panneau Liste d'événements ---
+const TICK_SECS: f64 = 0.1;
+
+fn save_lists(lists: &[EventList]) {
+    if let Err(e) = LocalStorage::set(EVENT_LISTS_STORAGE_KEY, lists) {
+        gloo::console::error!(format!("Sauvegarde des listes d'événements impossible : {:?}", e));
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct EventListPanelProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(EventListPanel)]
+pub fn event_list_panel(props: &EventListPanelProps) -> Html {
+    let lists = use_state(|| LocalStorage::get::<Vec<EventList>>(EVENT_LISTS_STORAGE_KEY).ok().filter(|l| !l.is_empty()).unwrap_or_else(default_event_lists));
+    let selected = use_state(|| 0usize);
+    let playback = use_state(|| Playback::new(0));
+    let locate_text = use_state(|| "00:00:00:00".to_string());
+    let csv_text = use_state(String::new);
+    let errors = use_state(Vec::<String>::new);
+
+    let index = (*selected).min(lists.len().saturating_sub(1));
+    let list = lists[index].clone();
+
+    // Pendant la lecture, chaque rendu programme le tick suivant avec l'état courant.
+    {
+        let playback = playback.clone();
+        let list = list.clone();
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        use_effect(move || {
+            let active = playback.running || playback.state == SyncState::Freewheel;
+            let timeout = active.then(|| {
+                Timeout::new((TICK_SECS * 1000.0) as u32, move || {
+                    let mut next = (*playback).clone();
+                    let mut sim = simulator;
+                    if next.advance(TICK_SECS, &list, &mut sim) > 0 {
+                        on_change.emit(sim);
+                    }
+                    playback.set(next);
+                })
+            });
+            move || drop(timeout)
+        });
+    }
+
+    // Modifie la liste affichée et enregistre
+    let edit = {
+        let lists = lists.clone();
+        let errors = errors.clone();
+        move |f: &dyn Fn(&mut EventList) -> Result<(), String>| {
+            let mut next = (*lists).clone();
+            match f(&mut next[index]) {
+                Ok(()) => {
+                    save_lists(&next);
+                    lists.set(next);
+                    errors.set(Vec::new());
+                }
+                Err(e) => errors.set(vec![e]),
+            }
+        }
+    };
+    let on_input = |f: fn(&mut EventList, usize, String) -> Result<(), String>, row: usize| {
+        let apply = edit.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let value = input.value();
+                apply(&|l| f(l, row, value.clone()));
+            } else if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                let value = select.value();
+                apply(&|l| f(l, row, value.clone()));
+            }
+        })
+    };
+    let on_toggle = |row: usize| {
+        let apply = edit.clone();
+        Callback::from(move |_: Event| {
+            apply(&|l| {
+                l.events[row].enabled = !l.events[row].enabled;
+                Ok(())
+            })
+        })
+    };
+    let on_delete = |row: usize| {
+        let apply = edit.clone();
+        Callback::from(move |_: MouseEvent| {
+            apply(&|l| {
+                l.events.remove(row);
+                Ok(())
+            })
+        })
+    };
+    let on_add = {
+        let apply = edit.clone();
+        let at = playback.position.unwrap_or(playback.source).floor() as i64;
+        Callback::from(move |_: MouseEvent| {
+            apply(&|l| {
+                // À la position de lecture, ou cinq secondes après le dernier événement
+                let frame = l.events.iter().map(|e| e.frame + 5 * l.rate.nominal_fps() as i64).max().unwrap_or(0).max(at);
+                l.events.push(TimecodeEvent { frame, action: EventAction::Cue { list: 1, cue: 1.0 }, enabled: true, label: String::new() });
+                l.sort();
+                Ok(())
+            })
+        })
+    };
+    let on_rate = {
+        let apply = edit.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                let rate = TimecodeRate::from_code(select.selected_index().max(0) as u8);
+                apply(&|l| {
+                    l.set_rate(rate);
+                    Ok(())
+                });
+            }
+        })
+    };
+    let on_freewheel = {
+        let apply = edit.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let value = input.value();
+                apply(&|l| {
+                    l.freewheel_frames = value.trim().parse().map_err(|_| format!("freewheel « {} » : nombre d'images attendu", value.trim()))?;
+                    Ok(())
+                });
+            }
+        })
+    };
+    let on_select_list = {
+        let selected = selected.clone();
+        let playback = playback.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                selected.set(select.selected_index().max(0) as usize);
+                playback.set(Playback::new(0));
+            }
+        })
+    };
+    let on_new_list = {
+        let lists = lists.clone();
+        let selected = selected.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*lists).clone();
+            let number = next.iter().map(|l| l.number).max().unwrap_or(0) + 1;
+            next.push(EventList::new(number));
+            save_lists(&next);
+            selected.set(next.len() - 1);
+            lists.set(next);
+        })
+    };
+
+    // Horloge de la source
+    let on_play = {
+        let playback = playback.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*playback).clone();
+            next.running = !next.running;
+            playback.set(next);
+        })
+    };
+    let on_signal = {
+        let playback = playback.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*playback).clone();
+            next.signal = !next.signal;
+            playback.set(next);
+        })
+    };
+    let on_locate_text = {
+        let locate_text = locate_text.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                locate_text.set(input.value());
+            }
+        })
+    };
+    let on_locate = {
+        let playback = playback.clone();
+        let errors = errors.clone();
+        let text = (*locate_text).clone();
+        let rate = list.rate;
+        Callback::from(move |_: MouseEvent| match Timecode::parse(&text, rate) {
+            Ok(tc) => {
+                let mut next = (*playback).clone();
+                next.locate(tc.to_frames());
+                playback.set(next);
+            }
+            Err(e) => errors.set(vec![e]),
+        })
+    };
+    let on_reset = {
+        let playback = playback.clone();
+        Callback::from(move |_: MouseEvent| playback.set(Playback::new(0)))
+    };
+
+    // CSV
+    let on_csv_text = {
+        let csv_text = csv_text.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(area) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                csv_text.set(area.value());
+            }
+        })
+    };
+    let on_import = {
+        let lists = lists.clone();
+        let errors = errors.clone();
+        let csv_text = csv_text.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*lists).clone();
+            match next[index].import_csv(&csv_text) {
+                Ok(_) => {
+                    save_lists(&next);
+                    lists.set(next);
+                    errors.set(Vec::new());
+                    csv_text.set(String::new());
+                }
+                Err(e) => errors.set(e),
+            }
+        })
+    };
+
+    let next_event = playback.next_event(&list);
+
+    html! {
+        <div class="event-list-panel">
+            <div class="sim-input">
+                <select onchange={on_select_list}>
+                    { for lists.iter().enumerate().map(|(i, l)| html! {
+                        <option selected={i == index}>{ format!("Liste {}{}", l.number, if l.label.is_empty() { String::new() } else { format!(" — {}", l.label) }) }</option>
+                    })}
+                </select>
+                <button class="back-btn" onclick={on_new_list}>{ "+ Nouvelle liste" }</button>
+                <select onchange={on_rate}>
+                    { for TIMECODE_RATES.iter().map(|(r, label)| html! { <option selected={*r == list.rate}>{ *label }</option> }) }
+                </select>
+                <label>{ "Freewheel " }<input type="number" min="0" class="event-freewheel" value={list.freewheel_frames.to_string()} onchange={on_freewheel} />{ " images" }</label>
+            </div>
+
+            <div class="event-clock">
+                <div>
+                    <span class="event-clock-label">{ "Source" }</span>
+                    <code class="event-clock-time">{ list.timecode(playback.source as i64).text() }</code>
+                </div>
+                <div>
+                    <span class="event-clock-label">{ format!("Liste {}", list.number) }</span>
+                    <code class="event-clock-time">{ playback.position.map(|p| list.timecode(p as i64).text()).unwrap_or_else(|| "--:--:--:--".to_string()) }</code>
+                </div>
+                <div class="event-sync">{ playback.state.label() }</div>
+            </div>
+            <div class="sim-input">
+                <button class="back-btn" onclick={on_play}>{ if playback.running { "⏸ Pause" } else { "▶ Lecture" } }</button>
+                <button class={classes!("back-btn", (!playback.signal).then_some("active"))} onclick={on_signal}>
+                    { if playback.signal { "📵 Couper le signal" } else { "📶 Rétablir le signal" } }
+                </button>
+                <input type="text" value={(*locate_text).clone()} oninput={on_locate_text} class="event-locate" />
+                <button class="back-btn" onclick={on_locate}>{ "⤳ Locate" }</button>
+                <button class="back-btn" onclick={on_reset}>{ "⟲ Remise à zéro" }</button>
+            </div>
+            if let Some(event) = next_event {
+                <p class="event-next">{ format!("Prochain : {} — {}", list.timecode(event.frame).text(), event.action.describe()) }</p>
+            }
+            if !errors.is_empty() {
+                <ul class="template-errors">{ for errors.iter().map(|e| html! { <li>{ e }</li> }) }</ul>
+            }
+
+            <table class="event-table">
+                <tr><th>{ "Temps" }</th><th>{ "Action" }</th><th>{ "Cible" }</th><th>{ "Actif" }</th><th>{ "Libellé" }</th><th></th></tr>
+                { for list.events.iter().enumerate().map(|(row, event)| {
+                    let past = playback.position.is_some_and(|p| event.frame <= p as i64);
+                    html! {
+                        <tr class={classes!((!event.enabled).then_some("event-disabled"), past.then_some("event-past"))}>
+                            <td><input type="text" value={list.timecode(event.frame).text()} onchange={on_input(|l, row, v| {
+                                l.events[row].frame = Timecode::parse(&v, l.rate)?.to_frames();
+                                l.sort();
+                                Ok(())
+                            }, row)} /></td>
+                            <td>
+                                <select onchange={on_input(|l, row, v| {
+                                    let target = l.events[row].action.target();
+                                    // Une cible de cue « 1/5 » garde son numéro de cue en passant à une macro ou un sub
+                                    let target = if v == "Cue" { target } else { target.rsplit('/').next().unwrap_or("1").split('.').next().unwrap_or("1").to_string() };
+                                    l.events[row].action = EventAction::parse(&v, &target)?;
+                                    Ok(())
+                                }, row)}>
+                                    { for ACTION_KINDS.iter().map(|kind| html! { <option selected={*kind == event.action.kind()}>{ *kind }</option> }) }
+                                </select>
+                            </td>
+                            <td><input type="text" value={event.action.target()} onchange={on_input(|l, row, v| {
+                                l.events[row].action = EventAction::parse(l.events[row].action.kind(), &v)?;
+                                Ok(())
+                            }, row)} /></td>
+                            <td><input type="checkbox" checked={event.enabled} onchange={on_toggle(row)} /></td>
+                            <td><input type="text" value={event.label.clone()} onchange={on_input(|l, row, v| {
+                                l.events[row].label = v;
+                                Ok(())
+                            }, row)} /></td>
+                            <td><button class="back-btn" onclick={on_delete(row)}>{ "🗑" }</button></td>
+                        </tr>
+                    }
+                })}
+            </table>
+            <div class="form-actions">
+                <button class="back-btn" onclick={on_add}>{ "+ Événement" }</button>
+                <a class="back-btn" href={data_url("text/csv", &list.to_csv())} download={format!("liste-evenements-{}.csv", list.number)}>{ "⬇️ Exporter (CSV)" }</a>
+            </div>
+
+            <h3>{ "Importer un CSV" }</h3>
+            <textarea rows="4" value={(*csv_text).clone()} oninput={on_csv_text}
+                      placeholder="temps;action;cible;actif;libellé&#10;01:00:05:00;Cue;1/5;oui;Ouverture" />
+            <button class="back-btn" onclick={on_import} disabled={csv_text.trim().is_empty()}>{ "⬆️ Remplacer les événements" }</button>
+
+            if !playback.log.is_empty() {
+                <ul class="console-log">{ for playback.log.iter().rev().map(|line| html! { <li>{ line }</li> }) }</ul>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drop_frame_csv_round_trip() {
+        let mut list = default_event_lists().remove(0);
+        list.set_rate(TimecodeRate::Fps2997Drop);
+        list.events[0].label = "Temps; \"cité\"".to_string();
+        let csv = list.to_csv();
+        assert!(csv.lines().nth(1).is_some_and(|line| line.starts_with("\"00:00:05;")), "{}", csv);
+
+        let mut imported = EventList::new(2);
+        imported.rate = TimecodeRate::Fps2997Drop;
+        assert_eq!(imported.import_csv(&csv), Ok(3));
+        assert_eq!(imported.events, list.events);
+    }
+
+    #[test]
+    fn imports_comma_separated_lines() {
+        let mut list = EventList::new(1);
+        assert_eq!(list.import_csv("00:00:01:00,Macro,5\n00:00:02:00,Cue,1/2.5,non,\"Fin, noir\""), Ok(2));
+        assert_eq!(list.events[0].action, EventAction::Macro(5));
+        assert_eq!(list.events[1].action, EventAction::Cue { list: 1, cue: 2.5 });
+        assert!(!list.events[1].enabled);
+        assert_eq!(list.events[1].label, "Fin, noir");
+    }
+}
@@ -0,0 +1,275 @@
+use gloo::timers::callback::Interval;
+use yew::prelude::*;
+
+use crate::notation::{render_keys, to_notation, KeyKind, KeyToken};
+
+// --- Pupitre Eos ---
+// Disposition simplifiée du pupitre : rangée de softkeys, bloc de fonctions,
+// pavé de commande et zone de Direct Select. Les touches absentes du pupitre
+// (menus, touches de fichier…) sont regroupées dans une rangée « Autres touches ».
+
+const KEY_W: f64 = 64.0;
+const KEY_H: f64 = 36.0;
+const GAP: f64 = 6.0;
+/// Durée d'affichage de chaque touche pendant l'animation, en millisecondes.
+const STEP_MS: f64 = 600.0;
+
+const SOFTKEYS: usize = 6;
+const DEFAULT_SOFTKEYS: [&str; SOFTKEYS] = ["Intens", "Focus", "Color", "Beam", "Pan", "Tilt"];
+const DIRECT_SELECTS: usize = 10;
+
+/// Bloc de fonctions, rangée par rangée.
+const FUNCTION_KEYS: [[&str; 6]; 5] = [
+    ["Live", "Blind", "Format", "Time", "Displays", "Help"],
+    ["Record", "Record Only", "Update", "Q Only", "Cue", "Go To Cue"],
+    ["Group", "Sub", "Macro", "Effect", "Palette", "Preset"],
+    ["Block", "Assert", "Trace", "Rem Dim", "Copy To", "Recall From"],
+    ["Delete", "Query", "Capture", "Freeze", "Release", "Undo"],
+];
+
+/// Pavé de commande ; `Enter` occupe deux colonnes.
+const KEYPAD_KEYS: [[&str; 4]; 6] = [
+    ["Sneak", "Shift", "Home", "Clear"],
+    ["7", "8", "9", "Thru"],
+    ["4", "5", "6", "+"],
+    ["1", "2", "3", "-"],
+    ["0", ".", "At", "Full"],
+    ["Out", "Learn", "Enter", ""],
+];
+
+#[derive(Clone, Debug, PartialEq)]
+struct PanelKey {
+    token: KeyToken,
+    x: f64,
+    y: f64,
+    w: f64,
+}
+
+/// Séquence touche par touche : les nombres sont décomposés en chiffres (`[50]` → `[5][0]`).
+pub fn expand(keys: &[KeyToken]) -> Vec<KeyToken> {
+    keys.iter()
+        .flat_map(|k| {
+            if k.is_number() {
+                k.label.chars().map(|c| KeyToken::hard(&c.to_string())).collect()
+            } else {
+                vec![k.clone()]
+            }
+        })
+        .collect()
+}
+
+fn same_key(a: &KeyToken, b: &KeyToken) -> bool {
+    a.kind == b.kind && a.is(&b.label)
+}
+
+/// Place toutes les touches ; les softkeys et le Direct Select reprennent les libellés de la séquence.
+fn layout(sequence: &[KeyToken]) -> (Vec<PanelKey>, f64, f64) {
+    let mut keys = Vec::new();
+    let step = |n: f64| n * (KEY_W + GAP);
+    let row = |n: f64| n * (KEY_H + GAP);
+
+    let distinct = |kind: KeyKind| {
+        let mut labels: Vec<String> = Vec::new();
+        for k in sequence.iter().filter(|k| k.kind == kind) {
+            if !labels.iter().any(|l| k.is(l)) {
+                labels.push(k.label.clone());
+            }
+        }
+        labels
+    };
+
+    // Softkeys : les pages par défaut, puis celles de la séquence.
+    let mut soft: Vec<String> = DEFAULT_SOFTKEYS.iter().map(|l| l.to_string()).collect();
+    for label in distinct(KeyKind::Soft) {
+        if !soft.iter().any(|l| KeyToken::soft(l).is(&label)) {
+            soft.push(label);
+        }
+    }
+    for (i, label) in soft.iter().enumerate() {
+        let (c, r) = (i % SOFTKEYS, i / SOFTKEYS);
+        keys.push(PanelKey { token: KeyToken::soft(label), x: step(c as f64), y: row(r as f64), w: KEY_W });
+    }
+    let soft_rows = soft.len().div_ceil(SOFTKEYS).max(1) as f64;
+    let top = row(soft_rows) + GAP * 2.0;
+
+    // Bloc de fonctions et pavé
+    for (r, labels) in FUNCTION_KEYS.iter().enumerate() {
+        for (c, label) in labels.iter().enumerate() {
+            keys.push(PanelKey { token: KeyToken::hard(label), x: step(c as f64), y: top + row(r as f64), w: KEY_W });
+        }
+    }
+    let keypad_x = step(FUNCTION_KEYS[0].len() as f64) + GAP * 3.0;
+    for (r, labels) in KEYPAD_KEYS.iter().enumerate() {
+        for (c, label) in labels.iter().enumerate().filter(|(_, l)| !l.is_empty()) {
+            let w = if *label == "Enter" { KEY_W * 2.0 + GAP } else { KEY_W };
+            keys.push(PanelKey { token: KeyToken::hard(label), x: keypad_x + step(c as f64), y: top + row(r as f64), w });
+        }
+    }
+    let width = keypad_x + step(KEYPAD_KEYS[0].len() as f64);
+
+    // Direct Select
+    let ds_top = top + row(KEYPAD_KEYS.len() as f64) + GAP * 2.0;
+    let mut users: Vec<String> = distinct(KeyKind::User).into_iter().filter(|l| !l.starts_with("DS ")).collect();
+    let mut n = 1;
+    while users.len() < DIRECT_SELECTS {
+        users.push(format!("DS {}", n));
+        n += 1;
+    }
+    let per_row = DIRECT_SELECTS / 2;
+    for (i, label) in users.iter().enumerate() {
+        let token = KeyToken { kind: KeyKind::User, label: label.clone() };
+        keys.push(PanelKey { token, x: step((i % per_row) as f64) * 1.2, y: ds_top + row((i / per_row) as f64), w: KEY_W * 1.2 });
+    }
+    let mut bottom = ds_top + row(users.len().div_ceil(per_row) as f64);
+
+    // Touches de la séquence absentes du pupitre
+    let missing: Vec<KeyToken> = distinct(KeyKind::Hard)
+        .into_iter()
+        .map(|l| KeyToken::hard(&l))
+        .filter(|t| !keys.iter().any(|k| same_key(&k.token, t)))
+        .collect();
+    if !missing.is_empty() {
+        bottom += GAP * 2.0;
+        let per_row = (width / (KEY_W * 1.5 + GAP)).floor().max(1.0) as usize;
+        for (i, token) in missing.iter().enumerate() {
+            let x = (i % per_row) as f64 * (KEY_W * 1.5 + GAP);
+            keys.push(PanelKey { token: token.clone(), x, y: bottom + row((i / per_row) as f64), w: KEY_W * 1.5 });
+        }
+        bottom += row(missing.len().div_ceil(per_row) as f64);
+    }
+    (keys, width, bottom)
+}
+
+fn key_label(label: &str) -> Html {
+    // Les libellés longs passent sur deux lignes.
+    match label.split_once(' ') {
+        Some((first, second)) if label.len() > 7 => html! {
+            <>
+                <tspan x="0" dy="-0.5em">{ first.to_string() }</tspan>
+                <tspan x="0" dy="1.1em">{ second.to_string() }</tspan>
+            </>
+        },
+        _ => html! { { label.to_string() } },
+    }
+}
+
+// --- Composant Pupitre ---
+#[derive(Properties, PartialEq)]
+pub struct FacepanelProps {
+    /// Séquence à mettre en évidence.
+    #[prop_or_default]
+    pub highlight: Vec<KeyToken>,
+    /// Anime la séquence touche après touche.
+    #[prop_or(false)]
+    pub animate: bool,
+    #[prop_or_default]
+    pub on_key: Callback<KeyToken>,
+}
+
+#[function_component(Facepanel)]
+pub fn facepanel(props: &FacepanelProps) -> Html {
+    let sequence = expand(&props.highlight);
+    let started = use_memo((props.highlight.clone(), props.animate), |_| js_sys::Date::now());
+    let redraw = use_force_update();
+
+    {
+        let animate = props.animate && !sequence.is_empty();
+        use_effect_with((props.highlight.clone(), animate), move |(_, animate)| {
+            let interval = animate.then(|| Interval::new(STEP_MS as u32 / 2, move || redraw.force_update()));
+            move || drop(interval)
+        });
+    }
+
+    // Une pause de deux pas entre deux passages.
+    let current = props.animate.then(|| {
+        let step = ((js_sys::Date::now() - *started) / STEP_MS) as usize % (sequence.len() + 2);
+        sequence.get(step).cloned()
+    });
+    let current = current.flatten();
+
+    let (keys, width, height) = layout(&sequence);
+
+    html! {
+        <svg class="facepanel" viewBox={format!("-2 -2 {} {}", width + 4.0, height + 4.0)} xmlns="http://www.w3.org/2000/svg">
+            { for keys.iter().map(|key| {
+                let in_sequence = sequence.iter().any(|k| same_key(k, &key.token));
+                let active = current.as_ref().is_some_and(|k| same_key(k, &key.token));
+                let order = sequence.iter().position(|k| same_key(k, &key.token));
+                let kind = match key.token.kind {
+                    KeyKind::Hard => "fp-hard",
+                    KeyKind::Soft => "fp-soft",
+                    KeyKind::User => "fp-user",
+                };
+                let onclick = {
+                    let on_key = props.on_key.clone();
+                    let token = key.token.clone();
+                    Callback::from(move |_: MouseEvent| on_key.emit(token.clone()))
+                };
+                html! {
+                    <g class={classes!("fp-key", kind, in_sequence.then_some("fp-in-sequence"), active.then_some("fp-active"))}
+                       transform={format!("translate({}, {})", key.x, key.y)} {onclick}>
+                        <title>{ key.token.to_notation() }</title>
+                        <rect width={key.w.to_string()} height={KEY_H.to_string()} rx="5" />
+                        <text transform={format!("translate({}, {})", key.w / 2.0, KEY_H / 2.0 + 4.0)} text-anchor="middle">
+                            { key_label(&key.token.label) }
+                        </text>
+                        if let Some(order) = order {
+                            <text class="fp-order" x="4" y="10">{ order + 1 }</text>
+                        }
+                    </g>
+                }
+            })}
+        </svg>
+    }
+}
+
+// --- Composant Construction de séquence ---
+// Le pupitre sert de clavier : les touches cliquées forment une séquence en notation du guide.
+#[derive(Properties, PartialEq)]
+pub struct NotationBuilderProps {
+    pub use_label: AttrValue,
+    pub on_use: Callback<String>,
+}
+
+#[function_component(NotationBuilder)]
+pub fn notation_builder(props: &NotationBuilderProps) -> Html {
+    let keys = use_state(Vec::<KeyToken>::new);
+
+    let on_key = {
+        let keys = keys.clone();
+        Callback::from(move |key: KeyToken| {
+            let mut next = (*keys).clone();
+            next.push(key);
+            keys.set(next);
+        })
+    };
+    let backspace = {
+        let keys = keys.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*keys).clone();
+            next.pop();
+            keys.set(next);
+        })
+    };
+    let clear = {
+        let keys = keys.clone();
+        Callback::from(move |_: MouseEvent| keys.set(Vec::new()))
+    };
+    let on_use = {
+        let keys = keys.clone();
+        let on_use = props.on_use.clone();
+        Callback::from(move |_: MouseEvent| on_use.emit(to_notation(&keys)))
+    };
+
+    html! {
+        <div class="notation-builder">
+            <div class="sim-command-line">{ render_keys(&keys) }</div>
+            <div class="form-actions">
+                <button class="back-btn" onclick={backspace} disabled={keys.is_empty()}>{ "⌫" }</button>
+                <button class="back-btn" onclick={clear} disabled={keys.is_empty()}>{ "Effacer" }</button>
+                <button class="back-btn" onclick={on_use} disabled={keys.is_empty()}>{ props.use_label.clone() }</button>
+            </div>
+            <Facepanel highlight={(*keys).clone()} {on_key} />
+        </div>
+    }
+}
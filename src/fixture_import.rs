@@ -0,0 +1,722 @@
+use gloo::file::callbacks::{read_as_bytes, FileReader};
+use gloo::file::File;
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::patch_plan::{address_text, DmxAddress, UNIVERSE_SIZE};
+use crate::simulator::{builtin_profiles, FixtureProfile, ParamKind, ParameterDef, PatchEntry, Simulator};
+use crate::xml::{self, Element};
+use crate::zip::{write_stored, ZipArchive};
+
+// --- Bibliothèque de fixtures importées ---
+// Les profils viennent de l'Open Fixture Library (JSON) ou de fichiers GDTF (archive ZIP contenant description.xml).
+// Chaque mode devient un FixtureProfile du simulateur ; la bibliothèque est sauvegardée à côté des données du guide.
+
+pub const FIXTURE_LIBRARY_STORAGE_KEY: &str = "eos_guide_fixture_library";
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ImportFormat {
+    Ofl,
+    Gdtf,
+}
+
+impl ImportFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            ImportFormat::Ofl => "Open Fixture Library",
+            ImportFormat::Gdtf => "GDTF",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ImportedProfile {
+    pub format: ImportFormat,
+    pub manufacturer: String,
+    pub model: String,
+    pub mode: String,
+    /// Nom d'origine de chaque paramètre, dans l'ordre du profil.
+    pub channels: Vec<String>,
+    pub profile: FixtureProfile,
+}
+
+/// Modes importés d'un fichier, avec ce qui n'a pas pu être repris.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImportResult {
+    pub profiles: Vec<ImportedProfile>,
+    pub warnings: Vec<String>,
+}
+
+pub fn load_library() -> Vec<ImportedProfile> {
+    LocalStorage::get(FIXTURE_LIBRARY_STORAGE_KEY).unwrap_or_default()
+}
+
+fn save_library(library: &[ImportedProfile]) {
+    if let Err(e) = LocalStorage::set(FIXTURE_LIBRARY_STORAGE_KEY, library) {
+        gloo::console::error!(format!("Sauvegarde de la bibliothèque de fixtures impossible : {:?}", e));
+    }
+}
+
+/// Profils intégrés suivis des profils importés.
+pub fn all_profiles() -> Vec<FixtureProfile> {
+    let mut profiles = builtin_profiles();
+    profiles.extend(load_library().into_iter().map(|p| p.profile));
+    profiles
+}
+
+/// Ajoute des profils à la bibliothèque ; un profil de même identifiant est remplacé.
+pub fn merge(library: &mut Vec<ImportedProfile>, imported: &[ImportedProfile]) {
+    for profile in imported {
+        match library.iter_mut().find(|p| p.profile.id == profile.profile.id) {
+            Some(existing) => *existing = profile.clone(),
+            None => library.push(profile.clone()),
+        }
+    }
+}
+
+/// Identifiant stable : `ofl:fabricant-modele-mode`.
+fn profile_id(format: ImportFormat, parts: &[&str]) -> String {
+    let mut slug = String::new();
+    for c in parts.join(" ").chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let prefix = match format {
+        ImportFormat::Ofl => "ofl",
+        ImportFormat::Gdtf => "gdtf",
+    };
+    format!("{}:{}", prefix, slug.trim_end_matches('-'))
+}
+
+/// Home quand le fichier n'en donne pas : centre pour Pan/Tilt, couleurs additives à fond comme sur Eos.
+fn default_home(kind: ParamKind) -> f32 {
+    match kind {
+        ParamKind::Pan | ParamKind::Tilt | ParamKind::Zoom => 50.0,
+        ParamKind::Red | ParamKind::Green | ParamKind::Blue | ParamKind::White | ParamKind::Amber => 100.0,
+        _ => 0.0,
+    }
+}
+
+/// Construit un mode paramètre par paramètre.
+#[derive(Default)]
+struct ModeBuilder {
+    parameters: Vec<ParameterDef>,
+    channels: Vec<String>,
+}
+
+impl ModeBuilder {
+    fn push(&mut self, name: &str, kind: ParamKind, home: Option<f32>, fine: bool) {
+        // Un second gobo ou une seconde roue de couleur ne peut pas partager le paramètre du premier.
+        let kind = if kind != ParamKind::Control && self.parameters.iter().any(|p| p.kind == kind) { ParamKind::Control } else { kind };
+        let home = home.unwrap_or_else(|| default_home(kind)).clamp(0.0, 100.0);
+        self.parameters.push(ParameterDef::new(kind, home, fine));
+        self.channels.push(name.to_string());
+    }
+
+    fn finish(self, format: ImportFormat, manufacturer: &str, model: &str, mode: &str) -> ImportedProfile {
+        let name = if manufacturer.is_empty() { format!("{} ({})", model, mode) } else { format!("{} {} ({})", manufacturer, model, mode) };
+        ImportedProfile {
+            format,
+            manufacturer: manufacturer.to_string(),
+            model: model.to_string(),
+            mode: mode.to_string(),
+            channels: self.channels,
+            profile: FixtureProfile { id: profile_id(format, &[manufacturer, model, mode]), name, parameters: self.parameters },
+        }
+    }
+}
+
+/// Reconnaît le format au contenu : un ZIP est un GDTF, le reste est lu comme du JSON OFL.
+pub fn import_file(bytes: &[u8]) -> Result<ImportResult, String> {
+    if bytes.starts_with(b"PK") {
+        import_gdtf(bytes)
+    } else {
+        let text = std::str::from_utf8(bytes).map_err(|_| "fichier ni ZIP (GDTF) ni texte UTF-8 (OFL)".to_string())?;
+        import_ofl(text)
+    }
+}
+
+// --- Open Fixture Library ---
+
+/// Clé de pixel si `key` correspond au modèle `template` (`Red $pixelKey` → `Red 3` donne `3`).
+fn template_match(template: &str, key: &str) -> Option<String> {
+    let (before, after) = template.split_once("$pixelKey")?;
+    let matches = key.len() > before.len() + after.len() && key.starts_with(before) && key.ends_with(after);
+    matches.then(|| key[before.len()..key.len() - after.len()].to_string())
+}
+
+/// Définition d'un canal OFL et clé de pixel quand il vient d'un canal modèle.
+fn ofl_channel<'a>(root: &'a Value, key: &str) -> Option<(&'a Value, String)> {
+    if let Some(def) = root["availableChannels"].get(key) {
+        return Some((def, String::new()));
+    }
+    root["templateChannels"].as_object()?.iter().find_map(|(template, def)| Some((def, template_match(template, key)?)))
+}
+
+fn ofl_fine_aliases(def: &Value, pixel: &str) -> Vec<String> {
+    def["fineChannelAliases"]
+        .as_array()
+        .map(|aliases| aliases.iter().filter_map(Value::as_str).map(|a| a.replace("$pixelKey", pixel)).collect())
+        .unwrap_or_default()
+}
+
+/// Vrai si la clé est l'alias fin d'un canal (elle n'a alors pas de définition propre).
+fn ofl_is_fine_alias(root: &Value, key: &str) -> bool {
+    ["availableChannels", "templateChannels"].iter().filter_map(|group| root[group].as_object()).flat_map(|channels| channels.values()).any(|def| {
+        def["fineChannelAliases"]
+            .as_array()
+            .is_some_and(|aliases| aliases.iter().filter_map(Value::as_str).any(|alias| alias == key || template_match(alias, key).is_some()))
+    })
+}
+
+/// Type d'une roue d'après ses slots, ou à défaut d'après son nom.
+fn ofl_wheel_kind(root: &Value, wheel: &str) -> ParamKind {
+    let slots = root["wheels"][wheel]["slots"].as_array();
+    let slot_type = |t: &str| slots.is_some_and(|s| s.iter().any(|slot| slot["type"].as_str() == Some(t)));
+    let name = wheel.to_lowercase();
+    if slot_type("Color") || name.contains("color") || name.contains("colour") {
+        ParamKind::ColorWheel
+    } else if slot_type("Gobo") || slot_type("AnimationGoboStart") || name.contains("gobo") {
+        ParamKind::Gobo
+    } else if slot_type("Prism") {
+        ParamKind::Prism
+    } else {
+        ParamKind::Control
+    }
+}
+
+/// Paramètre d'après la première capacité utile du canal.
+fn ofl_kind(root: &Value, def: &Value, key: &str) -> ParamKind {
+    let capabilities: Vec<&Value> = if def["capability"].is_object() {
+        vec![&def["capability"]]
+    } else {
+        def["capabilities"].as_array().map(|c| c.iter().collect()).unwrap_or_default()
+    };
+    let Some(capability) = capabilities.into_iter().find(|c| c["type"].as_str() != Some("NoFunction")) else {
+        return ParamKind::Control;
+    };
+    match capability["type"].as_str().unwrap_or("") {
+        "Intensity" => ParamKind::Intensity,
+        "Pan" => ParamKind::Pan,
+        "Tilt" => ParamKind::Tilt,
+        "ColorIntensity" => match capability["color"].as_str().unwrap_or("") {
+            "Red" => ParamKind::Red,
+            "Green" => ParamKind::Green,
+            "Blue" => ParamKind::Blue,
+            "Cyan" => ParamKind::Cyan,
+            "Magenta" => ParamKind::Magenta,
+            "Yellow" => ParamKind::Yellow,
+            "White" | "Warm White" | "Cold White" => ParamKind::White,
+            "Amber" => ParamKind::Amber,
+            _ => ParamKind::Control,
+        },
+        "ColorPreset" => ParamKind::ColorWheel,
+        "WheelSlot" => ofl_wheel_kind(root, capability["wheel"].as_str().unwrap_or(key)),
+        "ShutterStrobe" | "StrobeSpeed" | "StrobeDuration" => ParamKind::Strobe,
+        "Zoom" | "BeamAngle" => ParamKind::Zoom,
+        "Iris" => ParamKind::Iris,
+        "Focus" => ParamKind::Edge,
+        "Prism" => ParamKind::Prism,
+        _ => ParamKind::Control,
+    }
+}
+
+/// `defaultValue` OFL : valeur DMX dans la résolution du canal, ou pourcentage `"50%"`.
+fn ofl_home(def: &Value, pixel: &str) -> Option<f32> {
+    match &def["defaultValue"] {
+        Value::Number(n) => {
+            let bytes = match def["dmxValueResolution"].as_str() {
+                Some("8bit") => 1,
+                Some("16bit") => 2,
+                Some("24bit") => 3,
+                _ => ofl_fine_aliases(def, pixel).len() as i32 + 1,
+            };
+            Some((n.as_f64()? / (256f64.powi(bytes) - 1.0) * 100.0) as f32)
+        }
+        Value::String(s) => s.trim().trim_end_matches('%').trim().parse().ok(),
+        _ => None,
+    }
+}
+
+pub fn import_ofl(text: &str) -> Result<ImportResult, String> {
+    let root: Value = serde_json::from_str(text).map_err(|e| format!("JSON invalide : {}", e))?;
+    let model = root["name"].as_str().ok_or("champ « name » absent : ce n'est pas une fixture Open Fixture Library")?;
+    let manufacturer = root["manufacturerKey"].as_str().or(root["manufacturer"]["name"].as_str()).unwrap_or("");
+    let modes = root["modes"].as_array().ok_or("champ « modes » absent")?;
+    let mut result = ImportResult::default();
+    'modes: for mode in modes {
+        let mode_name = mode["name"].as_str().unwrap_or("Mode");
+        let channels = mode["channels"].as_array().map(Vec::as_slice).unwrap_or_default();
+        let mut builder = ModeBuilder::default();
+        let mut i = 0;
+        while i < channels.len() {
+            let key = match &channels[i] {
+                Value::Null => {
+                    builder.push("(inutilisé)", ParamKind::Control, Some(0.0), false);
+                    i += 1;
+                    continue;
+                }
+                Value::String(key) => key.as_str(),
+                _ => {
+                    result.warnings.push(format!("mode « {} » ignoré : les canaux de matrice ne sont pas gérés", mode_name));
+                    continue 'modes;
+                }
+            };
+            i += 1;
+            let Some((def, pixel)) = ofl_channel(&root, key) else {
+                if !ofl_is_fine_alias(&root, key) {
+                    result.warnings.push(format!("mode « {} » : canal « {} » inconnu, importé comme Control", mode_name, key));
+                }
+                // Alias fin séparé de son canal grossier : l'octet reste réservé.
+                builder.push(key, ParamKind::Control, Some(0.0), false);
+                continue;
+            };
+            let aliases = ofl_fine_aliases(def, &pixel);
+            let fine = aliases.first().is_some_and(|fine| channels.get(i).and_then(Value::as_str) == Some(fine.as_str()));
+            if fine {
+                i += 1;
+            }
+            builder.push(key, ofl_kind(&root, def, key), ofl_home(def, &pixel), fine);
+        }
+        result.profiles.push(builder.finish(ImportFormat::Ofl, manufacturer, model, mode_name));
+    }
+    if result.profiles.is_empty() {
+        return Err(format!("aucun mode importable dans « {} »", model));
+    }
+    Ok(result)
+}
+
+// --- GDTF ---
+
+/// Paramètre d'après l'attribut GDTF du canal logique (`Dimmer`, `ColorAdd_R`, `Gobo1`...).
+fn gdtf_kind(attribute: &str) -> ParamKind {
+    let indexed = |prefix: &str| attribute.strip_prefix(prefix).is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+    match attribute {
+        "Dimmer" => ParamKind::Intensity,
+        "Pan" => ParamKind::Pan,
+        "Tilt" => ParamKind::Tilt,
+        "ColorAdd_R" | "ColorRGB_Red" => ParamKind::Red,
+        "ColorAdd_G" | "ColorRGB_Green" => ParamKind::Green,
+        "ColorAdd_B" | "ColorRGB_Blue" => ParamKind::Blue,
+        "ColorSub_C" | "ColorAdd_C" | "ColorRGB_Cyan" => ParamKind::Cyan,
+        "ColorSub_M" | "ColorAdd_M" | "ColorRGB_Magenta" => ParamKind::Magenta,
+        "ColorSub_Y" | "ColorAdd_Y" | "ColorRGB_Yellow" => ParamKind::Yellow,
+        "ColorAdd_W" | "ColorAdd_WW" | "ColorAdd_CW" => ParamKind::White,
+        "ColorAdd_A" => ParamKind::Amber,
+        "Zoom" => ParamKind::Zoom,
+        "Iris" => ParamKind::Iris,
+        _ if indexed("Color") => ParamKind::ColorWheel,
+        _ if indexed("Gobo") => ParamKind::Gobo,
+        _ if indexed("Shutter") => ParamKind::Strobe,
+        _ if indexed("Focus") => ParamKind::Edge,
+        _ if indexed("Prism") => ParamKind::Prism,
+        _ => ParamKind::Control,
+    }
+}
+
+/// Valeur DMX GDTF `valeur/octets` (`32768/2`, `128/1`, `1/1s`) en pourcentage.
+fn gdtf_percent(value: &str) -> Option<f32> {
+    let (value, bytes) = value.split_once('/')?;
+    let bytes: i32 = bytes.trim_end_matches('s').parse().ok()?;
+    let value: f64 = value.parse().ok()?;
+    Some((value / (256f64.powi(bytes) - 1.0) * 100.0) as f32)
+}
+
+/// Défaut du canal (GDTF 1.0), sinon celui de la fonction initiale (GDTF 1.1), sinon de la première fonction.
+fn gdtf_default(channel: &Element, logical: Option<&Element>) -> Option<f32> {
+    if let Some(value) = channel.attr("Default") {
+        return gdtf_percent(value);
+    }
+    let functions: Vec<&Element> = logical.map(|l| l.children_named("ChannelFunction").collect()).unwrap_or_default();
+    let initial = channel.attr("InitialFunction").and_then(|f| f.rsplit('.').next());
+    let function = initial.and_then(|name| functions.iter().find(|f| f.attr("Name") == Some(name))).or(functions.first());
+    function.and_then(|f| f.attr("Default")).or(logical.and_then(|l| l.attr("Default"))).and_then(gdtf_percent)
+}
+
+fn gdtf_mode(mode: &Element, warnings: &mut Vec<String>) -> ModeBuilder {
+    let mode_name = mode.attr("Name").unwrap_or("Mode");
+    let mut channels = Vec::new();
+    let mut other_breaks = false;
+    for channel in mode.child("DMXChannels").into_iter().flat_map(|c| c.children_named("DMXChannel")) {
+        // Canal virtuel (sans Offset) : il n'occupe aucune adresse.
+        let offsets: Vec<u32> = channel.attr("Offset").unwrap_or("").split(',').filter_map(|o| o.trim().parse().ok()).collect();
+        let Some(&first) = offsets.first() else { continue };
+        // Adresses hors univers ou plus de 32 bits : on ne crée pas des centaines de paramètres de remplissage.
+        if offsets.len() > 4 || offsets.iter().any(|o| !(1..=UNIVERSE_SIZE).contains(o)) {
+            warnings.push(format!("mode « {} » : Offset « {} » invalide, canal ignoré", mode_name, channel.attr("Offset").unwrap_or("")));
+            continue;
+        }
+        if channel.attr("DMXBreak").unwrap_or("1") != "1" {
+            other_breaks = true;
+            continue;
+        }
+        let logical = channel.child("LogicalChannel");
+        let attribute = logical.and_then(|l| l.attr("Attribute")).unwrap_or("");
+        let name = match channel.attr("Geometry") {
+            Some(geometry) if !attribute.is_empty() => format!("{} · {}", geometry, attribute),
+            _ => channel.attr("Name").unwrap_or(attribute).to_string(),
+        };
+        channels.push((first, offsets.len() as u32, name, gdtf_kind(attribute), gdtf_default(channel, logical)));
+    }
+    if other_breaks {
+        warnings.push(format!("mode « {} » : seuls les canaux du premier DMX break sont importés", mode_name));
+    }
+    channels.sort_by_key(|c| c.0);
+    let mut builder = ModeBuilder::default();
+    let mut next = 1;
+    for (first, bytes, name, kind, home) in channels {
+        if first < next {
+            warnings.push(format!("mode « {} » : « {} » chevauche le canal précédent, ignoré", mode_name, name));
+            continue;
+        }
+        for address in next..first {
+            builder.push(&format!("(adresse {} inutilisée)", address), ParamKind::Control, Some(0.0), false);
+        }
+        builder.push(&name, kind, home, bytes >= 2);
+        // Au-delà de 16 bits, les octets suivants restent réservés.
+        for extra in 3..=bytes {
+            builder.push(&format!("{} (octet {})", name, extra), ParamKind::Control, Some(0.0), false);
+        }
+        next = first + bytes;
+    }
+    builder
+}
+
+pub fn import_gdtf(bytes: &[u8]) -> Result<ImportResult, String> {
+    let archive = ZipArchive::open(bytes)?;
+    let description = archive.read("description.xml")?;
+    let text = String::from_utf8(description).map_err(|_| "description.xml n'est pas en UTF-8".to_string())?;
+    import_gdtf_description(&text)
+}
+
+pub fn import_gdtf_description(text: &str) -> Result<ImportResult, String> {
+    let root = xml::parse(text)?;
+    let fixture = if root.name == "FixtureType" { Some(&root) } else { root.find("FixtureType") }.ok_or("élément FixtureType absent : ce n'est pas une description GDTF")?;
+    let model = fixture.attr("LongName").filter(|n| !n.is_empty()).or(fixture.attr("Name")).unwrap_or("Fixture GDTF");
+    let manufacturer = fixture.attr("Manufacturer").unwrap_or("");
+    let mut result = ImportResult::default();
+    for mode in fixture.child("DMXModes").into_iter().flat_map(|m| m.children_named("DMXMode")) {
+        let builder = gdtf_mode(mode, &mut result.warnings);
+        let mode_name = mode.attr("Name").unwrap_or("Mode");
+        if builder.parameters.is_empty() {
+            result.warnings.push(format!("mode « {} » ignoré : aucun canal DMX", mode_name));
+            continue;
+        }
+        result.profiles.push(builder.finish(ImportFormat::Gdtf, manufacturer, model, mode_name));
+    }
+    if result.profiles.is_empty() {
+        return Err(format!("aucun mode importable dans « {} »", model));
+    }
+    Ok(result)
+}
+
+// --- Fichiers de démonstration ---
+
+const DEMO_OFL: &str = r#"{
+  "name": "Spot 250",
+  "manufacturerKey": "demo",
+  "categories": ["Moving Head"],
+  "availableChannels": {
+    "Dimmer": { "fineChannelAliases": ["Dimmer fine"], "capability": { "type": "Intensity" } },
+    "Shutter": { "defaultValue": 255, "capabilities": [
+      { "dmxRange": [0, 9], "type": "ShutterStrobe", "shutterEffect": "Closed" },
+      { "dmxRange": [10, 249], "type": "ShutterStrobe", "shutterEffect": "Strobe" },
+      { "dmxRange": [250, 255], "type": "ShutterStrobe", "shutterEffect": "Open" }
+    ] },
+    "Pan": { "fineChannelAliases": ["Pan fine"], "defaultValue": "50%", "capability": { "type": "Pan", "angleStart": "0deg", "angleEnd": "540deg" } },
+    "Tilt": { "fineChannelAliases": ["Tilt fine"], "defaultValue": "50%", "capability": { "type": "Tilt", "angleStart": "0deg", "angleEnd": "270deg" } },
+    "Color Wheel": { "capabilities": [
+      { "dmxRange": [0, 127], "type": "WheelSlot", "slotNumber": 1 },
+      { "dmxRange": [128, 255], "type": "WheelSlot", "slotNumber": 2 }
+    ] },
+    "Gobo Wheel": { "capability": { "type": "WheelSlot", "slotNumber": 1 } },
+    "Prism": { "capabilities": [
+      { "dmxRange": [0, 127], "type": "NoFunction" },
+      { "dmxRange": [128, 255], "type": "Prism" }
+    ] },
+    "Focus": { "capability": { "type": "Focus", "distance": "near", "distanceEnd": "far" } },
+    "Zoom": { "defaultValue": 128, "capability": { "type": "Zoom", "angleStart": "12deg", "angleEnd": "36deg" } },
+    "Reset": { "capability": { "type": "Maintenance" } }
+  },
+  "wheels": {
+    "Color Wheel": { "slots": [{ "type": "Open" }, { "type": "Color", "name": "Red" }] },
+    "Gobo Wheel": { "slots": [{ "type": "Open" }, { "type": "Gobo", "name": "Breakup" }] }
+  },
+  "modes": [
+    { "name": "Extended", "channels": ["Dimmer", "Dimmer fine", "Shutter", "Pan", "Pan fine", "Tilt", "Tilt fine", "Color Wheel", "Gobo Wheel", "Prism", "Focus", "Zoom", "Reset"] },
+    { "name": "Basic", "channels": ["Dimmer", "Shutter", "Pan", "Tilt", "Color Wheel", "Gobo Wheel", "Zoom", null, "Reset"] }
+  ]
+}"#;
+
+const DEMO_GDTF_DESCRIPTION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="no" ?>
+<GDTF DataVersion="1.1">
+  <FixtureType Name="Wash 7" ShortName="W7" LongName="Wash 7 RGBW" Manufacturer="Demo" Description="Wash LED de démonstration">
+    <!-- Seuls les modes DMX sont lus par l'import du guide -->
+    <DMXModes>
+      <DMXMode Name="Standard" Geometry="Body">
+        <DMXChannels>
+          <DMXChannel DMXBreak="1" Offset="1,2" Highlight="65535/2" Geometry="Body" InitialFunction="Body_Dimmer.Dimmer.Dimmer 1">
+            <LogicalChannel Attribute="Dimmer" Snap="No" Master="Grand">
+              <ChannelFunction Name="Dimmer 1" Attribute="Dimmer" DMXFrom="0/2" Default="0/2" />
+            </LogicalChannel>
+          </DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="3" Geometry="Body" InitialFunction="Body_ColorAdd_R.ColorAdd_R.Red">
+            <LogicalChannel Attribute="ColorAdd_R"><ChannelFunction Name="Red" Attribute="ColorAdd_R" DMXFrom="0/1" Default="255/1" /></LogicalChannel>
+          </DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="4" Geometry="Body" InitialFunction="Body_ColorAdd_G.ColorAdd_G.Green">
+            <LogicalChannel Attribute="ColorAdd_G"><ChannelFunction Name="Green" Attribute="ColorAdd_G" DMXFrom="0/1" Default="255/1" /></LogicalChannel>
+          </DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="5" Geometry="Body" InitialFunction="Body_ColorAdd_B.ColorAdd_B.Blue">
+            <LogicalChannel Attribute="ColorAdd_B"><ChannelFunction Name="Blue" Attribute="ColorAdd_B" DMXFrom="0/1" Default="255/1" /></LogicalChannel>
+          </DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="6" Geometry="Body" InitialFunction="Body_ColorAdd_W.ColorAdd_W.White">
+            <LogicalChannel Attribute="ColorAdd_W"><ChannelFunction Name="White" Attribute="ColorAdd_W" DMXFrom="0/1" Default="0/1" /></LogicalChannel>
+          </DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="8" Geometry="Body" InitialFunction="Body_Shutter1.Shutter1.Open">
+            <LogicalChannel Attribute="Shutter1">
+              <ChannelFunction Name="Open" Attribute="Shutter1" DMXFrom="0/1" Default="0/1" />
+              <ChannelFunction Name="Strobe" Attribute="Shutter1Strobe" DMXFrom="32/1" Default="32/1" />
+            </LogicalChannel>
+          </DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="9" Geometry="Body" InitialFunction="Body_Zoom.Zoom.Zoom">
+            <LogicalChannel Attribute="Zoom"><ChannelFunction Name="Zoom" Attribute="Zoom" DMXFrom="0/1" Default="128/1" /></LogicalChannel>
+          </DMXChannel>
+        </DMXChannels>
+      </DMXMode>
+      <DMXMode Name="RGBW" Geometry="Body">
+        <DMXChannels>
+          <DMXChannel DMXBreak="1" Offset="1" Geometry="Body"><LogicalChannel Attribute="ColorAdd_R" /></DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="2" Geometry="Body"><LogicalChannel Attribute="ColorAdd_G" /></DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="3" Geometry="Body"><LogicalChannel Attribute="ColorAdd_B" /></DMXChannel>
+          <DMXChannel DMXBreak="1" Offset="4" Geometry="Body"><LogicalChannel Attribute="ColorAdd_W" /></DMXChannel>
+        </DMXChannels>
+      </DMXMode>
+    </DMXModes>
+  </FixtureType>
+</GDTF>"#;
+
+pub fn demo_ofl() -> Vec<u8> {
+    DEMO_OFL.as_bytes().to_vec()
+}
+
+pub fn demo_gdtf() -> Vec<u8> {
+    write_stored(&[("description.xml", DEMO_GDTF_DESCRIPTION.as_bytes())])
+}
+
+// --- Composant ---
+
+/// Détail d'un paramètre importé : `Pan 16 bits · home 50 %`.
+fn parameter_text(param: &ParameterDef) -> String {
+    format!("{}{} · home {:.0} %", param.kind.label(), if param.fine { " 16 bits" } else { "" }, param.home)
+}
+
+/// Premier canal patché dont la plage d'adresses recoupe `start..start + footprint`.
+fn address_conflict(sim: &Simulator, channel: u32, start: u32, footprint: u32) -> Option<u32> {
+    sim.patch.iter().filter(|p| p.channel != channel).find_map(|p| {
+        let size = sim.profile_for(p.channel).map(FixtureProfile::footprint).unwrap_or(1);
+        (p.address < start + footprint && start < p.address + size).then_some(p.channel)
+    })
+}
+
+#[derive(Properties, PartialEq)]
+pub struct FixtureLibraryPanelProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(FixtureLibraryPanel)]
+pub fn fixture_library_panel(props: &FixtureLibraryPanelProps) -> Html {
+    let library = use_state(load_library);
+    let report = use_state(|| None::<(String, Result<ImportResult, String>)>);
+    let selected = use_state(String::new);
+    let channel_text = use_state(|| "101".to_string());
+    let address_input = use_state(|| "2/1".to_string());
+    let message = use_state(|| None::<String>);
+    // Le lecteur doit vivre jusqu'à la fin de la lecture
+    let reader = use_mut_ref(|| None::<FileReader>);
+
+    // Import : la bibliothèque est sauvegardée et le simulateur reçoit les nouveaux profils.
+    let on_import = {
+        let library = library.clone();
+        let report = report.clone();
+        let selected = selected.clone();
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |(name, result): (String, Result<ImportResult, String>)| {
+            if let Ok(imported) = &result {
+                let mut next = (*library).clone();
+                merge(&mut next, &imported.profiles);
+                save_library(&next);
+                library.set(next);
+                let mut sim = simulator.clone();
+                for profile in imported.profiles.iter().map(|p| &p.profile) {
+                    sim.profiles.retain(|p| p.id != profile.id);
+                    sim.profiles.push(profile.clone());
+                }
+                on_change.emit(sim);
+                if let Some(first) = imported.profiles.first() {
+                    selected.set(first.profile.id.clone());
+                }
+            }
+            report.set(Some((name, result)));
+        })
+    };
+    let on_file = {
+        let reader = reader.clone();
+        let on_import = on_import.clone();
+        Callback::from(move |e: Event| {
+            let Some(input) = e.target_dyn_into::<HtmlInputElement>() else { return };
+            let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+            let file = File::from(file);
+            let name = file.name();
+            let on_import = on_import.clone();
+            *reader.borrow_mut() = Some(read_as_bytes(&file, move |result| {
+                on_import.emit((name, result.map_err(|e| format!("Lecture impossible : {}", e)).and_then(|bytes| import_file(&bytes))));
+            }));
+        })
+    };
+    let on_demo = |name: &'static str, bytes: fn() -> Vec<u8>| {
+        let on_import = on_import.clone();
+        Callback::from(move |_: MouseEvent| on_import.emit((name.to_string(), import_file(&bytes()))))
+    };
+    let on_delete = |id: String| {
+        let library = library.clone();
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*library).clone();
+            next.retain(|p| p.profile.id != id);
+            save_library(&next);
+            library.set(next);
+            // Les canaux patchés avec ce profil sont dépatchés.
+            let mut sim = simulator.clone();
+            let channels: Vec<u32> = sim.patch.iter().filter(|p| p.profile_id == id).map(|p| p.channel).collect();
+            sim.patch.retain(|p| p.profile_id != id);
+            sim.profiles.retain(|p| p.id != id);
+            for ch in channels {
+                sim.levels.remove(&ch);
+                sim.selection.retain(|c| *c != ch);
+            }
+            on_change.emit(sim);
+        })
+    };
+    let on_patch = {
+        let library = library.clone();
+        let selected = selected.clone();
+        let channel_text = channel_text.clone();
+        let address_input = address_input.clone();
+        let message = message.clone();
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let result = (|| {
+                let imported = library.iter().find(|p| p.profile.id == *selected).ok_or("choisir un profil importé")?;
+                let channel = channel_text.trim().parse::<u32>().ok().filter(|c| *c > 0).ok_or_else(|| format!("canal « {} » invalide", channel_text.trim()))?;
+                let start = DmxAddress::parse(&address_input)?.absolute();
+                let footprint = imported.profile.footprint();
+                let mut sim = simulator.clone();
+                if !sim.profiles.iter().any(|p| p.id == imported.profile.id) {
+                    sim.profiles.push(imported.profile.clone());
+                }
+                if let Some(other) = address_conflict(&sim, channel, start, footprint) {
+                    return Err(format!("{} - {} recoupe le canal {}", address_text(start), address_text(start + footprint - 1), other));
+                }
+                sim.patch_channel(PatchEntry { channel, profile_id: imported.profile.id.clone(), address: start });
+                on_change.emit(sim);
+                Ok(format!("Canal {} patché en {} ({}, {} adresses)", channel, address_text(start), imported.profile.name, footprint))
+            })();
+            message.set(Some(result.unwrap_or_else(|e: String| format!("Erreur : {}", e))));
+        })
+    };
+    let on_select = {
+        let selected = selected.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                selected.set(select.value());
+            }
+        })
+    };
+    let text_input = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                state.set(input.value());
+            }
+        })
+    };
+
+    html! {
+        <div class="fixture-library">
+            <p>{ "Importer un fichier Open Fixture Library (.json, format d'export « OFL ») ou GDTF (.gdtf) : chaque mode devient un profil utilisable dans le simulateur et le plan de patch." }</p>
+            <div class="sim-input">
+                <input type="file" accept=".json,.gdtf" onchange={on_file} />
+                <button class="back-btn" onclick={on_demo("spot-250.json", demo_ofl)}>{ "🧪 Exemple OFL" }</button>
+                <button class="back-btn" onclick={on_demo("wash-7.gdtf", demo_gdtf)}>{ "🧪 Exemple GDTF" }</button>
+            </div>
+            { match &*report {
+                None => html! {},
+                Some((name, Err(e))) => html! { <p class="sim-message">{ format!("{} : {}", name, e) }</p> },
+                Some((name, Ok(imported))) => html! {
+                    <div class="sim-message">
+                        { format!("{} : {} mode(s) importé(s)", name, imported.profiles.len()) }
+                        if !imported.warnings.is_empty() {
+                            <ul class="template-errors">
+                                { for imported.warnings.iter().map(|w| html! { <li>{ w }</li> }) }
+                            </ul>
+                        }
+                    </div>
+                },
+            }}
+
+            <h3>{ format!("Bibliothèque : {} profil(s) importé(s)", library.len()) }</h3>
+            if library.is_empty() {
+                <p>{ "Aucun profil importé pour l'instant." }</p>
+            } else {
+                <table class="event-table fixture-table">
+                    <tr><th>{ "Fabricant" }</th><th>{ "Modèle" }</th><th>{ "Mode" }</th><th>{ "Format" }</th><th>{ "Empreinte" }</th><th>{ "Paramètres" }</th><th></th></tr>
+                    { for library.iter().map(|imported| html! {
+                        <tr>
+                            <td>{ &imported.manufacturer }</td>
+                            <td>{ &imported.model }</td>
+                            <td>{ &imported.mode }</td>
+                            <td>{ imported.format.label() }</td>
+                            <td>{ imported.profile.footprint() }</td>
+                            <td class="fixture-params">
+                                { for imported.profile.parameters.iter().zip(&imported.channels).map(|(param, channel)| html! {
+                                    <span class={classes!("fixture-param", (param.kind == ParamKind::Control).then_some("control"))} title={channel.clone()}>
+                                        <span class="fixture-cat">{ param.kind.category().letter() }</span>
+                                        { parameter_text(param) }
+                                    </span>
+                                })}
+                            </td>
+                            <td><button class="back-btn" onclick={on_delete(imported.profile.id.clone())}>{ "🗑" }</button></td>
+                        </tr>
+                    })}
+                </table>
+            }
+
+            <h3>{ "Patcher dans le simulateur" }</h3>
+            <div class="sim-input">
+                <select onchange={on_select}>
+                    <option selected={selected.is_empty()} value="">{ "— profil —" }</option>
+                    { for library.iter().map(|p| html! {
+                        <option value={p.profile.id.clone()} selected={*selected == p.profile.id}>{ &p.profile.name }</option>
+                    })}
+                </select>
+                <label>{ "Canal " }<input type="number" min="1" value={(*channel_text).clone()} oninput={text_input(&channel_text)} /></label>
+                <label>{ "Adresse " }<input type="text" value={(*address_input).clone()} oninput={text_input(&address_input)} placeholder="2/1 ou 513" /></label>
+                <button class="back-btn" onclick={on_patch}>{ "Patcher" }</button>
+            </div>
+            if let Some(m) = &*message {
+                <p class="sim-message">{ m }</p>
+            }
+            <div class="tips">{ "Les canaux Control (réglages, reset, octets réservés) occupent leur adresse mais restent à leur valeur par défaut : le simulateur ne les pilote pas." }</div>
+        </div>
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use yew::prelude::*;
+
+use crate::drill::DrillRecord;
+use crate::quiz::QuizResult;
+use crate::InitialData;
+
+// --- Cartes de révision ---
+// Chaque `Command` du guide donne jusqu'à trois sortes de cartes :
+// action → syntaxe, description → action, exemple → signification.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardKind {
+    ActionToSyntax,
+    DescriptionToAction,
+    ExampleToMeaning,
+}
+
+impl CardKind {
+    pub fn prompt(self) -> &'static str {
+        match self {
+            CardKind::ActionToSyntax => "Quelle est la syntaxe ?",
+            CardKind::DescriptionToAction => "Quelle commande fait cela ?",
+            CardKind::ExampleToMeaning => "Que fait cette séquence ?",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Card {
+    pub id: String,
+    pub command_id: String,
+    pub category_id: String,
+    pub kind: CardKind,
+    pub front: String,
+    pub back: String,
+}
+
+/// Catégorie d'une commande, via sa sous-catégorie.
+pub fn category_of(data: &InitialData, subcat: &str) -> Option<String> {
+    data.subcategories.iter().find(|sc| sc.id == subcat).map(|sc| sc.parent_id.clone())
+}
+
+pub fn build_cards(data: &InitialData) -> Vec<Card> {
+    let mut cards = Vec::new();
+    for cmd in &data.commands {
+        let Some(category_id) = category_of(data, &cmd.subcat) else { continue };
+        let mut push = |suffix: String, kind: CardKind, front: String, back: String| {
+            if !front.trim().is_empty() && !back.trim().is_empty() {
+                cards.push(Card {
+                    id: format!("{}:{}", cmd.id, suffix),
+                    command_id: cmd.id.clone(),
+                    category_id: category_id.clone(),
+                    kind,
+                    front,
+                    back,
+                });
+            }
+        };
+        if let Some(syntaxes) = &cmd.syntaxes {
+            push("syntax".to_string(), CardKind::ActionToSyntax, cmd.action.clone(), syntaxes.join("\n"));
+        }
+        push("action".to_string(), CardKind::DescriptionToAction, cmd.description.clone(), cmd.action.clone());
+        for (i, example) in cmd.examples.iter().flatten().enumerate() {
+            // Seuls les exemples de la forme « séquence → effet » font une carte.
+            if let Some((sequence, meaning)) = example.split_once('→') {
+                push(format!("example-{}", i), CardKind::ExampleToMeaning, sequence.trim().to_string(), meaning.trim().to_string());
+            }
+        }
+    }
+    cards
+}
+
+// --- Répétition espacée (SM-2) ---
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CardProgress {
+    pub ease: f32,
+    pub interval: u32,
+    pub repetitions: u32,
+    /// Jour (depuis l'epoch Unix) où la carte doit être revue.
+    pub due: i64,
+}
+
+impl Default for CardProgress {
+    fn default() -> Self {
+        CardProgress { ease: 2.5, interval: 0, repetitions: 0, due: 0 }
+    }
+}
+
+impl CardProgress {
+    /// Applique une réponse notée de 0 (oubli total) à 5 (parfait), selon l'algorithme SM-2.
+    pub fn review(&mut self, quality: u8, today: i64) {
+        let q = quality.min(5) as f32;
+        if quality < 3 {
+            self.repetitions = 0;
+            self.interval = 1;
+        } else {
+            self.repetitions += 1;
+            self.interval = match self.repetitions {
+                1 => 1,
+                2 => 6,
+                _ => (self.interval as f32 * self.ease).round() as u32,
+            };
+        }
+        self.ease = (self.ease + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(1.3);
+        self.due = today + self.interval as i64;
+    }
+}
+
+/// Progression d'apprentissage, sauvegardée à côté de `InitialData` dans le localStorage.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct StudyProgress {
+    pub cards: BTreeMap<String, CardProgress>,
+    /// Statistiques de l'exercice de frappe, par commande.
+    #[serde(default)]
+    pub drill: BTreeMap<String, DrillRecord>,
+    /// Défis du simulateur réussis.
+    #[serde(default)]
+    pub challenges: BTreeSet<String>,
+    /// Passages de quiz, exportables en CSV.
+    #[serde(default)]
+    pub quiz_results: Vec<QuizResult>,
+}
+
+pub const STUDY_STORAGE_KEY: &str = "eos_guide_study";
+
+/// Nouvelles cartes introduites par session.
+pub const NEW_PER_SESSION: usize = 10;
+
+pub fn today() -> i64 {
+    (js_sys::Date::now() / 86_400_000.0).floor() as i64
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeckStats {
+    pub total: usize,
+    pub new: usize,
+    pub due: usize,
+    pub learned: usize,
+}
+
+impl StudyProgress {
+    pub fn is_due(&self, card: &Card, today: i64) -> bool {
+        self.cards.get(&card.id).is_some_and(|p| p.due <= today)
+    }
+
+    /// Cartes à revoir aujourd'hui, puis quelques nouvelles.
+    pub fn session<'a>(&self, cards: &'a [Card], today: i64) -> Vec<&'a Card> {
+        let mut due: Vec<&Card> = cards.iter().filter(|c| self.is_due(c, today)).collect();
+        due.sort_by_key(|c| self.cards.get(&c.id).map(|p| p.due).unwrap_or(0));
+        due.extend(cards.iter().filter(|c| !self.cards.contains_key(&c.id)).take(NEW_PER_SESSION));
+        due
+    }
+
+    pub fn stats(&self, cards: &[&Card], today: i64) -> DeckStats {
+        let mut stats = DeckStats { total: cards.len(), ..DeckStats::default() };
+        for card in cards {
+            match self.cards.get(&card.id) {
+                None => stats.new += 1,
+                Some(p) => {
+                    if p.due <= today {
+                        stats.due += 1;
+                    }
+                    if p.repetitions > 0 {
+                        stats.learned += 1;
+                    }
+                }
+            }
+        }
+        stats
+    }
+}
+
+// --- Composant Panneau Flashcards ---
+const GRADES: [(u8, &str); 4] = [(0, "❌ À revoir"), (3, "😓 Difficile"), (4, "🙂 Bien"), (5, "😎 Facile")];
+
+#[derive(Properties, PartialEq)]
+pub struct FlashcardsPanelProps {
+    pub data: InitialData,
+    pub progress: StudyProgress,
+    pub on_progress: Callback<StudyProgress>,
+}
+
+#[function_component(FlashcardsPanel)]
+pub fn flashcards_panel(props: &FlashcardsPanelProps) -> Html {
+    let deck = use_state(|| None::<String>);
+    let revealed = use_state(|| false);
+    let cards = use_memo(props.data.clone(), build_cards);
+    let today = today();
+
+    let Some(category_id) = (*deck).clone() else {
+        // Choix du paquet : une carte par catégorie du guide.
+        return html! {
+            <div class="category-grid">
+                { for props.data.categories.iter().filter_map(|cat| {
+                    let deck_cards: Vec<&Card> = cards.iter().filter(|c| c.category_id == cat.id).collect();
+                    if deck_cards.is_empty() {
+                        return None;
+                    }
+                    let stats = props.progress.stats(&deck_cards, today);
+                    let deck = deck.clone();
+                    let id = cat.id.clone();
+                    Some(html! {
+                        <div class="category-card" onclick={Callback::from(move |_| deck.set(Some(id.clone())))}>
+                            <h3>{ &cat.icon }{ " " }{ &cat.name }</h3>
+                            <p class="deck-stats">
+                                { format!("{} cartes — {} nouvelles — {} à revoir — {} apprises", stats.total, stats.new, stats.due, stats.learned) }
+                            </p>
+                        </div>
+                    })
+                })}
+            </div>
+        };
+    };
+
+    let deck_cards: Vec<Card> = cards.iter().filter(|c| c.category_id == category_id).cloned().collect();
+    let session = props.progress.session(&deck_cards, today);
+    let back_to_decks = {
+        let deck = deck.clone();
+        Callback::from(move |_: MouseEvent| deck.set(None))
+    };
+
+    let Some(card) = session.first().map(|c| (*c).clone()) else {
+        return html! {
+            <div class="flashcards-panel">
+                <p>{ "🎉 Rien à revoir dans ce paquet pour aujourd'hui." }</p>
+                <button class="back-btn" onclick={back_to_decks}>{ "← Paquets" }</button>
+            </div>
+        };
+    };
+
+    let reveal = {
+        let revealed = revealed.clone();
+        Callback::from(move |_: MouseEvent| revealed.set(true))
+    };
+
+    html! {
+        <div class="flashcards-panel">
+            <div class="form-actions">
+                <button class="back-btn" onclick={back_to_decks}>{ "← Paquets" }</button>
+                <span class="sim-message">{ format!("{} carte(s) dans la session", session.len()) }</span>
+            </div>
+            <div class="flashcard">
+                <p class="flashcard-prompt">{ card.kind.prompt() }</p>
+                <div class="flashcard-front">{ &card.front }</div>
+                if *revealed {
+                    <div class="flashcard-back syntax">{ &card.back }</div>
+                    <div class="action-buttons">
+                        { for GRADES.iter().map(|(quality, label)| {
+                            let progress = props.progress.clone();
+                            let on_progress = props.on_progress.clone();
+                            let revealed = revealed.clone();
+                            let card_id = card.id.clone();
+                            let quality = *quality;
+                            let grade = Callback::from(move |_: MouseEvent| {
+                                let mut progress = progress.clone();
+                                progress.cards.entry(card_id.clone()).or_default().review(quality, today);
+                                revealed.set(false);
+                                on_progress.emit(progress);
+                            });
+                            html! { <button class="back-btn" onclick={grade}>{ *label }</button> }
+                        })}
+                    </div>
+                } else {
+                    <div class="action-buttons">
+                        <button class="back-btn" onclick={reveal}>{ "Voir la réponse" }</button>
+                    </div>
+                }
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals_grow_with_ease() {
+        let mut card = CardProgress::default();
+        let mut intervals = Vec::new();
+        for day in 0..4 {
+            card.review(4, day);
+            intervals.push(card.interval);
+        }
+        // Une note de 4 laisse la facilité à 2,5 : 1, 6, puis 6 × 2,5 = 15, 15 × 2,5 ≈ 38
+        assert_eq!(intervals, vec![1, 6, 15, 38]);
+        assert!((card.ease - 2.5).abs() < 1e-5);
+        assert_eq!((card.repetitions, card.due), (4, 3 + 38));
+
+        let mut perfect = CardProgress::default();
+        perfect.review(5, 0);
+        perfect.review(5, 1);
+        assert!((perfect.ease - 2.7).abs() < 1e-5);
+        perfect.review(5, 7);
+        assert_eq!(perfect.interval, 16);
+    }
+
+    #[test]
+    fn low_grade_resets_repetitions() {
+        let mut card = CardProgress::default();
+        card.review(5, 0);
+        card.review(5, 1);
+        card.review(2, 7);
+        assert_eq!((card.repetitions, card.interval, card.due), (0, 1, 8));
+        // Reprise depuis le début de la série
+        card.review(4, 8);
+        assert_eq!((card.repetitions, card.interval), (1, 1));
+    }
+
+    #[test]
+    fn ease_never_drops_below_floor() {
+        let mut card = CardProgress::default();
+        for day in 0..10 {
+            card.review(0, day);
+        }
+        assert_eq!(card.ease, 1.3);
+        card.review(3, 10);
+        assert!(card.ease >= 1.3);
+    }
+}
@@ -4,6 +4,51 @@ use serde::{Deserialize, Serialize};
 use web_sys::console; // Pour les erreurs console
 use yew::prelude::*;
 
+mod artnet;
+mod capture;
+mod challenges;
+mod cues;
+mod drill;
+mod effects;
+mod event_list;
+mod facepanel;
+mod fixture_import;
+mod flashcards;
+mod macros;
+mod msc;
+mod network_plan;
+mod normalizer;
+mod notation;
+mod osc;
+mod osc_codec;
+mod partitions;
+mod patch_plan;
+mod paths;
+mod quiz;
+mod remote;
+mod sacn;
+mod search;
+mod shortcuts;
+mod simulator;
+mod templates;
+mod timecode;
+mod tools;
+mod training;
+mod undo;
+mod xml;
+mod zip;
+
+use facepanel::Facepanel;
+use flashcards::{StudyProgress, STUDY_STORAGE_KEY};
+use paths::{ContinueCard, PathsProgress, PathsView, PATHS_STORAGE_KEY};
+use remote::SendToConsole;
+use search::{command_badges, normalize_tags, CommandFilter, FilterChips, MetadataEditor, SearchResults};
+use shortcuts::keyboard_html;
+use simulator::{Simulator, SimulatorView};
+use templates::{CommandTemplate, TemplateWizard};
+use tools::ToolsView;
+use training::TrainingView;
+
 // --- Structures de données ---
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 struct Category {
@@ -16,6 +61,8 @@ struct Category {
 struct Subcategory {
     id: String,
     name: String,
+    // Le JSON intégré écrit `parentId` ; `parent_id` est celui des états sauvegardés par les versions précédentes.
+    #[serde(rename = "parentId", alias = "parent_id")]
     parent_id: String,
     #[serde(rename = "type")]
     sub_type: String, // Renommé pour éviter conflit avec mot-clé Rust
@@ -30,13 +77,62 @@ struct Command {
     syntaxes: Option<Vec<String>>,
     examples: Option<Vec<String>>,
     tips: Option<Vec<String>>,
+    /// Difficulté ; absente pour les commandes enregistrées avant son introduction.
+    /// Une valeur inconnue est ignorée plutôt que de rendre toutes les données illisibles.
+    #[serde(default, deserialize_with = "lenient_level")]
+    level: Option<Level>,
+    /// Étiquettes libres (« tracking », « sécurité »…), normalisées par `normalize_tags`.
+    #[serde(default, deserialize_with = "lenient_tags")]
+    tags: Vec<String>,
+    /// Syntaxes modèles à paramètres, remplies par l'assistant de la fiche.
+    #[serde(default)]
+    templates: Vec<CommandTemplate>,
+}
+
+/// Niveaux du guide, de « Novice » à « Expert ».
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+enum Level {
+    Novice,
+    Intermediate,
+    Expert,
+}
+
+impl Level {
+    const ALL: [Level; 3] = [Level::Novice, Level::Intermediate, Level::Expert];
+
+    fn label(self) -> &'static str {
+        match self {
+            Level::Novice => "🌱 Novice",
+            Level::Intermediate => "🌿 Intermédiaire",
+            Level::Expert => "🌳 Expert",
+        }
+    }
+}
+
+fn lenient_level<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Level>, D::Error> {
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|v| serde_json::from_value(v).ok()))
+}
+
+/// Garde les étiquettes textuelles, normalisées ; les autres valeurs sont écartées.
+fn lenient_tags<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let values = Option::<Vec<serde_json::Value>>::deserialize(deserializer)?.unwrap_or_default();
+    let tags: Vec<String> = values.into_iter().filter_map(|v| v.as_str().map(str::to_string)).collect();
+    Ok(normalize_tags(&tags))
 }
 
+/// Version des données sauvegardées : les données plus anciennes sont complétées une fois.
+/// 1 : niveaux, étiquettes et modèles ; 2 : commandes intégrées ajoutées depuis la sauvegarde.
+const METADATA_VERSION: u32 = 2;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 struct InitialData {
     categories: Vec<Category>,
     subcategories: Vec<Subcategory>,
     commands: Vec<Command>,
+    #[serde(default, rename = "metadataVersion")]
+    metadata_version: u32,
 }
 
 // --- Données initiales (votre INITIAL_DATA) ---
@@ -113,11 +209,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "at",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe", "niveaux"],
             "action": "Touche [At]",
             "description": "Définit un niveau (intensité, position, etc.).",
             "syntaxes": [
                 "[1][At][50][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[chan][At][niveau][Enter]",
+                    "sentence": "Met le canal {chan} à {niveau} %.",
+                    "params": [
+                        { "name": "chan", "kind": "channel" },
+                        { "name": "niveau", "kind": "level" }
+                    ]
+                }
+            ],
             "examples": [
                 "[1][At][50][Enter] → met le canal 1 à 50%."
             ],
@@ -128,11 +236,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "thru",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe", "sélection"],
             "action": "Touche [Thru]",
             "description": "Sélectionne une plage de canaux.",
             "syntaxes": [
                 "[1][Thru][10]"
             ],
+            "templates": [
+                {
+                    "syntax": "[plage][At][niveau][Enter]",
+                    "sentence": "Met les canaux {plage} à {niveau} %.",
+                    "params": [
+                        { "name": "plage", "kind": "range" },
+                        { "name": "niveau", "kind": "level" }
+                    ]
+                }
+            ],
             "examples": [
                 "[1][Thru][10][At][Full][Enter] → allume les canaux 1 à 10 à 100%."
             ],
@@ -143,6 +263,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "full",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe", "niveaux"],
             "action": "Touche [Full]",
             "description": "Raccourci pour [At][Full][Enter].",
             "syntaxes": [
@@ -160,6 +282,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "out",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe", "niveaux"],
             "action": "Touche [Out]",
             "description": "Raccourci pour [At][0][Enter].",
             "syntaxes": [
@@ -177,6 +301,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "softkey",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe"],
             "action": "Softkey { }",
             "description": "Bouton à l’écran, accessible via les touches sous l’écran.",
             "syntaxes": [
@@ -194,6 +320,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "user-0",
             "subcat": "syntax-base",
+            "level": "expert",
+            "tags": ["syntaxe", "multi-console"],
             "action": "Utilisateur en arrière-plan <U0>",
             "description": "Exécute une commande sans apparaître sur la ligne principale.",
             "syntaxes": [
@@ -209,6 +337,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "direct-select",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe"],
             "action": "Direct Select « »",
             "description": "Boutons physiques en haut de la console.",
             "syntaxes": [
@@ -225,6 +355,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "enter",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe"],
             "action": "Touche [Enter]",
             "description": "Valide la commande. Certaines commandes s’auto-valident.",
             "syntaxes": [
@@ -242,6 +374,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "format",
             "subcat": "syntax-base",
+            "level": "intermediate",
+            "tags": ["affichage"],
             "action": "Basculer le format d'affichage [Format]",
             "description": "Passe d’un affichage détaillé à un affichage simplifié.",
             "syntaxes": [
@@ -257,6 +391,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "help",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["aide"],
             "action": "Aide contextuelle [Help]",
             "description": "Affiche la fonction, description, et syntaxe d’une touche.",
             "syntaxes": [
@@ -272,6 +408,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "clear",
             "subcat": "syntax-base",
+            "level": "novice",
+            "tags": ["syntaxe"],
             "action": "Effacer la ligne de commande [Clear]",
             "description": "Annule la commande en cours.",
             "syntaxes": [
@@ -287,11 +425,24 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sneak",
             "subcat": "advanced-base",
+            "level": "intermediate",
+            "tags": ["temps"],
             "action": "Touche [Sneak]",
             "description": "Applique un fondu à la valeur manuelle (non enregistrée).",
             "syntaxes": [
                 "[1][At][50][Sneak][2][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[plage][At][niveau][Sneak][temps][Enter]",
+                    "sentence": "Amène les canaux {plage} à {niveau} % en {temps} s.",
+                    "params": [
+                        { "name": "plage", "kind": "range" },
+                        { "name": "niveau", "kind": "level" },
+                        { "name": "temps", "kind": "time" }
+                    ]
+                }
+            ],
             "examples": [
                 "[1][At][50][Sneak][2][Enter] → le canal 1 monte à 50% en 2 secondes."
             ],
@@ -302,6 +453,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "shift-sneak",
             "subcat": "advanced-base",
+            "level": "expert",
+            "tags": ["temps"],
             "action": "Touche [Shift] + [Sneak]",
             "description": "Rend les données manuelles non manuelles (désactive Update/Record Only).",
             "syntaxes": [
@@ -319,6 +472,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "staging-mode",
             "subcat": "live-blind-base",
+            "level": "expert",
+            "tags": ["préparation"],
             "action": "Mode Staging",
             "description": "Mode de prévisualisation pour les techniciens.",
             "syntaxes": [
@@ -334,6 +489,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "live-mode",
             "subcat": "live-blind-base",
+            "level": "novice",
+            "tags": ["affichage"],
             "action": "Basculer en mode Live",
             "description": "Active la sortie DMX.",
             "syntaxes": [
@@ -349,6 +506,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "blind-mode",
             "subcat": "live-blind-base",
+            "level": "novice",
+            "tags": ["affichage", "préparation"],
             "action": "Basculer en mode Blind",
             "description": "Édition sans sortie DMX.",
             "syntaxes": [
@@ -364,6 +523,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "select-last",
             "subcat": "live-blind-base",
+            "level": "novice",
+            "tags": ["sélection"],
             "action": "Sélectionner la dernière sélection",
             "description": "Rappelle la dernière sélection de canaux.",
             "syntaxes": [
@@ -379,6 +540,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "channel-check",
             "subcat": "live-blind-base",
+            "level": "intermediate",
+            "tags": ["diagnostic"],
             "action": "Vérifier un canal (Channel Check)",
             "description": "Test visuel temporaire d’un canal.",
             "syntaxes": [
@@ -394,11 +557,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "address-at-level",
             "subcat": "live-blind-base",
+            "level": "expert",
+            "tags": ["diagnostic", "dmx"],
             "action": "Address At Level",
             "description": "Permet de piloter une adresse DMX directement.",
             "syntaxes": [
                 "[Address][513][At][50][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Address][adresse][At][niveau][Enter]",
+                    "sentence": "Met l’adresse {adresse} à {niveau} %.",
+                    "params": [
+                        { "name": "adresse", "kind": "address" },
+                        { "name": "niveau", "kind": "level" }
+                    ]
+                }
+            ],
             "examples": [
                 "[Address][513][At][50][Enter] → met l’adresse 513 à 50 %."
             ],
@@ -409,6 +584,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "address-check",
             "subcat": "live-blind-base",
+            "level": "intermediate",
+            "tags": ["diagnostic", "dmx"],
             "action": "Address Check",
             "description": "Vérifie la valeur d’une adresse DMX.",
             "syntaxes": [
@@ -424,6 +601,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "flash",
             "subcat": "live-blind-base",
+            "level": "novice",
+            "tags": ["diagnostic"],
             "action": "Flash temporaire",
             "description": "Active un canal tant que la touche est enfoncée.",
             "syntaxes": [
@@ -440,12 +619,24 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "patch-create",
             "subcat": "patch-base",
+            "level": "intermediate",
+            "tags": ["patch", "dmx"],
             "action": "Créer un patch",
             "description": "Associe un canal Eos à une adresse DMX physique.",
             "syntaxes": [
                 "[1][Patch][Enter]",
                 "[1][Patch][513][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[chan][Patch][adresse][Enter]",
+                    "sentence": "Patche le canal {chan} à l’adresse {adresse}.",
+                    "params": [
+                        { "name": "chan", "kind": "channel" },
+                        { "name": "adresse", "kind": "address" }
+                    ]
+                }
+            ],
             "examples": [
                 "[1][Patch][513][Enter] → canal 1 = adresse DMX 513."
             ],
@@ -453,9 +644,31 @@ const INITIAL_DATA_JSON: &str = r#"
                 "Essentiel pour contrôler les projecteurs."
             ]
         },
+        {
+            "id": "patch-universe-address",
+            "subcat": "patch-base",
+            "level": "novice",
+            "tags": ["patch", "dmx"],
+            "action": "Adresser en univers/adresse",
+            "description": "Une adresse DMX se donne en absolu (1 à 512 pour l’univers 1, 513 à 1024 pour l’univers 2…) ou en univers/adresse. Absolu = (univers − 1) × 512 + adresse.",
+            "syntaxes": [
+                "[1][Patch][3/1][Enter]",
+                "[1][Patch][1025][Enter]"
+            ],
+            "examples": [
+                "1025 = 3/1 ; 512 = 1/512 ; 513 = 2/1.",
+                "Un spot de 10 adresses patché en 1/507 déborde sur 2/1 à 2/4 : à éviter."
+            ],
+            "tips": [
+                "Aligner les départs (1, 11, 21…) facilite la lecture du patch et le réglage des projecteurs.",
+                "🧰 Outils → 🧮 Plan de patch calcule l’adressage de tout un kit, signale chevauchements et débordements et exporte le patch en CSV pour l’import de la console (File > Import > CSV)."
+            ]
+        },
         {
             "id": "patch-edit",
             "subcat": "patch-base",
+            "level": "intermediate",
+            "tags": ["patch", "dmx"],
             "action": "Éditer un patch",
             "description": "Modifie les propriétés d’un canal patché.",
             "syntaxes": [
@@ -472,6 +685,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "patch-clear",
             "subcat": "patch-base",
+            "level": "intermediate",
+            "tags": ["patch", "dmx"],
             "action": "Effacer un patch",
             "description": "Supprime un ou plusieurs canaux du patch.",
             "syntaxes": [
@@ -488,6 +703,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "fixture-type",
             "subcat": "patch-base",
+            "level": "intermediate",
+            "tags": ["patch", "asservis"],
             "action": "Sélectionner un type de fixture",
             "description": "Choisir un modèle de projecteur dans la bibliothèque.",
             "syntaxes": [
@@ -500,9 +717,30 @@ const INITIAL_DATA_JSON: &str = r#"
                 "Détermine les paramètres et canaux disponibles."
             ]
         },
+        {
+            "id": "fixture-import",
+            "subcat": "patch-base",
+            "level": "expert",
+            "tags": ["patch", "asservis", "gdtf"],
+            "action": "Lire un profil OFL ou GDTF",
+            "description": "Un profil de fixture décrit ses modes : pour chaque mode, la liste ordonnée de ses canaux DMX (l’empreinte), le paramètre de chacun et sa valeur par défaut. Open Fixture Library (JSON) et GDTF (archive .gdtf contenant description.xml) sont les deux formats ouverts les plus courants.",
+            "syntaxes": [
+                "[1][Patch][Enter] → {Fixture Type} → [Select]"
+            ],
+            "examples": [
+                "Un spot en mode « Extended » : Dimmer + Dimmer fine, Pan + Pan fine, Tilt + Tilt fine… = 13 adresses ; le même en « Basic » n’en prend que 9.",
+                "En GDTF, Offset=\"1,2\" désigne un paramètre 16 bits sur les adresses 1 et 2, Default=\"32768/2\" sa valeur par défaut (50 %)."
+            ],
+            "tips": [
+                "Toujours vérifier que le mode choisi dans le patch est celui réglé sur le projecteur : une empreinte différente décale toutes les adresses suivantes.",
+                "🎛️ Simulateur → 💡 Fixtures importe des fichiers OFL ou GDTF, les garde dans le navigateur et permet de les patcher ; ils apparaissent aussi dans 🧰 Outils → 🧮 Plan de patch."
+            ]
+        },
         {
             "id": "unpatch",
             "subcat": "patch-base",
+            "level": "intermediate",
+            "tags": ["patch", "dmx"],
             "action": "Dépatcher un canal",
             "description": "Supprime l’adresse DMX d’un canal.",
             "syntaxes": [
@@ -518,6 +756,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "swap-channels",
             "subcat": "patch-base",
+            "level": "intermediate",
+            "tags": ["patch"],
             "action": "Swapper deux canaux",
             "description": "Échange les adresses DMX de deux canaux.",
             "syntaxes": [
@@ -533,6 +773,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "device-list",
             "subcat": "patch-base",
+            "level": "expert",
+            "tags": ["patch", "rdm"],
             "action": "Utiliser Device List",
             "description": "Affiche la liste des dispositifs patchés.",
             "syntaxes": [
@@ -548,11 +790,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "group-create",
             "subcat": "groups-base",
+            "level": "novice",
+            "tags": ["sélection"],
             "action": "Créer un Group",
             "description": "Stocke une sélection de canaux pour rappel rapide.",
             "syntaxes": [
                 "[1][Thru][10][Group][1][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[plage][Group][groupe][Enter]",
+                    "sentence": "Enregistre les canaux {plage} dans le Group {groupe}.",
+                    "params": [
+                        { "name": "plage", "kind": "range" },
+                        { "name": "groupe", "kind": "number", "label": "Group", "max": 1000 }
+                    ]
+                }
+            ],
             "examples": [
                 "Group 1 = fronts, Group 2 = backlights."
             ],
@@ -563,6 +817,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "group-recall",
             "subcat": "groups-base",
+            "level": "novice",
+            "tags": ["sélection"],
             "action": "Rappeler un Group",
             "description": "Sélectionne les canaux d’un group.",
             "syntaxes": [
@@ -578,6 +834,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "palette-create",
             "subcat": "palettes-base",
+            "level": "intermediate",
+            "tags": ["palettes", "asservis"],
             "action": "Créer une palette",
             "description": "Stocke une ou plusieurs valeurs (couleur, position, etc.).",
             "syntaxes": [
@@ -593,6 +851,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "palette-apply",
             "subcat": "palettes-base",
+            "level": "novice",
+            "tags": ["palettes", "asservis"],
             "action": "Appliquer une palette",
             "description": "Applique les valeurs d’une palette à des canaux sélectionnés.",
             "syntaxes": [
@@ -608,11 +868,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sub-create",
             "subcat": "submasters-base",
+            "level": "intermediate",
+            "tags": ["faders"],
             "action": "Créer un Submaster",
             "description": "Crée un contrôle maître pour un ensemble de canaux.",
             "syntaxes": [
                 "[1][Thru][10][Sub][101][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[plage][Sub][sub][Enter]",
+                    "sentence": "Enregistre les canaux {plage} dans le Submaster {sub}.",
+                    "params": [
+                        { "name": "plage", "kind": "range" },
+                        { "name": "sub", "kind": "sub" }
+                    ]
+                }
+            ],
             "examples": [
                 "Sub 101 = house lights à 100 %."
             ],
@@ -623,11 +895,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sub-recall",
             "subcat": "submasters-base",
+            "level": "novice",
+            "tags": ["faders"],
             "action": "Rappeler un Submaster",
             "description": "Active un submaster avec son niveau.",
             "syntaxes": [
                 "[Sub][101][50][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Sub][sub][At][niveau][Enter]",
+                    "sentence": "Monte le Submaster {sub} à {niveau} %.",
+                    "params": [
+                        { "name": "sub", "kind": "sub" },
+                        { "name": "niveau", "kind": "level" }
+                    ]
+                }
+            ],
             "examples": [
                 "[Sub][101][50][Enter] → active Sub 101 à 50 %."
             ],
@@ -638,6 +922,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sub-hold",
             "subcat": "submasters-base",
+            "level": "intermediate",
+            "tags": ["faders"],
             "action": "Activer le mode Hold",
             "description": "Empêche le Submaster de s’éteindre automatiquement.",
             "syntaxes": [
@@ -653,11 +939,22 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sub-solo",
             "subcat": "submasters-base",
+            "level": "intermediate",
+            "tags": ["faders"],
             "action": "Utiliser le mode Solo",
             "description": "Isole un Submaster pour édition.",
             "syntaxes": [
                 "[Sub][101]{Solo}[Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Sub][sub]{Solo}[Enter]",
+                    "sentence": "Passe le Submaster {sub} en mode Solo.",
+                    "params": [
+                        { "name": "sub", "kind": "sub" }
+                    ]
+                }
+            ],
             "examples": [
                 "Seul le Sub 101 est actif."
             ],
@@ -668,11 +965,24 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sub-fade",
             "subcat": "submasters-base",
+            "level": "intermediate",
+            "tags": ["faders", "temps"],
             "action": "Configurer les temps de fondu d’un Submaster",
             "description": "Définit les temps Up/Down pour un Submaster.",
             "syntaxes": [
                 "[Sub][101][Time][2][Time][2][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Sub][sub][Time][montee][Time][descente][Enter]",
+                    "sentence": "Règle le Submaster {sub} : montée en {montee} s, descente en {descente} s.",
+                    "params": [
+                        { "name": "sub", "kind": "sub" },
+                        { "name": "montee", "kind": "time", "label": "Montée (s)" },
+                        { "name": "descente", "kind": "time", "label": "Descente (s)" }
+                    ]
+                }
+            ],
             "examples": [
                 "Sub 101 : Up=2s, Down=2s."
             ],
@@ -683,6 +993,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sub-exempt",
             "subcat": "submasters-base",
+            "level": "intermediate",
+            "tags": ["faders", "sécurité"],
             "action": "Exclure du Grandmaster",
             "description": "Empêche un canal/submaster d’être affecté par le Grandmaster.",
             "syntaxes": [
@@ -698,11 +1010,22 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-record",
             "subcat": "cues-base",
+            "level": "novice",
+            "tags": ["cues", "tracking"],
             "action": "Enregistrer un Cue",
             "description": "Stocke l’état actuel dans un cue.",
             "syntaxes": [
                 "[1][Thru][10][At][Full][Enter] → [Record][Cue][1][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Record][Cue][cue][Enter]",
+                    "sentence": "Enregistre l’état actuel dans la Cue {cue}.",
+                    "params": [
+                        { "name": "cue", "kind": "cue" }
+                    ]
+                }
+            ],
             "examples": [
                 "Cue 1 = fronts à 100 %."
             ],
@@ -713,11 +1036,22 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-go",
             "subcat": "cues-base",
+            "level": "novice",
+            "tags": ["cues", "playback"],
             "action": "Aller à un Cue",
             "description": "Joue un cue existant.",
             "syntaxes": [
                 "[Go To Cue][5][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Go To Cue][cue][Enter]",
+                    "sentence": "Va directement à la Cue {cue}.",
+                    "params": [
+                        { "name": "cue", "kind": "cue" }
+                    ]
+                }
+            ],
             "examples": [
                 "[Go To Cue][1][Enter] pour le premier cue."
             ],
@@ -728,11 +1062,22 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-update",
             "subcat": "cues-base",
+            "level": "novice",
+            "tags": ["cues", "tracking"],
             "action": "Mettre à jour un Cue",
             "description": "Modifie un cue avec les valeurs actuelles.",
             "syntaxes": [
                 "[1][At][50][Enter] → [Update][Cue][1][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Update][Cue][cue][Enter]",
+                    "sentence": "Met à jour la Cue {cue} avec les modifications en cours.",
+                    "params": [
+                        { "name": "cue", "kind": "cue" }
+                    ]
+                }
+            ],
             "examples": [
                 "Modifier l’intensité du canal 1 dans le Cue 1."
             ],
@@ -743,6 +1088,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-link",
             "subcat": "cues-base",
+            "level": "intermediate",
+            "tags": ["cues"],
             "action": "Lier des Cues",
             "description": "Fait enchaîner automatiquement un cue vers le suivant.",
             "syntaxes": [
@@ -758,6 +1105,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-mark",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "asservis"],
             "action": "Système de Marks (M/m) et AutoMark",
             "description": "Prépare des mouvements non-intensité avant que l’intensité ne revienne.",
             "syntaxes": [
@@ -775,6 +1124,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-minus-links",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "playback"],
             "action": "Utiliser {Minus Links} dans Go To Cue",
             "description": "Exécute un cue sans déclencher ses liens.",
             "syntaxes": [
@@ -790,6 +1141,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-complete",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "playback"],
             "action": "Utiliser {Complete} dans Go To Cue",
             "description": "Exécute un cue et tous ses liens en chaîne.",
             "syntaxes": [
@@ -805,6 +1158,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "cue-out",
             "subcat": "cues-base",
+            "level": "intermediate",
+            "tags": ["cues", "playback"],
             "action": "Utiliser Go To Cue Out",
             "description": "Réinitialise tous les paramètres et revient au premier cue.",
             "syntaxes": [
@@ -820,6 +1175,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "freeze",
             "subcat": "cues-base",
+            "level": "intermediate",
+            "tags": ["playback", "sécurité"],
             "action": "Utiliser [Freeze]",
             "description": "Gèle les valeurs actuelles.",
             "syntaxes": [
@@ -835,6 +1192,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "release",
             "subcat": "cues-base",
+            "level": "intermediate",
+            "tags": ["playback"],
             "action": "Utiliser [Release]",
             "description": "Masque des données dans un cue.",
             "syntaxes": [
@@ -850,6 +1209,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "release-all",
             "subcat": "cues-base",
+            "level": "intermediate",
+            "tags": ["playback", "sécurité"],
             "action": "Utiliser [Release All]",
             "description": "Masque toutes les données manuelles.",
             "syntaxes": [
@@ -865,6 +1226,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "block",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "tracking"],
             "action": "Utiliser [Block]",
             "description": "Empêche qu’un paramètre soit modifié.",
             "syntaxes": [
@@ -880,6 +1243,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "trace",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "tracking"],
             "action": "Utiliser [Trace]",
             "description": "Modifie des valeurs dans les cues précédents.",
             "syntaxes": [
@@ -895,6 +1260,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "rem-dim",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "tracking"],
             "action": "Supprimer le tracking (Rem Dim)",
             "description": "Empêche un canal de revenir à son ancien niveau.",
             "syntaxes": [
@@ -910,6 +1277,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "preheat",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues"],
             "action": "Utiliser Preheat",
             "description": "Chauffe les filaments avant un fondu montant.",
             "syntaxes": [
@@ -925,12 +1294,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "record-only",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "tracking"],
             "action": "Utiliser [Record Only]",
             "description": "Enregistre uniquement les données manuelles.",
             "syntaxes": [
                 "[Record Only][Cue][3][Enter]",
                 "[1][At][50][Enter][Record Only][Cue][4][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Record Only][Cue][cue][Enter]",
+                    "sentence": "Enregistre uniquement les valeurs manuelles dans la Cue {cue}.",
+                    "params": [
+                        { "name": "cue", "kind": "cue" }
+                    ]
+                }
+            ],
             "examples": [
                 "[Record Only][Cue][3][Enter] → enregistre uniquement les données manuelles dans le cue 3.",
                 "[1][At][50][Enter][Record Only][Cue][4][Enter] → enregistre l'état manuel du canal 1 dans le cue 4."
@@ -943,6 +1323,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "q-only",
             "subcat": "cues-base",
+            "level": "expert",
+            "tags": ["cues", "tracking"],
             "action": "Utiliser [Q Only]",
             "description": "Empêche le tracking vers le cue suivant.",
             "syntaxes": [
@@ -960,6 +1342,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "effect-create",
             "subcat": "effects-base",
+            "level": "intermediate",
+            "tags": ["effets"],
             "action": "Créer un Effect",
             "description": "Crée un effet (gobo rotate, dimmer chase, etc.).",
             "syntaxes": [
@@ -976,6 +1360,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "macro-learn",
             "subcat": "macros-base",
+            "level": "intermediate",
+            "tags": ["macros"],
             "action": "Enregistrer une Macro avec [Learn]",
             "description": "Capture une séquence de touches.",
             "syntaxes": [
@@ -991,6 +1377,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "macro-background",
             "subcat": "macros-base",
+            "level": "expert",
+            "tags": ["macros"],
             "action": "Exécuter une macro en arrière-plan",
             "description": "Permet d’exécuter des commandes sans perturber la ligne principale.",
             "syntaxes": [
@@ -1006,6 +1394,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "macro-wait",
             "subcat": "macros-base",
+            "level": "expert",
+            "tags": ["macros", "temps"],
             "action": "Utiliser {Wait} dans une Macro",
             "description": "Ajoute une pause dans une macro.",
             "syntaxes": [
@@ -1021,6 +1411,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "macro-loop",
             "subcat": "macros-base",
+            "level": "expert",
+            "tags": ["macros"],
             "action": "Utiliser {Loop} dans une Macro",
             "description": "Crée une boucle dans une macro.",
             "syntaxes": [
@@ -1036,6 +1428,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "ms-create",
             "subcat": "magic-sheets-base",
+            "level": "expert",
+            "tags": ["magic-sheets"],
             "action": "Créer un Magic Sheet",
             "description": "Page personnalisée pour accès rapide.",
             "syntaxes": [
@@ -1051,6 +1445,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "ms-indicator",
             "subcat": "magic-sheets-base",
+            "level": "expert",
+            "tags": ["magic-sheets"],
             "action": "Créer un indicateur visuel",
             "description": "Affiche visuellement si un Submaster est actif.",
             "syntaxes": [
@@ -1067,6 +1463,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "home-tab",
             "subcat": "magic-sheets-base",
+            "level": "expert",
+            "tags": ["magic-sheets"],
             "action": "Créer une page d'accueil (Home Tab)",
             "description": "Définit un Magic Sheet comme page d'accueil.",
             "syntaxes": [
@@ -1082,6 +1480,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "sacn-input",
             "subcat": "show-control-base",
+            "level": "expert",
+            "tags": ["réseau", "sacn"],
             "action": "sACN Input Monitor",
             "description": "Affiche les valeurs sACN entrantes en temps réel.",
             "syntaxes": [
@@ -1091,12 +1491,108 @@ const INITIAL_DATA_JSON: &str = r#"
                 "Afficher les valeurs RGB(A) d’un univers sACN."
             ],
             "tips": [
-                "Les objets sACN se mettent à jour en temps réel."
+                "Les objets sACN se mettent à jour en temps réel.",
+                "Chaque univers a son adresse multicast : 239.255.<univers ÷ 256>.<univers mod 256>, port 5568.",
+                "Deux sources à la même priorité sur un univers sont fusionnées en HTP : le backup doit rester en dessous du pupitre principal.",
+                "🧰 Outils → 📶 sACN analyse une capture Wireshark : niveaux par source, trous de séquence et conflits de priorité."
+            ]
+        },
+        {
+            "id": "network-ip-plan",
+            "subcat": "network-base",
+            "level": "intermediate",
+            "tags": ["réseau", "ip"],
+            "action": "Planifier les adresses IP du système",
+            "description": "Consoles, RPU, gateways, nodes et tablettes doivent tous être dans le même sous-réseau, chacun avec une adresse unique. On réserve une plage par type d’appareil pour retrouver facilement qui est qui.",
+            "examples": [
+                "Sous-réseau = adresse de réseau / préfixe (10.101.0.0/16 = masque 255.255.0.0)",
+                "Adresses d’hôtes : de réseau + 1 à broadcast − 1",
+                "Plan d’exemple en 10.101.0.0/16 : console principale 10.101.100.101, backup 10.101.100.102, RPU 10.101.100.111, gateways 10.101.1.101 et 10.101.1.102, node 10.101.2.101, tablette 10.101.50.101.",
+                "Le même plan passé en 192.168.1.0/24 garde les mêmes fins d’adresse : console 192.168.1.101, RPU 192.168.1.111 ; un /24 n’offre que 254 adresses."
+            ],
+            "tips": [
+                "Noter l’adresse imposée d’un appareil qui ne se règle pas facilement (node en DHCP désactivé, tablette) avant de répartir les autres.",
+                "🧰 Outils → 🗺️ Plan réseau attribue les adresses par type, signale doublons et adresses hors sous-réseau et imprime la fiche réseau."
+            ]
+        },
+        {
+            "id": "network-subnet-check",
+            "subcat": "network-base",
+            "level": "intermediate",
+            "tags": ["réseau", "ip", "diagnostic"],
+            "action": "Vérifier sous-réseau et conflits d’adresse",
+            "description": "Un appareil hors du sous-réseau ne voit pas la console ; deux appareils sur la même adresse se perdent l’un l’autre par intermittence. L’adresse de réseau et celle de broadcast ne s’attribuent jamais.",
+            "examples": [
+                "IP ET masque = adresse de réseau : même résultat = même sous-réseau",
+                "Broadcast = adresse de réseau OU (NON masque)",
+                "Tablette restée en 192.168.1.50 sur un système en 10.101.0.0/16 : hors sous-réseau, elle ne trouve pas la console.",
+                "Avec un masque 255.255.255.0, 10.101.1.101 (gateway) et 10.101.100.101 (console) ne sont plus dans le même réseau : la gateway ne reçoit plus rien.",
+                "En 10.101.0.0/16, 10.101.255.255 est l’adresse de broadcast : à ne donner à aucun appareil."
+            ],
+            "tips": [
+                "Le même masque doit être réglé partout : un appareil en /24 dans un système en /16 ne voit qu’une partie des autres.",
+                "🧰 Outils → 🗺️ Plan réseau : une adresse imposée en double ou hors réseau est surlignée sur la fiche."
+            ]
+        },
+        {
+            "id": "sacn-multicast-address",
+            "subcat": "network-base",
+            "level": "expert",
+            "tags": ["réseau", "sacn", "multicast"],
+            "action": "Calculer le groupe multicast sACN d’un univers",
+            "description": "Chaque univers sACN est envoyé sur son propre groupe multicast ; un récepteur s’abonne (IGMP) aux seuls univers qu’il sort en DMX.",
+            "examples": [
+                "Groupe = 239.255.<univers ÷ 256>.<univers mod 256>, port UDP 5568",
+                "Univers de 1 à 63999",
+                "Univers 1 = 239.255.0.1 ; univers 256 = 239.255.1.0 ; univers 300 = 239.255.1.44.",
+                "Plan d’exemple : la gateway jardin s’abonne à 239.255.0.1 et 239.255.0.2, le node cintres à 239.255.0.5 et 239.255.0.6."
+            ],
+            "tips": [
+                "Sur un switch géré, activer l’IGMP snooping (avec un querier) pour que chaque node ne reçoive que ses univers.",
+                "Un univers sorti par un node mais émis par aucune console reste figé sur son dernier état : 🧰 Outils → 🗺️ Plan réseau le signale."
+            ]
+        },
+        {
+            "id": "artnet-port-address",
+            "subcat": "network-base",
+            "level": "intermediate",
+            "tags": ["réseau", "art-net", "sacn"],
+            "action": "Numéroter les univers Art-Net",
+            "description": "Un univers Art-Net est une Port-Address sur 15 bits : Net (0-127), Sub-Net (0-15) et Universe (0-15), notée net:sub:uni. Eos et la plupart des nodes font correspondre Art-Net 0:0:0 à l’univers 1.",
+            "examples": [
+                "Port-Address = Net × 256 + Sub-Net × 16 + Universe",
+                "Univers Eos / sACN = Port-Address + 1",
+                "Art-Net 0:1:0 = Port-Address 16 = univers sACN 17.",
+                "Univers sACN 300 = Port-Address 299 = Art-Net 1:2:11."
+            ],
+            "tips": [
+                "Certains logiciels comptent Art-Net à partir de 1 : un décalage d’un univers entre Art-Net et sACN vient presque toujours de là.",
+                "🧰 Outils → 🌐 Art-Net convertit dans les deux sens, avec la convention choisie."
+            ]
+        },
+        {
+            "id": "artnet-sacn-crosscheck",
+            "subcat": "network-base",
+            "level": "expert",
+            "tags": ["réseau", "art-net", "sacn", "diagnostic"],
+            "action": "Recouper Art-Net et sACN sur une capture",
+            "description": "Quand des nodes Art-Net cohabitent avec sACN, on vérifie que chaque sortie reçoit ses données dans le protocole qu’elle écoute et que les mêmes niveaux portent le même numéro d’univers.",
+            "examples": [
+                "ArtPoll → ArtPollReply : liste des nodes et de leurs ports",
+                "ArtDmx : niveaux d’une Port-Address ; ArtSync : déclenchement synchronisé",
+                "Un node sort 0:0:2 en Art-Net mais seul sACN 3 circule : passer le port en sACN ou activer la sortie Art-Net d’Eos.",
+                "Les niveaux d’Art-Net 0:0:3 sont ceux de sACN 3 : l’émetteur compte Art-Net à partir de 1."
+            ],
+            "tips": [
+                "Deux émetteurs sur la même Port-Address sont fusionnés en HTP par le node ; un troisième est ignoré.",
+                "🧰 Outils → 🌐 Art-Net liste les nodes découverts et recoupe chaque univers avec sACN à partir d’une capture Wireshark."
             ]
         },
         {
             "id": "midi-show-control",
             "subcat": "show-control-base",
+            "level": "expert",
+            "tags": ["show-control", "midi"],
             "action": "Utiliser MIDI Show Control",
             "description": "Contrôle Eos via MIDI.",
             "syntaxes": [
@@ -1108,12 +1604,17 @@ const INITIAL_DATA_JSON: &str = r#"
                 "Fire 10 → exécute la macro 10."
             ],
             "tips": [
-                "Supporte les commandes Go, Stop, Resume, Fire."
+                "Supporte les commandes Go, Stop, Resume, Fire.",
+                "Trame : F0 7F <device ID> 02 01 <commande> <données> F7 ; GO = 01, FIRE = 07, cue et liste en ASCII séparées par 00.",
+                "Le device ID doit correspondre à celui réglé dans Setup › Show Control › MSC ; 7F s’adresse à tous.",
+                "🧰 Outils → 🎼 MSC compose un message et décode les octets capturés."
             ]
         },
         {
             "id": "osc",
             "subcat": "show-control-base",
+            "level": "expert",
+            "tags": ["show-control", "réseau"],
             "action": "Utiliser Open Sound Control (OSC)",
             "description": "Contrôle Eos via OSC.",
             "syntaxes": [
@@ -1123,12 +1624,16 @@ const INITIAL_DATA_JSON: &str = r#"
                 "/eos/cue/1/1/fire → joue le cue 1.1."
             ],
             "tips": [
-                "Supporte l’envoi et la réception de données OSC."
+                "Supporte l’envoi et la réception de données OSC.",
+                "/eos/newcmd efface le command line avant d’y taper le texte, /eos/cmd le complète ; # vaut [Enter].",
+                "🧰 Outils → 📡 OSC construit les messages d’une syntaxe du guide et décode les paquets capturés."
             ]
         },
         {
             "id": "timecode",
             "subcat": "show-control-base",
+            "level": "expert",
+            "tags": ["show-control", "timecode"],
             "action": "Utiliser le Time Code",
             "description": "Synchronise Eos avec un signal Time Code.",
             "syntaxes": [
@@ -1141,9 +1646,72 @@ const INITIAL_DATA_JSON: &str = r#"
                 "Supporte LTC, MTC, et Art-Net Time Code."
             ]
         },
+        {
+            "id": "timecode-rates",
+            "subcat": "timecode-base",
+            "level": "intermediate",
+            "tags": ["timecode"],
+            "action": "Choisir la cadence et calculer en drop-frame",
+            "description": "Un time code compte des images : 24, 25, 29,97 drop-frame ou 30 par seconde. En drop-frame, les numéros ;00 et ;01 sont sautés chaque minute sauf toutes les dix minutes.",
+            "syntaxes": [
+                "HH:MM:SS:FF (non drop)",
+                "HH:MM:SS;FF (drop-frame)"
+            ],
+            "examples": [
+                "00:01:00;02 suit 00:00:59;29 en 29,97 DF.",
+                "01:00:00;00 en 29,97 DF = image 107892 = 3599,996 s réelles."
+            ],
+            "tips": [
+                "La cadence de la liste d’événements doit être celle de la source, sinon les déclenchements dérivent.",
+                "🧰 Outils → ⏱️ Time code convertit images, secondes et HH:MM:SS:FF, et calcule offsets et durées."
+            ]
+        },
+        {
+            "id": "timecode-mtc",
+            "subcat": "timecode-base",
+            "level": "expert",
+            "tags": ["timecode", "midi"],
+            "action": "Lire le MIDI Time Code (MTC)",
+            "description": "Le MTC transmet le time code en huit quarts d’image (F1 0n v) pendant la lecture, et en image complète (F0 7F 7F 01 01 hh mm ss ff F7) lors d’un saut.",
+            "syntaxes": [
+                "F1 0v F1 1v F1 2v … F1 7v",
+                "F0 7F 7F 01 01 hh mm ss ff F7"
+            ],
+            "examples": [
+                "F0 7F 7F 01 01 61 00 00 00 F7 → 01:00:00:00 à 30 i/s.",
+                "Le quart 7 porte la cadence dans ses bits 1-2 : 0 = 24, 1 = 25, 2 = 29,97 DF, 3 = 30."
+            ],
+            "tips": [
+                "Huit quarts d’image couvrent deux images : le time code reconstitué a deux images de retard.",
+                "🧰 Outils → ⏱️ Time code décode un flux MTC ou un mot LTC collé en hexadécimal."
+            ]
+        },
+        {
+            "id": "timecode-event-list",
+            "subcat": "timecode-base",
+            "level": "expert",
+            "tags": ["timecode", "show-control"],
+            "action": "Programmer une liste d’événements time code",
+            "description": "Une liste d’événements associe des time codes à des actions (cue, macro, submaster). Elle suit la source (chase) et, si le signal disparaît, continue seule le temps du freewheel avant de s’arrêter.",
+            "syntaxes": [
+                "[Event] [1] [/] [Enter]",
+                "[Event] [1] [/] [1] [Time] 01:00:05:00 [Cue] [5] [Enter]"
+            ],
+            "examples": [
+                "Event 1/1 à 01:00:05:00 lance la cue 5 quand la source passe ce time code.",
+                "Freewheel 30 images à 30 i/s : une coupure d’une seconde passe sans que la liste s’arrête."
+            ],
+            "tips": [
+                "Un saut de time code recale la liste sans rejouer les événements sautés.",
+                "Désactiver un événement le garde dans la liste sans le déclencher : pratique pendant les répétitions.",
+                "🎛️ Simulateur → ⏱️ Time code édite des listes, simule chase, coupure et freewheel, et importe / exporte en CSV."
+            ]
+        },
         {
             "id": "pixel-map",
             "subcat": "pixels-media-base",
+            "level": "expert",
+            "tags": ["pixels", "médias"],
             "action": "Mapper des pixels",
             "description": "Configure la disposition physique des pixels.",
             "syntaxes": [
@@ -1159,6 +1727,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "channel-check-advanced",
             "subcat": "diagnostics-base",
+            "level": "intermediate",
+            "tags": ["diagnostic"],
             "action": "Channel Check avancé avec Next/Last",
             "description": "Parcourt les canaux un par un en Channel Check.",
             "syntaxes": [
@@ -1174,6 +1744,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "query",
             "subcat": "diagnostics-base",
+            "level": "intermediate",
+            "tags": ["diagnostic", "sélection"],
             "action": "Utiliser [Query]",
             "description": "Liste les canaux selon des critères.",
             "syntaxes": [
@@ -1189,6 +1761,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "about-system",
             "subcat": "diagnostics-base",
+            "level": "intermediate",
+            "tags": ["diagnostic"],
             "action": "Utiliser [About] System",
             "description": "Affiche les informations réseau et logicielles.",
             "syntaxes": [
@@ -1204,11 +1778,23 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "copy-to",
             "subcat": "advanced-base",
+            "level": "intermediate",
+            "tags": ["édition"],
             "action": "Utiliser [Copy To]",
             "description": "Copie des valeurs entre canaux, groupes ou palettes.",
             "syntaxes": [
                 "[1][Copy To][2][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[chan][Copy To][cible][Enter]",
+                    "sentence": "Copie le canal {chan} vers le canal {cible}.",
+                    "params": [
+                        { "name": "chan", "kind": "channel" },
+                        { "name": "cible", "kind": "channel", "label": "Canal cible", "default": "2" }
+                    ]
+                }
+            ],
             "examples": [
                 "Copier la couleur du canal 1 vers le canal 2."
             ],
@@ -1219,6 +1805,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "recall-from",
             "subcat": "advanced-base",
+            "level": "intermediate",
+            "tags": ["édition"],
             "action": "Utiliser [Recall From]",
             "description": "Rappelle des données d’un autre endroit.",
             "syntaxes": [
@@ -1234,6 +1822,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "undo",
             "subcat": "advanced-base",
+            "level": "novice",
+            "tags": ["édition", "sécurité"],
             "action": "Utiliser [Undo]",
             "description": "Annule la dernière commande.",
             "syntaxes": [
@@ -1249,6 +1839,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "capture",
             "subcat": "advanced-base",
+            "level": "expert",
+            "tags": ["playback"],
             "action": "Utiliser [Capture]",
             "description": "Stocke l’état actuel dans un cue ou preset.",
             "syntaxes": [
@@ -1264,6 +1856,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "assert",
             "subcat": "emergency-base",
+            "level": "expert",
+            "tags": ["playback", "cues"],
             "action": "Utiliser [Assert]",
             "description": "Force la relecture d’un cue ou submaster.",
             "syntaxes": [
@@ -1279,6 +1873,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "emergency-home",
             "subcat": "emergency-base",
+            "level": "novice",
+            "tags": ["sécurité", "asservis"],
             "action": "Réinitialiser les paramètres non-intensité (Home)",
             "description": "Ramène les projecteurs à leur position de base sans éteindre.",
             "syntaxes": [
@@ -1294,6 +1890,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "emergency-flash",
             "subcat": "emergency-base",
+            "level": "novice",
+            "tags": ["sécurité", "diagnostic"],
             "action": "Flash temporaire",
             "description": "Active un canal brièvement tant que la touche est enfoncée.",
             "syntaxes": [
@@ -1309,6 +1907,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "help-emergency",
             "subcat": "emergency-base",
+            "level": "novice",
+            "tags": ["sécurité", "aide"],
             "action": "Utiliser le Help interactif",
             "description": "Affiche la fonction, la description et des exemples pour n’importe quelle touche.",
             "syntaxes": [
@@ -1324,6 +1924,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "allfade",
             "subcat": "emergency-base",
+            "level": "intermediate",
+            "tags": ["sécurité", "playback"],
             "action": "Utiliser [Allfade]",
             "description": "Fait fondre progressivement toute la scène à 0 %.",
             "syntaxes": [
@@ -1339,11 +1941,22 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "grandmaster",
             "subcat": "emergency-base",
+            "level": "novice",
+            "tags": ["sécurité"],
             "action": "Utiliser le Grandmaster",
             "description": "Contrôle global de l’intensité.",
             "syntaxes": [
                 "[Grandmaster][50][Enter]"
             ],
+            "templates": [
+                {
+                    "syntax": "[Grandmaster][niveau][Enter]",
+                    "sentence": "Règle le Grandmaster à {niveau} %.",
+                    "params": [
+                        { "name": "niveau", "kind": "level" }
+                    ]
+                }
+            ],
             "examples": [
                 "[Grandmaster][50][Enter] → met le GM à 50 %."
             ],
@@ -1354,6 +1967,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "file-save",
             "subcat": "file-base",
+            "level": "novice",
+            "tags": ["fichiers", "sécurité"],
             "action": "Sauvegarder un fichier show",
             "description": "Enregistre le show dans la mémoire interne ou sur clé USB.",
             "syntaxes": [
@@ -1369,6 +1984,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "file-open",
             "subcat": "file-base",
+            "level": "novice",
+            "tags": ["fichiers"],
             "action": "Ouvrir un fichier show",
             "description": "Charge un show depuis la mémoire interne ou une clé USB.",
             "syntaxes": [
@@ -1384,6 +2001,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "quick-save",
             "subcat": "file-base",
+            "level": "novice",
+            "tags": ["fichiers", "sécurité"],
             "action": "Quick Save (Shift + Update)",
             "description": "Sauvegarde rapidement le show dans l’archive interne.",
             "syntaxes": [
@@ -1399,6 +2018,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "clear-functions",
             "subcat": "file-base",
+            "level": "intermediate",
+            "tags": ["fichiers", "sécurité"],
             "action": "Utiliser les fonctions Clear",
             "description": "Supprime des parties du show.",
             "syntaxes": [
@@ -1414,6 +2035,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "import-show",
             "subcat": "file-base",
+            "level": "intermediate",
+            "tags": ["fichiers"],
             "action": "Importer un fichier show",
             "description": "Importe un show depuis une clé USB ou le réseau.",
             "syntaxes": [
@@ -1429,6 +2052,8 @@ const INITIAL_DATA_JSON: &str = r#"
         {
             "id": "partition-select",
             "subcat": "partition-base",
+            "level": "expert",
+            "tags": ["multi-console", "partitions"],
             "action": "Sélectionner une Partition",
             "description": "Passe à une partition spécifique.",
             "syntaxes": [
@@ -1445,6 +2070,259 @@ const INITIAL_DATA_JSON: &str = r#"
 }
 "#;
 
+// --- Défis du simulateur ---
+// Un état de départ (séquences tapées sur un simulateur vierge), un état à atteindre,
+// et des indices renvoyant vers les fiches du guide.
+const CHALLENGES_JSON: &str = r#"
+[
+    {
+        "id": "premier-niveau",
+        "level": "novice",
+        "title": "Premier niveau",
+        "goal": "Mettez le canal 5 à 70%.",
+        "setup": [],
+        "targets": [
+            { "type": "live", "channels": [5], "level": 70 }
+        ],
+        "hints": [
+            { "text": "Sélectionnez le canal puis utilisez [At].", "command": "at" }
+        ]
+    },
+    {
+        "id": "plage-full",
+        "level": "novice",
+        "title": "Une plage à Full",
+        "goal": "Montez les canaux 1 à 4 à Full et le canal 5 à 50%.",
+        "setup": [],
+        "targets": [
+            { "type": "live", "channels": [1, 2, 3, 4], "level": 100 },
+            { "type": "live", "channels": [5], "level": 50 }
+        ],
+        "hints": [
+            { "text": "[Thru] sélectionne une plage de canaux.", "command": "thru" },
+            { "text": "[Full] remplace [At][1][0][0].", "command": "full" }
+        ]
+    },
+    {
+        "id": "premiere-cue",
+        "level": "novice",
+        "title": "Première cue",
+        "goal": "Enregistrez la cue 1 avec les canaux 1 à 3 à 50%.",
+        "setup": [],
+        "targets": [
+            { "type": "cue_exists", "cue": 1, "exists": true },
+            { "type": "cue", "cue": 1, "channels": [1, 2, 3], "level": 50 }
+        ],
+        "hints": [
+            { "text": "Réglez les niveaux, puis [Record][Cue][1][Enter].", "command": "cue-record" }
+        ]
+    },
+    {
+        "id": "tracking-update",
+        "level": "intermediate",
+        "title": "Laisser tracker",
+        "goal": "Passez le canal 1 à 80% dans la cue 1, et laissez ce niveau tracker dans la cue 2.",
+        "setup": [
+            "[1][Thru][3][At][50][Enter]",
+            "[Record][Cue][1][Enter]",
+            "[4][At][Full][Enter]",
+            "[Record][Cue][2][Enter]",
+            "[Go To Cue][1][Enter]"
+        ],
+        "targets": [
+            { "type": "cue", "cue": 1, "channels": [1], "level": 80 },
+            { "type": "cue", "cue": 2, "channels": [1], "level": 80 },
+            { "type": "stored", "cue": 2, "channel": 1, "stored": false }
+        ],
+        "hints": [
+            { "text": "La cue 1 est chargée : [Update] réenregistre la cue active.", "command": "cue-update" },
+            { "text": "La cue 2 ne contient pas le canal 1 : elle reprendra la nouvelle valeur.", "command": "cue-record" }
+        ]
+    },
+    {
+        "id": "couleur-led",
+        "level": "intermediate",
+        "title": "Couleur de LED",
+        "goal": "Allumez les pars LED 11 à 14 à Full, en rouge pur (Red à 100%, Green et Blue à 0%).",
+        "setup": [
+            "[11][Thru][14]{Green}[At][60][Enter]"
+        ],
+        "targets": [
+            { "type": "live", "channels": [11, 12, 13, 14], "level": 100 },
+            { "type": "live", "channels": [11, 12, 13, 14], "param": "Red", "level": 100 },
+            { "type": "live", "channels": [11, 12, 13, 14], "param": "Green", "level": 0 },
+            { "type": "live", "channels": [11, 12, 13, 14], "param": "Blue", "level": 0 }
+        ],
+        "hints": [
+            { "text": "Choisissez le paramètre par sa softkey ({Red}, {Green}…) avant [At].", "command": "softkey" }
+        ]
+    },
+    {
+        "id": "supprimer-cue",
+        "level": "intermediate",
+        "title": "Supprimer une cue",
+        "goal": "Supprimez la cue 2 sans toucher aux cues 1 et 3.",
+        "setup": [
+            "[1][At][Full][Enter]",
+            "[Record][Cue][1][Enter]",
+            "[2][At][Full][Enter]",
+            "[Record][Cue][2][Enter]",
+            "[3][At][Full][Enter]",
+            "[Record][Cue][3][Enter]"
+        ],
+        "targets": [
+            { "type": "cue_exists", "cue": 1, "exists": true },
+            { "type": "cue_exists", "cue": 2, "exists": false },
+            { "type": "cue_exists", "cue": 3, "exists": true },
+            { "type": "cue", "cue": 3, "channels": [1, 3], "level": 100 }
+        ],
+        "hints": [
+            { "text": "[Delete][Cue][n][Enter] supprime une cue ; [Undo] rattrape une erreur.", "command": "undo" }
+        ]
+    },
+    {
+        "id": "cue-only",
+        "level": "expert",
+        "title": "Sans tracker dans la suite",
+        "goal": "Ajoutez le canal 12 à Full dans la cue 5, sans qu'il ne tracke dans la cue 6.",
+        "setup": [
+            "[1][Thru][5][At][50][Enter]",
+            "[Record][Cue][4][Enter]",
+            "[6][At][Full][Enter]",
+            "[Record][Cue][5][Enter]",
+            "[7][At][Full][Enter]",
+            "[Record][Cue][6][Enter]",
+            "[Go To Cue][5][Enter]"
+        ],
+        "targets": [
+            { "type": "cue", "cue": 5, "channels": [12], "level": 100 },
+            { "type": "cue", "cue": 6, "channels": [12], "level": 0 },
+            { "type": "cue", "cue": 6, "channels": [6, 7], "level": 100 }
+        ],
+        "hints": [
+            { "text": "Un simple [Record] laisse la valeur tracker dans les cues suivantes.", "command": "cue-record" },
+            { "text": "[Q Only] limite l'enregistrement à la cue elle-même.", "command": "q-only" }
+        ]
+    },
+    {
+        "id": "partition-record",
+        "level": "expert",
+        "title": "Enregistrer dans sa partition",
+        "goal": "Enregistrez la cue 1 avec les canaux 1 à 3 à Full, sans y inclure le canal 11 (qui doit rester à Full sur scène).",
+        "setup": [
+            "[11][At][Full][Enter]",
+            "[1][Thru][3][At][Full][Enter]"
+        ],
+        "targets": [
+            { "type": "cue", "cue": 1, "channels": [1, 2, 3], "level": 100 },
+            { "type": "stored", "cue": 1, "channel": 11, "stored": false },
+            { "type": "live", "channels": [11], "level": 100 }
+        ],
+        "hints": [
+            { "text": "Une partition limite les canaux que vous enregistrez.", "command": "partition-select" }
+        ]
+    }
+]
+"#;
+
+// --- Parcours d'apprentissage ---
+// Suites ordonnées de fiches, de lectures, de défis et d'exercices, de « Novice » à « Expert ».
+const LEARNING_PATHS_JSON: &str = r#"
+[
+    {
+        "id": "premiers-pas",
+        "level": "novice",
+        "title": "Premiers pas sur la console",
+        "description": "La ligne de commande Eos : sélectionner des canaux et leur donner un niveau.",
+        "prerequisites": [],
+        "steps": [
+            { "type": "reading", "title": "Lire la notation du guide", "text": "Les touches physiques sont notées entre crochets ([At]), les softkeys entre accolades ({Pan}) et le Direct Select entre chevrons («Group 1»). Une commande se lit de gauche à droite, comme une phrase : « quoi », « quoi faire », [Enter]." },
+            { "type": "command", "command": "at" },
+            { "type": "command", "command": "thru" },
+            { "type": "command", "command": "full" },
+            { "type": "command", "command": "out" },
+            { "type": "command", "command": "enter" },
+            { "type": "command", "command": "clear" },
+            { "type": "challenge", "challenge": "premier-niveau" },
+            { "type": "challenge", "challenge": "plage-full" },
+            { "type": "drill", "title": "Exercice de frappe : 10 consignes sans erreur" }
+        ]
+    },
+    {
+        "id": "cues-tracking",
+        "level": "intermediate",
+        "title": "Cues et tracking",
+        "description": "Enregistrer, rappeler et modifier des cues en comprenant le tracking.",
+        "prerequisites": ["premiers-pas"],
+        "steps": [
+            { "type": "reading", "title": "Le tracking en une phrase", "text": "Une cue n'enregistre que ce qui change : une valeur reste (« tracke ») dans les cues suivantes jusqu'à ce qu'une cue la modifie." },
+            { "type": "command", "command": "cue-record" },
+            { "type": "command", "command": "cue-go" },
+            { "type": "command", "command": "cue-update" },
+            { "type": "command", "command": "undo" },
+            { "type": "challenge", "challenge": "premiere-cue" },
+            { "type": "challenge", "challenge": "tracking-update" },
+            { "type": "challenge", "challenge": "supprimer-cue" }
+        ]
+    },
+    {
+        "id": "couleurs-asservis",
+        "level": "intermediate",
+        "title": "Couleurs et asservis",
+        "description": "Les paramètres non-intensité : softkeys, palettes et retour au Home.",
+        "prerequisites": ["premiers-pas"],
+        "steps": [
+            { "type": "command", "command": "softkey" },
+            { "type": "command", "command": "palette-create" },
+            { "type": "command", "command": "palette-apply" },
+            { "type": "command", "command": "emergency-home" },
+            { "type": "challenge", "challenge": "couleur-led" }
+        ]
+    },
+    {
+        "id": "tracking-avance",
+        "level": "expert",
+        "title": "Maîtriser le tracking",
+        "description": "Bloquer, limiter et nettoyer le tracking d'une conduite.",
+        "prerequisites": ["cues-tracking"],
+        "steps": [
+            { "type": "command", "command": "block" },
+            { "type": "command", "command": "q-only" },
+            { "type": "command", "command": "record-only" },
+            { "type": "command", "command": "rem-dim" },
+            { "type": "challenge", "challenge": "cue-only" }
+        ]
+    },
+    {
+        "id": "multi-utilisateurs",
+        "level": "expert",
+        "title": "Travailler à plusieurs",
+        "description": "Utilisateurs et partitions sur un système multi-consoles.",
+        "prerequisites": ["cues-tracking"],
+        "steps": [
+            { "type": "reading", "title": "Un utilisateur, une partition", "text": "Chaque console porte un numéro d'utilisateur ; en mode partitionné, chacun ne contrôle et n'enregistre que les canaux de sa partition." },
+            { "type": "command", "command": "user-0" },
+            { "type": "command", "command": "partition-select" },
+            { "type": "challenge", "challenge": "partition-record" }
+        ]
+    },
+    {
+        "id": "macros",
+        "level": "expert",
+        "title": "Automatiser avec les macros",
+        "description": "Enregistrer et structurer des macros.",
+        "prerequisites": ["premiers-pas"],
+        "steps": [
+            { "type": "command", "command": "macro-learn" },
+            { "type": "command", "command": "macro-wait" },
+            { "type": "command", "command": "macro-loop" },
+            { "type": "command", "command": "macro-background" },
+            { "type": "simulator", "title": "Écrire une macro qui fait clignoter le canal 1 dans le simulateur" }
+        ]
+    }
+]
+"#;
+
 // --- Types pour la navigation ---
 #[derive(Clone, PartialEq)]
 enum View {
@@ -1452,18 +2330,20 @@ enum View {
     Subcategories,
     Commands,
     Detail,
+    Simulator,
+    Training,
+    Paths,
+    Tools,
 }
 
 // --- Composant principal ---
 #[function_component(App)]
 fn app() -> Html {
     // --- 1. Hooks Yew : TOUJOURS AU DÉBUT de la fonction ---
-    // Charge l'état initial (soit depuis localStorage, soit les données par défaut)
-    let initial_data_result = load_state_from_storage(); // Fonction qui retourne Result<InitialData, Error>
-
-    // Stocke l'état principal de l'application
+    // Stocke l'état principal de l'application, chargé une seule fois
+    // (soit depuis localStorage, soit les données par défaut)
     let state = use_state(|| {
-        match initial_data_result {
+        match load_state_from_storage() {
             Ok(data) => data,
             Err(e) => {
                 console::error_1(&format!("Erreur de chargement initial: {:?}", e).into());
@@ -1485,6 +2365,50 @@ fn app() -> Html {
     let current_subcategory_id = use_state(|| None::<String>);
     let current_command_id = use_state(|| None::<String>);
 
+    // État du simulateur (conservé pendant la navigation)
+    let simulator = use_state(|| {
+        // Les profils importés (OFL, GDTF) rejoignent ceux du patch de démonstration.
+        let mut sim = Simulator::default();
+        sim.profiles.extend(fixture_import::load_library().into_iter().map(|p| p.profile));
+        sim
+    });
+
+    // Progression de révision, sauvegardée à côté des données du guide
+    let study = use_state(|| LocalStorage::get::<StudyProgress>(STUDY_STORAGE_KEY).unwrap_or_default());
+    let save_study = {
+        let study = study.clone();
+        Callback::from(move |progress: StudyProgress| {
+            study.set(progress.clone());
+            if let Err(e) = LocalStorage::set(STUDY_STORAGE_KEY, &progress) {
+                console::error_1(&format!("Erreur de sauvegarde de la progression: {:?}", e).into());
+            }
+        })
+    };
+
+    // Progression dans les parcours, par profil
+    let paths = use_state(|| LocalStorage::get::<PathsProgress>(PATHS_STORAGE_KEY).unwrap_or_default());
+    let save_paths = {
+        let paths = paths.clone();
+        Callback::from(move |progress: PathsProgress| {
+            paths.set(progress.clone());
+            if let Err(e) = LocalStorage::set(PATHS_STORAGE_KEY, &progress) {
+                console::error_1(&format!("Erreur de sauvegarde des parcours: {:?}", e).into());
+            }
+        })
+    };
+
+    // Un défi réussi compte pour le profil de parcours courant
+    let complete_challenge = {
+        let paths = paths.clone();
+        let save_paths = save_paths.clone();
+        Callback::from(move |id: String| {
+            let mut progress = (*paths).clone();
+            if progress.complete_challenge(&id) {
+                save_paths.emit(progress);
+            }
+        })
+    };
+
     // Hook pour la fonction de sauvegarde
     let state_clone = state.clone();
     let save_state = Callback::from(move |new_state: InitialData| {
@@ -1515,6 +2439,7 @@ fn app() -> Html {
                     current_view.set(View::Home);
                     current_category_id.set(None);
                 }
+                View::Simulator | View::Training | View::Paths | View::Tools => current_view.set(View::Home),
                 View::Home => {} // Déjà à la racine
             }
         })
@@ -1533,6 +2458,50 @@ fn app() -> Html {
         })
     };
 
+    let show_simulator = {
+        let current_view = current_view.clone();
+        Callback::from(move |_| current_view.set(View::Simulator))
+    };
+
+    let show_training = {
+        let current_view = current_view.clone();
+        Callback::from(move |_| current_view.set(View::Training))
+    };
+
+    let show_tools = {
+        let current_view = current_view.clone();
+        Callback::from(move |_| current_view.set(View::Tools))
+    };
+
+    let show_paths = {
+        let current_view = current_view.clone();
+        let paths = paths.clone();
+        let save_paths = save_paths.clone();
+        Callback::from(move |path_id: Option<String>| {
+            let mut progress = (*paths).clone();
+            progress.profile_mut().last_path = path_id;
+            save_paths.emit(progress);
+            current_view.set(View::Paths);
+        })
+    };
+
+    // Tape une séquence sur le simulateur puis l'affiche
+    let send_to_simulator = {
+        let simulator = simulator.clone();
+        let current_view = current_view.clone();
+        Callback::from(move |notation: String| {
+            let mut sim = (*simulator).clone();
+            sim.type_notation(&notation);
+            simulator.set(sim);
+            current_view.set(View::Simulator);
+        })
+    };
+
+    let on_simulator_change = {
+        let simulator = simulator.clone();
+        Callback::from(move |sim: Simulator| simulator.set(sim))
+    };
+
     let show_subcategories = {
         let current_view = current_view.clone();
         let current_category_id = current_category_id.clone();
@@ -1560,12 +2529,30 @@ fn app() -> Html {
         })
     };
 
+    // Ouvre une fiche depuis une autre vue (indices des défis) en replaçant la navigation
+    let show_command = {
+        let state = state.clone();
+        let current_view = current_view.clone();
+        let current_category_id = current_category_id.clone();
+        let current_subcategory_id = current_subcategory_id.clone();
+        let current_command_id = current_command_id.clone();
+        Callback::from(move |cmd_id: String| {
+            let Some(cmd) = state.commands.iter().find(|c| c.id == cmd_id) else { return };
+            let category = state.subcategories.iter().find(|sc| sc.id == cmd.subcat).map(|sc| sc.parent_id.clone());
+            current_category_id.set(category);
+            current_subcategory_id.set(Some(cmd.subcat.clone()));
+            current_command_id.set(Some(cmd_id));
+            current_view.set(View::Detail);
+        })
+    };
+
     // Callbacks de modification
     let add_command = {
         let state = state.clone();
         let save_state = save_state.clone();
         let current_subcategory_id = current_subcategory_id.clone();
-        Callback::from(move |new_cmd: Command| {
+        Callback::from(move |mut new_cmd: Command| {
+            new_cmd.tags = normalize_tags(&new_cmd.tags);
             let mut new_state = (*state).clone();
             let subcat_id = current_subcategory_id.as_deref().unwrap_or_default();
             if new_cmd.subcat == subcat_id {
@@ -1580,7 +2567,8 @@ fn app() -> Html {
     let edit_command = {
         let state = state.clone();
         let save_state = save_state.clone();
-        Callback::from(move |updated_cmd: Command| {
+        Callback::from(move |mut updated_cmd: Command| {
+            updated_cmd.tags = normalize_tags(&updated_cmd.tags);
             let mut new_state = (*state).clone();
             if let Some(index) = new_state.commands.iter().position(|c| c.id == updated_cmd.id) {
                 new_state.commands[index] = updated_cmd;
@@ -1604,7 +2592,10 @@ fn app() -> Html {
         View::Home => html! {
             <HomeView
                 state={(*state).clone()}
+                paths={(*paths).clone()}
                 on_show_subcategories={show_subcategories}
+                on_show_paths={show_paths}
+                on_show_command={show_command.clone()}
             />
         },
         View::Subcategories => {
@@ -1637,15 +2628,48 @@ fn app() -> Html {
                     on_edit={edit_command}
                     on_delete={delete_command}
                     on_go_back={go_back.clone()}
+                    on_send_to_simulator={send_to_simulator}
                 />
             }
         }
+        View::Simulator => html! {
+            <SimulatorView simulator={(*simulator).clone()} on_change={on_simulator_change} />
+        },
+        View::Training => html! {
+            <TrainingView
+                data={(*state).clone()}
+                progress={(*study).clone()}
+                on_progress={save_study}
+                on_complete_challenge={complete_challenge}
+                on_show_command={show_command}
+            />
+        },
+        View::Paths => html! {
+            <PathsView
+                data={(*state).clone()}
+                progress={(*paths).clone()}
+                on_progress={save_paths}
+                on_show_command={show_command}
+                on_show_training={show_training.clone()}
+                on_show_simulator={show_simulator.clone()}
+            />
+        },
+        View::Tools => html! {
+            <ToolsView data={(*state).clone()} />
+        },
     };
 
     // --- 4. Rendu final ---
     html! {
         <div class="container">
-            <Header on_show_home={show_home} on_go_back={go_back} current_view={(*current_view).clone()} />
+            <Header
+                on_show_home={show_home}
+                on_go_back={go_back}
+                on_show_simulator={show_simulator}
+                on_show_training={show_training}
+                on_show_tools={show_tools}
+                current_view={(*current_view).clone()}
+            />
             {view_html}
         </div>
     }
@@ -1658,8 +2682,14 @@ fn load_state_from_storage() -> Result<InitialData, Box<dyn std::error::Error>>
         e
     }).ok();
 
-    if let Some(data) = stored {
+    if let Some(mut data) = stored {
         console::log_1(&"État chargé depuis localStorage".into());
+        if upgrade_metadata(&mut data) {
+            // Sauvegardée aussitôt, la mise à niveau ne se refait pas à chaque chargement.
+            if let Err(e) = LocalStorage::set("eos_guide_state", &data) {
+                console::error_1(&format!("Erreur de sauvegarde après mise à niveau: {:?}", e).into());
+            }
+        }
         Ok(data)
     } else {
         // Si aucun état sauvegardé n'est trouvé, charger les données initiales
@@ -1668,6 +2698,8 @@ fn load_state_from_storage() -> Result<InitialData, Box<dyn std::error::Error>>
                 console::error_1(&format!("Erreur de parsing de INITIAL_DATA_JSON: {:?}", e).into());
                 e
             })?;
+        let mut initial = initial;
+        initial.metadata_version = METADATA_VERSION;
         // Sauvegarder les données initiales dans localStorage
         LocalStorage::set("eos_guide_state", &initial)
             .map_err(|e| {
@@ -1679,12 +2711,61 @@ fn load_state_from_storage() -> Result<InitialData, Box<dyn std::error::Error>>
     }
 }
 
+// --- Mise à niveau des données sauvegardées ---
+// Chaque étape ne s'applique qu'une fois, aux données d'une version antérieure :
+// une étiquette ou un niveau effacé ensuite par l'utilisateur le reste.
+// Renvoie vrai si les données ont changé et doivent être sauvegardées.
+fn upgrade_metadata(data: &mut InitialData) -> bool {
+    if data.metadata_version >= METADATA_VERSION {
+        return false;
+    }
+    let Ok(builtin) = serde_json::from_str::<InitialData>(INITIAL_DATA_JSON) else { return false };
+    // 1 : les commandes enregistrées avant les niveaux et étiquettes reprennent celles du guide intégré.
+    if data.metadata_version < 1 {
+        for cmd in &mut data.commands {
+            if let Some(original) = builtin.commands.iter().find(|c| c.id == cmd.id) {
+                if cmd.level.is_none() {
+                    cmd.level = original.level;
+                }
+                if cmd.tags.is_empty() {
+                    cmd.tags = original.tags.clone();
+                }
+                if cmd.templates.is_empty() {
+                    cmd.templates = original.templates.clone();
+                }
+            }
+        }
+    }
+    // 2 : les commandes intégrées absentes (et leurs catégories) sont ajoutées.
+    if data.metadata_version < 2 {
+        for category in builtin.categories {
+            if !data.categories.iter().any(|c| c.id == category.id) {
+                data.categories.push(category);
+            }
+        }
+        for subcategory in builtin.subcategories {
+            if !data.subcategories.iter().any(|s| s.id == subcategory.id) {
+                data.subcategories.push(subcategory);
+            }
+        }
+        for cmd in builtin.commands {
+            if !data.commands.iter().any(|c| c.id == cmd.id) {
+                data.commands.push(cmd);
+            }
+        }
+    }
+    data.metadata_version = METADATA_VERSION;
+    true
+}
 
 // --- Composant d'en-tête ---
 #[derive(Properties, PartialEq, Clone)] // <--- S'assurer que Properties est bien là
 struct HeaderProps {
     on_show_home: Callback<MouseEvent>,
     on_go_back: Callback<MouseEvent>,
+    on_show_simulator: Callback<MouseEvent>,
+    on_show_training: Callback<MouseEvent>,
+    on_show_tools: Callback<MouseEvent>,
     current_view: View,
 }
 
@@ -1698,6 +2779,9 @@ fn header(props: &HeaderProps) -> Html {
                 <button class="back-btn" onclick={props.on_go_back.clone()}>{"← Retour"}</button>
             }
             <h1>{ "📘 Guide Eos" }</h1>
+            <button class="back-btn" onclick={props.on_show_simulator.clone()}>{"🎛️ Simulateur"}</button>
+            <button class="back-btn" onclick={props.on_show_training.clone()}>{"🎓 Formation"}</button>
+            <button class="back-btn" onclick={props.on_show_tools.clone()}>{"🧰 Outils"}</button>
             <button class="back-btn" onclick={props.on_show_home.clone()}>{"?"}</button>
         </div>
     }
@@ -1707,13 +2791,22 @@ fn header(props: &HeaderProps) -> Html {
 #[derive(Properties, PartialEq)]
 struct HomeViewProps {
     state: InitialData,
+    paths: PathsProgress,
     on_show_subcategories: Callback<String>,
+    on_show_paths: Callback<Option<String>>,
+    on_show_command: Callback<String>,
 }
 
 #[function_component(HomeView)]
 fn home_view(props: &HomeViewProps) -> Html {
     html! {
         <div id="home-view" class="view active">
+            <ContinueCard
+                data={props.state.clone()}
+                progress={props.paths.clone()}
+                on_open={props.on_show_paths.clone()}
+            />
+            <SearchResults data={props.state.clone()} on_show_command={props.on_show_command.clone()} />
             <div class="category-grid">
                 { for props.state.categories.iter().map(|cat| {
                     html! {
@@ -1784,6 +2877,7 @@ struct CommandsViewProps {
 
 #[function_component(CommandsView)]
 fn commands_view(props: &CommandsViewProps) -> Html {
+    let filter = use_state(CommandFilter::default);
     let subcategory = props.state.subcategories.iter().find(|sc| sc.id == props.subcategory_id);
     let category = subcategory.and_then(|sc| props.state.categories.iter().find(|c| c.id == sc.parent_id));
     let commands: Vec<&Command> = props
@@ -1792,6 +2886,10 @@ fn commands_view(props: &CommandsViewProps) -> Html {
         .iter()
         .filter(|c| c.subcat == props.subcategory_id)
         .collect();
+    let on_filter = {
+        let filter = filter.clone();
+        Callback::from(move |f: CommandFilter| filter.set(f))
+    };
 
     if let Some(cat) = category {
         if let Some(subcat) = subcategory {
@@ -1813,12 +2911,20 @@ fn commands_view(props: &CommandsViewProps) -> Html {
                                 syntaxes: None,
                                 examples: None,
                                 tips: None,
+                                level: None,
+                                tags: Vec::new(),
+                                templates: Vec::new(),
                             };
                             on_add_command.emit(new_cmd); // Utiliser le callback cloné
                         })
                     }>{"➕ Ajouter une commande"}</button>
+                    <FilterChips
+                        commands={commands.iter().map(|c| (*c).clone()).collect::<Vec<_>>()}
+                        filter={(*filter).clone()}
+                        on_change={on_filter}
+                    />
                     <div id="command-list-container">
-                        { for commands.iter().map(|cmd| {
+                        { for commands.iter().filter(|c| filter.matches(c)).map(|cmd| {
                             html! {
                                 <div class="command-item" onclick={Callback::from({
                                     let on_show_detail = props.on_show_detail.clone();
@@ -1826,6 +2932,7 @@ fn commands_view(props: &CommandsViewProps) -> Html {
                                     move |_| on_show_detail.emit(cmd_id.clone())
                                 })}>
                                     <h3 class="command-title">{ &cmd.action }</h3>
+                                    { command_badges(cmd) }
                                 </div>
                             }
                         })}
@@ -1849,15 +2956,20 @@ struct DetailViewProps {
     on_edit: Callback<Command>,
     on_delete: Callback<String>,
     on_go_back: Callback<MouseEvent>,
+    on_send_to_simulator: Callback<String>,
 }
 
 #[function_component(DetailView)]
 fn detail_view(props: &DetailViewProps) -> Html {
     let command = props.state.commands.iter().find(|c| c.id == props.command_id);
+    // Syntaxe montrée sur le pupitre
+    let shown_syntax = use_state(|| None::<usize>);
+    // Modèle ouvert dans l'assistant « Remplir »
+    let open_template = use_state(|| None::<usize>);
 
     if let Some(cmd) = command {
         let syntax_html = cmd.syntaxes.as_ref().map(|s| {
-            s.iter().map(|s| {
+            s.iter().enumerate().map(|(i, s)| {
                 let processed = s.replace("[", "<span class=\"key key-hard\">[")
                                  .replace("]", "]</span>")
                                  .replace("{", "<span class=\"key key-soft\">{")
@@ -1866,7 +2978,22 @@ fn detail_view(props: &DetailViewProps) -> Html {
                                  .replace("»", "»</span>")
                                  .replace("<", "<span class=\"key key-user\">&lt;")
                                  .replace(">", "&gt;</span>");
-                html! { <div class="syntax" dangerously_set_inner_html={processed.clone()}></div> }
+                let shown = *shown_syntax == Some(i);
+                let toggle = {
+                    let shown_syntax = shown_syntax.clone();
+                    Callback::from(move |_: MouseEvent| shown_syntax.set(if shown { None } else { Some(i) }))
+                };
+                html! {
+                    <>
+                        <div class="syntax" dangerously_set_inner_html={processed.clone()}></div>
+                        <div class="syntax-keyboard" title="Équivalent clavier (Eos offline / Nomad)">{ "⌨️ " }{ keyboard_html(&notation::tokenize(s)) }</div>
+                        <button class="back-btn" onclick={toggle}>{ if shown { "🎹 Masquer le pupitre" } else { "🎹 Voir sur le pupitre" } }</button>
+                        <SendToConsole keys={notation::tokenize(s)} />
+                        if shown {
+                            <Facepanel highlight={notation::tokenize(s)} animate=true />
+                        }
+                    </>
+                }
             }).collect::<Html>()
         }).unwrap_or_default();
 
@@ -1880,8 +3007,32 @@ fn detail_view(props: &DetailViewProps) -> Html {
             <div id="detail-view" class="view active">
                 <h1>{ &cmd.action }</h1>
                 <div class="detail-card">
+                    { command_badges(cmd) }
+                    <MetadataEditor key={cmd.id.clone()} command={cmd.clone()} on_save={props.on_edit.clone()} />
                     <p>{ &cmd.description }</p>
                     { syntax_html }
+                    if !cmd.templates.is_empty() {
+                        <div class="templates">
+                            <h4>{ "Modèles à remplir :" }</h4>
+                            { for cmd.templates.iter().enumerate().map(|(i, template)| {
+                                let open = *open_template == Some(i);
+                                let toggle = {
+                                    let open_template = open_template.clone();
+                                    Callback::from(move |_: MouseEvent| open_template.set(if open { None } else { Some(i) }))
+                                };
+                                html! {
+                                    <>
+                                        <div class="syntax">{ notation::render_keys(&notation::tokenize(&template.syntax)) }</div>
+                                        <button class="back-btn" onclick={toggle}>{ if open { "Fermer" } else { "🧩 Remplir" } }</button>
+                                        if open {
+                                            <TemplateWizard key={format!("{}-{}", cmd.id, i)} template={template.clone()}
+                                                            on_send_to_simulator={props.on_send_to_simulator.clone()} />
+                                        }
+                                    </>
+                                }
+                            })}
+                        </div>
+                    }
                     if let Some(examples) = &cmd.examples {
                         <div class="examples">
                             <h4>{ "Exemples :" }</h4>
@@ -1923,6 +3074,7 @@ fn detail_view(props: &DetailViewProps) -> Html {
 }
 
 // --- CSS (à inclure dans index.html ou via trunk) ---
+#[allow(dead_code)]
 const CSS: &str = r#"
 :root {
     --bg: #1e1e1e;
@@ -1991,6 +3143,215 @@ a:hover { text-decoration: underline; }
 .form-actions { display: flex; gap: 10px; justify-content: flex-end; }
 .form-actions button { padding: 8px 16px; font-weight: 600; }
 .footer { text-align: center; margin-top: 30px; padding-top: 20px; border-top: 1px solid var(--border); font-size: 0.8em; color: #aaa; }
+/* Simulateur */
+.sim-command-line { background: var(--syntax-bg); padding: 12px; border-radius: 6px; font-family: monospace; min-height: 48px; margin-bottom: 8px; }
+.sim-prompt { color: var(--section-header); font-weight: 600; }
+.sim-message { font-size: 0.9em; color: var(--tips); }
+.sim-input { display: flex; gap: 8px; margin-bottom: 12px; }
+.sim-input input { flex: 1; padding: 8px; border-radius: 4px; border: 1px solid var(--border); background: var(--bg); color: var(--text); font-family: monospace; }
+.sim-keypad { display: grid; grid-template-columns: repeat(4, 1fr); gap: 6px; max-width: 320px; margin-bottom: 12px; }
+.sim-keypad .key, .sim-param-keys .key { color: var(--text); cursor: pointer; padding: 8px 4px; margin: 0; }
+.sim-enter { grid-column: span 2; }
+.sim-param-keys { display: flex; flex-wrap: wrap; gap: 6px; margin-bottom: 16px; }
+.sim-channel-grid { display: grid; grid-template-columns: repeat(auto-fill, minmax(64px, 1fr)); gap: 6px; margin-bottom: 16px; }
+.sim-channel { background: var(--card-bg); border: 1px solid var(--border); border-radius: 4px; padding: 4px; text-align: center; font-family: monospace; }
+.sim-channel.selected { border-color: var(--header); }
+.sim-channel-number { font-size: 0.8em; color: #aaa; }
+.sim-channel-level { font-size: 1.2em; color: #ffd54f; }
+.sim-categories { display: flex; justify-content: center; gap: 2px; font-size: 0.7em; }
+.sim-cat { color: #555; }
+.sim-cat.present { color: #aaa; }
+.sim-cat.moved { color: var(--section-header); font-weight: bold; }
+.sim-params { width: 100%; border-collapse: collapse; font-size: 0.85em; }
+.sim-params th, .sim-params td { border: 1px solid var(--border); padding: 4px; vertical-align: top; }
+.sim-params .moved { color: var(--section-header); }
+.sim-dmx { font-family: monospace; font-size: 0.8em; }
+.sim-tabs { display: flex; flex-wrap: wrap; gap: 6px; margin-bottom: 12px; }
+.sim-tabs .active { background: var(--section-header); color: var(--bg); }
+/* Effets */
+.effect-form { display: grid; grid-template-columns: repeat(auto-fill, minmax(180px, 1fr)); gap: 8px; margin: 12px 0; }
+.effect-form input + input { margin-top: 4px; }
+.effect-preview { display: flex; flex-wrap: wrap; gap: 6px; margin-bottom: 12px; }
+.effect-cell { width: 56px; height: 56px; border-radius: 50%; border: 1px solid var(--border); display: flex; flex-direction: column; align-items: center; justify-content: center; font-size: 0.75em; text-shadow: 0 0 3px #000; }
+.effect-timeline { width: 100%; background: var(--syntax-bg); border-radius: 6px; }
+.effect-axis { stroke: var(--border); stroke-width: 1; }
+.effect-curve { fill: none; stroke: var(--section-header); stroke-width: 1.5; }
+.effect-cursor { stroke: var(--header); stroke-width: 1; }
+.effect-label { fill: #aaa; font-size: 10px; }
+/* Macros */
+.macro-list { list-style: none; padding-left: 0; }
+.macro-list .selected { border-color: var(--header); }
+.macro-learning { color: var(--header); font-weight: 600; }
+.macro-debugger { background: var(--card-bg); border: 1px solid var(--border); border-radius: 6px; padding: 12px; margin: 12px 0; }
+.macro-steps { font-family: monospace; font-size: 0.85em; }
+.macro-steps .current { color: var(--header); font-weight: bold; }
+.macro-loop { font-size: 0.9em; color: var(--tips); }
+.sim-cues { display: flex; flex-wrap: wrap; align-items: center; gap: 6px; margin-bottom: 16px; }
+.sim-cue { background: var(--card-bg); border: 1px solid var(--border); border-radius: 4px; padding: 2px 8px; font-family: monospace; }
+.sim-cue.active { border-color: var(--header); color: var(--header); }
+/* Undo */
+.undo-list { list-style: none; padding-left: 0; }
+.undo-entry { display: flex; justify-content: space-between; align-items: center; gap: 8px; }
+.undo-command { font-family: monospace; font-size: 0.85em; color: #aaa; }
+/* Partitions */
+.sim-channel.out-of-partition { opacity: 0.35; background: repeating-linear-gradient(45deg, var(--card-bg), var(--card-bg) 4px, #222 4px, #222 8px); }
+.partition-list { list-style: none; padding-left: 0; }
+.partition-list .selected { border-color: var(--header); }
+.partition-list input { width: 100%; margin-top: 6px; padding: 6px; border-radius: 4px; border: 1px solid var(--border); background: var(--bg); color: var(--text); }
+/* Formation */
+.deck-stats { font-size: 0.8em; color: #aaa; margin-top: 8px; }
+.flashcard { background: var(--card-bg); border: 1px solid var(--border); border-radius: 8px; padding: 20px; margin-top: 12px; text-align: center; }
+.flashcard-prompt { color: var(--section-header); font-size: 0.9em; }
+.flashcard-front { font-size: 1.3em; margin: 12px 0; white-space: pre-wrap; }
+.flashcard-back { text-align: left; }
+/* Exercice de frappe */
+.drill-input { min-height: 2.5em; cursor: text; outline: none; }
+.drill-input:focus { box-shadow: 0 0 0 2px #4a9eff; }
+.drill-caret { animation: blink 1s step-end infinite; opacity: 0.7; }
+@keyframes blink { 50% { opacity: 0; } }
+.mark-ok { background: #2e7d32 !important; color: #fff; }
+.mark-wrong { background: #c62828 !important; color: #fff; }
+.mark-missing { opacity: 0.5; border-style: dashed !important; }
+.mark-extra { text-decoration: line-through; opacity: 0.7; }
+.drill-verdict { font-weight: bold; color: #e57373; }
+.drill-verdict.ok { color: #81c784; }
+/* Défis */
+.challenge-level h3 { margin: 1em 0 0.5em; }
+.challenge-targets, .challenge-hints { list-style: none; padding: 0; text-align: left; }
+.challenge-targets li, .challenge-hints li { margin: 0.3em 0; }
+.challenges-panel #simulator-view h1 { display: none; }
+/* Parcours */
+.continue-card { background: var(--card-bg); border: 1px solid #4ecdc4; border-radius: 8px; box-shadow: var(--card-shadow); padding: 16px 20px; margin-bottom: 20px; cursor: pointer; }
+.continue-card:hover { background: #3a3a3a; }
+.path-progress { height: 8px; background: var(--border); border-radius: 4px; overflow: hidden; margin: 8px 0; }
+.path-progress div { height: 100%; background: #4ecdc4; }
+.path-steps { padding-left: 0; list-style: none; }
+.path-step.done { opacity: 0.7; }
+.command-item.locked { cursor: not-allowed; opacity: 0.5; }
+/* Recherche, niveaux et étiquettes */
+.search { margin-bottom: 20px; }
+.search-input { width: 100%; padding: 10px; border-radius: 6px; border: 1px solid var(--border); background: var(--card-bg); color: var(--text); margin-bottom: 8px; }
+.filter-chips { display: flex; flex-wrap: wrap; gap: 6px; margin-bottom: 12px; }
+.chip { background: var(--btn-bg); color: var(--text); border: 1px solid var(--border); border-radius: 12px; padding: 2px 10px; font-size: 0.8em; cursor: pointer; }
+.chip.active { background: var(--section-header); color: var(--bg); border-color: var(--section-header); }
+.chip-level { border-color: var(--tips); }
+.command-badges { display: flex; flex-wrap: wrap; gap: 4px; margin: 6px 0; }
+.command-badges .chip { cursor: default; }
+/* Quiz */
+.quiz-choices { display: grid; gap: 8px; margin-top: 12px; }
+.quiz-choice { justify-content: flex-start; text-align: left; padding: 12px; margin: 0; }
+.quiz-timer { font-family: monospace; font-weight: 600; }
+.quiz-timer.urgent { color: var(--header); }
+/* Pupitre */
+.facepanel { width: 100%; max-width: 720px; display: block; margin: 10px 0; }
+.fp-key { cursor: pointer; }
+.fp-key rect { fill: #1a1a1a; stroke: var(--key-border); }
+.fp-key text { fill: var(--text); font-size: 10px; }
+.fp-soft rect { fill: var(--key-soft); }
+.fp-user rect { fill: var(--key-user); }
+.fp-in-sequence rect { stroke: var(--section-header); stroke-width: 2; }
+.fp-active rect { fill: var(--section-header); }
+.fp-active text { fill: #000; }
+.fp-key text.fp-order { font-size: 8px; fill: var(--tips); }
+.notation-builder { margin: 10px 0; }
+/* Raccourcis clavier */
+.syntax-keyboard { font-size: 0.85em; color: var(--tips); margin: 2px 0 8px; }
+kbd { display: inline-block; border: 1px solid var(--key-border); border-bottom-width: 2px; border-radius: 3px; padding: 0 5px; margin: 0 1px; font-family: monospace; font-size: 0.9em; background: #2a2a2a; }
+.kbd-combo { margin-right: 6px; white-space: nowrap; }
+.kbd-mouse { margin-right: 6px; opacity: 0.7; font-size: 0.9em; }
+.shortcut-columns { display: flex; flex-wrap: wrap; gap: 12px; }
+.shortcut-table { border-collapse: collapse; min-width: 220px; }
+.shortcut-table th { text-align: left; color: var(--section-header); padding: 4px; }
+.shortcut-table td { padding: 3px 4px; border-top: 1px solid var(--key-border); }
+.sim-command-line:focus { outline: 2px solid var(--section-header); }
+@media print { .header, .no-print { display: none !important; } body { background: #fff; color: #000; } .printable, .printable h2, .printable th { color: #000; } .printable kbd, .printable .key { background: #fff; color: #000; } }
+/* Modèles à remplir */
+.templates { margin: 12px 0; }
+.template-wizard { background: var(--syntax-bg); border-radius: 6px; padding: 10px; margin: 6px 0 12px; }
+.template-fields { display: flex; flex-wrap: wrap; gap: 10px; margin-bottom: 8px; }
+.template-fields label { display: flex; flex-direction: column; font-size: 0.85em; }
+.template-fields input { width: 110px; }
+.template-sentence { font-style: italic; color: var(--tips); }
+.template-errors li { color: var(--header); }
+/* Saisie en texte libre */
+.normalizer { margin: 10px 0; }
+.normalizer input { width: 100%; }
+.normalizer-pieces { list-style: none; padding-left: 0; margin: 8px 0; }
+.normalizer-pieces li { margin-bottom: 2px; }
+.normalizer-pieces li.ambiguous { color: #ffd166; }
+.normalizer-pieces li.unknown { color: var(--header); }
+.normalizer-link { cursor: pointer; color: var(--text); }
+.normalizer-note { margin-left: 6px; font-size: 0.85em; opacity: 0.8; }
+/* OSC */
+.osc-panel textarea { width: 100%; font-family: monospace; }
+.osc-packets { list-style: none; padding-left: 12px; margin: 6px 0; }
+.osc-packets code { color: var(--section-header); word-break: break-all; }
+.osc-meaning { font-size: 0.85em; color: var(--tips); }
+.osc-bytes { font-size: 0.8em; word-break: break-all; }
+.osc-bytes span { opacity: 0.7; }
+/* Console */
+.console-send { display: inline-flex; align-items: center; gap: 6px; }
+.console-feedback { font-size: 0.85em; color: var(--tips); }
+.console-echo { font-family: monospace; padding: 6px 10px; margin: 6px 0; border-left: 3px solid var(--section-header); background: rgba(78, 205, 196, 0.1); }
+.console-status { font-weight: bold; }
+.console-log { list-style: none; padding: 0; max-height: 240px; overflow-y: auto; font-size: 0.85em; }
+.console-log li { padding: 2px 0; border-bottom: 1px dashed rgba(128, 128, 128, 0.3); }
+.console-steps li { margin: 6px 0; }
+/* MSC */
+.msc-form { display: flex; flex-wrap: wrap; gap: 10px; margin-bottom: 10px; }
+.msc-form label { display: flex; flex-direction: column; font-size: 0.85em; gap: 2px; }
+.msc-form input { width: 110px; }
+.msc-hex { font-size: 1.1em; }
+.msc-bytes { border-collapse: collapse; font-size: 0.9em; margin-bottom: 10px; }
+.msc-bytes td { padding: 2px 10px; border-bottom: 1px dashed rgba(128, 128, 128, 0.3); }
+/* Time code */
+.tc-table { border-collapse: collapse; margin-bottom: 12px; }
+.tc-table th { text-align: left; font-weight: normal; color: var(--tips); padding: 3px 12px 3px 0; }
+.tc-table td { padding: 3px 0; }
+.tc-events { font-size: 0.9em; }
+.tc-events .tc-complete { font-weight: bold; }
+/* Liste d'événements */
+.event-clock { display: flex; gap: 24px; align-items: center; margin: 10px 0; flex-wrap: wrap; }
+.event-clock-label { display: block; font-size: 0.8em; color: var(--tips); }
+.event-clock-time { font-size: 1.6em; }
+.event-locate, .event-freewheel { width: 8em; }
+.event-next { color: var(--tips); }
+.event-table { border-collapse: collapse; width: 100%; margin: 10px 0; }
+.event-table th { text-align: left; font-weight: normal; color: var(--tips); padding: 3px 6px; }
+.event-table td { padding: 2px 6px; }
+.event-table input[type=text] { width: 100%; }
+.event-table .event-past { opacity: 0.6; }
+.event-table .event-disabled { text-decoration: line-through; opacity: 0.5; }
+/* Captures réseau */
+.capture-loader input[type=file] { flex: 1; }
+.capture-table { border-collapse: collapse; width: 100%; margin: 10px 0; font-size: 0.9em; }
+.capture-table th { text-align: left; font-weight: normal; color: var(--tips); padding: 3px 8px; }
+.capture-table td { padding: 3px 8px; border-top: 1px solid var(--border); }
+.capture-row { cursor: pointer; }
+.capture-row.active { background: var(--btn-hover); }
+.capture-inactive { opacity: 0.6; }
+.capture-alert { color: #e67e22; }
+.dmx-grid { display: grid; grid-template-columns: repeat(32, 1fr); gap: 1px; font-family: monospace; font-size: 0.65em; margin: 8px 0; }
+.dmx-slot { text-align: center; padding: 2px 0; background: linear-gradient(to top, rgba(52, 152, 219, 0.5) var(--level), transparent var(--level)); border: 1px solid var(--border); }
+.dmx-zero { color: var(--tips); }
+.dmx-unused { opacity: 0.3; }
+/* Art-Net */
+.artnet-converter { display: flex; gap: 24px; flex-wrap: wrap; }
+.artnet-converter input { width: 8em; }
+/* Plan de patch */
+.patch-usage { display: grid; gap: 4px; margin: 10px 0; max-width: 480px; }
+.patch-universe { display: grid; grid-template-columns: 7em 1fr 5em; gap: 8px; align-items: center; }
+.patch-bar { height: 10px; background: var(--syntax-bg); border-radius: 5px; overflow: hidden; }
+.patch-bar div { height: 100%; background: var(--section-header); }
+/* Bibliothèque de fixtures */
+.fixture-params { display: flex; flex-wrap: wrap; gap: 4px; }
+.fixture-param { display: inline-flex; align-items: center; gap: 4px; padding: 2px 6px; border: 1px solid var(--border); border-radius: 4px; font-size: 0.85em; white-space: nowrap; }
+.fixture-param.control { opacity: 0.6; }
+.fixture-cat { font-weight: bold; color: var(--section-header); }
+/* Plan réseau */
+.network-sheet { margin-top: 16px; }
+.network-sheet h2 { color: var(--section-header); }
+@media print { .network-sheet .capture-alert td { background: #fff; font-weight: bold; } }
 /* Responsive */
 @media (max-width: 600px) {
     .category-grid { grid-template-columns: 1fr; }
@@ -2000,6 +3361,7 @@ a:hover { text-decoration: underline; }
 }
 "#;
 
+#[wasm_bindgen::prelude::wasm_bindgen(start)]
 fn main() {
     yew::Renderer::<App>::new().render();
 }
\ No newline at end of file
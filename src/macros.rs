@@ -0,0 +1,504 @@
+use std::collections::BTreeMap;
+
+use gloo::timers::callback::Timeout;
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::facepanel::NotationBuilder;
+use crate::normalizer::NormalizerPanel;
+use crate::notation::{render_keys, tokenize, KeyToken};
+use crate::simulator::Simulator;
+
+// --- Macros ---
+// Une macro est stockée sous forme de texte en notation du guide, par exemple :
+// `{Loop Begin}[1][At][Full][Enter]{Wait}[1][1][Out][Enter]{Loop End}[Loop Num][3][Enter]`.
+// `{Wait}` prend la touche numérique qui suit (`{Wait}[5]`, `{Wait}[0.5]`) comme durée en secondes.
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Macro {
+    pub label: String,
+    pub text: String,
+}
+
+/// Enregistrement `[Learn]` en cours : numéro de macro en cours de saisie, puis touches capturées.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LearnSession {
+    pub target: Option<u32>,
+    pub number: String,
+    pub keys: Vec<KeyToken>,
+}
+
+pub fn default_macros() -> BTreeMap<u32, Macro> {
+    let mut macros = BTreeMap::new();
+    macros.insert(
+        1,
+        Macro {
+            label: "Clignote le canal 1 trois fois".to_string(),
+            text: "{Loop Begin}[1][At][Full][Enter]{Wait}[1][1][Out][Enter]{Wait}[1]{Loop End}[Loop Num][3][Enter]".to_string(),
+        },
+    );
+    macros
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MacroStep {
+    Key(KeyToken),
+    Wait(f64),
+    /// `count` = None : boucle sans fin (jusqu'à l'arrêt de la macro).
+    LoopBegin { end: usize, count: Option<u32> },
+    LoopEnd { begin: usize },
+}
+
+impl MacroStep {
+    pub fn describe(&self) -> String {
+        match self {
+            MacroStep::Key(k) => k.to_notation(),
+            MacroStep::Wait(s) => format!("{{Wait}} {} s", s),
+            MacroStep::LoopBegin { count: Some(n), .. } => format!("{{Loop Begin}} ×{}", n),
+            MacroStep::LoopBegin { count: None, .. } => "{Loop Begin} ×∞".to_string(),
+            MacroStep::LoopEnd { .. } => "{Loop End}".to_string(),
+        }
+    }
+}
+
+/// Chiffres (et `.`) consécutifs à partir de `start`, comme on les tape touche par touche : `[1][0]` = 10.
+/// Renvoie le texte du nombre et l'indice qui le suit.
+fn read_number(tokens: &[KeyToken], start: usize) -> (String, usize) {
+    let count = tokens.get(start..).unwrap_or_default().iter().take_while(|t| t.is_number()).count();
+    let digits = tokens[start..start + count].iter().map(|t| t.label.as_str()).collect();
+    (digits, start + count)
+}
+
+/// Traduit le texte d'une macro en pas exécutables, en reliant les débuts et fins de boucle.
+pub fn compile(text: &str) -> Result<Vec<MacroStep>, String> {
+    let tokens = tokenize(text);
+    let mut steps = Vec::new();
+    let mut open_loops: Vec<usize> = Vec::new();
+    let mut last_closed: Option<usize> = None;
+    let mut i = 0;
+    while i < tokens.len() {
+        let key = &tokens[i];
+        if key.is("Wait") {
+            let (digits, next) = read_number(&tokens, i + 1);
+            let secs = digits.parse::<f64>().map_err(|_| "{Wait} doit être suivi d'une durée, par exemple {Wait}[5]")?;
+            steps.push(MacroStep::Wait(secs));
+            i = next;
+        } else if key.is("Loop Begin") {
+            open_loops.push(steps.len());
+            steps.push(MacroStep::LoopBegin { end: 0, count: None });
+            i += 1;
+        } else if key.is("Loop End") {
+            let begin = open_loops.pop().ok_or("{Loop End} sans {Loop Begin}")?;
+            let end = steps.len();
+            if let MacroStep::LoopBegin { end: e, .. } = &mut steps[begin] {
+                *e = end;
+            }
+            steps.push(MacroStep::LoopEnd { begin });
+            last_closed = Some(begin);
+            i += 1;
+        } else if key.is("Loop Num") {
+            // S'applique à la boucle ouverte, sinon à celle qui vient de se fermer.
+            let target = open_loops.last().copied().or(last_closed).ok_or("[Loop Num] hors d'une boucle")?;
+            let (digits, next) = read_number(&tokens, i + 1);
+            let n = digits.parse::<u32>().map_err(|_| "[Loop Num] doit être suivi d'un nombre entier")?;
+            if let MacroStep::LoopBegin { count, .. } = &mut steps[target] {
+                *count = Some(n);
+            }
+            i = next;
+            if tokens.get(i).is_some_and(|t| t.is("Enter")) {
+                i += 1;
+            }
+        } else {
+            steps.push(MacroStep::Key(key.clone()));
+            i += 1;
+        }
+    }
+    if !open_loops.is_empty() {
+        return Err("{Loop Begin} sans {Loop End}".to_string());
+    }
+    Ok(steps)
+}
+
+// --- Exécution pas à pas ---
+/// Garde-fou contre les boucles sans fin.
+pub const MAX_STEPS: usize = 10_000;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoopFrame {
+    pub begin: usize,
+    pub iteration: u32,
+    pub count: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MacroRun {
+    pub number: u32,
+    pub steps: Vec<MacroStep>,
+    /// Index du prochain pas à exécuter.
+    pub pc: usize,
+    pub loops: Vec<LoopFrame>,
+    pub wait_left: f64,
+    /// En arrière-plan, la macro tape sur son propre command line sans toucher à celui de l'utilisateur.
+    pub background: bool,
+    pub line: Vec<KeyToken>,
+    pub executed: usize,
+    pub finished: bool,
+    pub error: Option<String>,
+}
+
+impl MacroRun {
+    pub fn new(number: u32, steps: Vec<MacroStep>, background: bool) -> Self {
+        let finished = steps.is_empty();
+        MacroRun {
+            number,
+            steps,
+            pc: 0,
+            loops: Vec::new(),
+            wait_left: 0.0,
+            background,
+            line: Vec::new(),
+            executed: 0,
+            finished,
+            error: None,
+        }
+    }
+
+    /// Exécute un pas. Si une attente est en cours, elle est sautée.
+    pub fn step(&mut self, sim: &mut Simulator) {
+        if self.finished {
+            return;
+        }
+        if self.wait_left > 0.0 {
+            self.wait_left = 0.0;
+            return;
+        }
+        if self.executed >= MAX_STEPS {
+            self.finished = true;
+            self.error = Some(format!("Arrêt après {} pas (boucle sans fin ?)", MAX_STEPS));
+            return;
+        }
+        self.executed += 1;
+        match self.steps[self.pc].clone() {
+            MacroStep::Key(key) => {
+                if self.background {
+                    std::mem::swap(&mut sim.command_line, &mut self.line);
+                    sim.press(key);
+                    std::mem::swap(&mut sim.command_line, &mut self.line);
+                } else {
+                    sim.press(key);
+                }
+                self.pc += 1;
+            }
+            MacroStep::Wait(secs) => {
+                self.wait_left = secs;
+                self.pc += 1;
+            }
+            MacroStep::LoopBegin { end, count } => {
+                if count == Some(0) {
+                    self.pc = end + 1;
+                } else {
+                    self.loops.push(LoopFrame { begin: self.pc, iteration: 1, count });
+                    self.pc += 1;
+                }
+            }
+            MacroStep::LoopEnd { begin } => match self.loops.last_mut() {
+                Some(frame) if frame.count.is_none_or(|n| frame.iteration < n) => {
+                    frame.iteration += 1;
+                    self.pc = begin + 1;
+                }
+                _ => {
+                    self.loops.pop();
+                    self.pc += 1;
+                }
+            },
+        }
+        if self.pc >= self.steps.len() && self.wait_left <= 0.0 {
+            self.finished = true;
+        }
+    }
+
+    /// Fait avancer la macro de `dt` secondes de temps simulé.
+    pub fn advance(&mut self, sim: &mut Simulator, mut dt: f64) {
+        while !self.finished {
+            if self.wait_left > 0.0 {
+                if dt < self.wait_left {
+                    self.wait_left -= dt;
+                    return;
+                }
+                dt -= self.wait_left;
+                self.wait_left = 0.0;
+                if self.pc >= self.steps.len() {
+                    self.finished = true;
+                }
+                continue;
+            }
+            self.step(sim);
+        }
+    }
+}
+
+// --- Composant Panneau Macros ---
+const TICK_SECS: f64 = 0.1;
+
+#[derive(Properties, PartialEq)]
+pub struct MacrosPanelProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(MacrosPanel)]
+pub fn macros_panel(props: &MacrosPanelProps) -> Html {
+    let selected = use_state(|| props.simulator.macros.keys().next().copied().unwrap_or(1));
+    let run = use_state(|| None::<MacroRun>);
+    let playing = use_state(|| false);
+    let background = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let text_ref = use_node_ref();
+    let show_panel = use_state(|| false);
+    let show_normalizer = use_state(|| false);
+    let number_ref = use_node_ref();
+
+    // Pendant la lecture, chaque rendu programme le tick suivant avec l'état courant.
+    {
+        let run = run.clone();
+        let playing = playing.clone();
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        use_effect(move || {
+            let timeout = (*playing).then(|| {
+                Timeout::new((TICK_SECS * 1000.0) as u32, move || {
+                    if let Some(mut r) = (*run).clone() {
+                        let mut sim = simulator;
+                        r.advance(&mut sim, TICK_SECS);
+                        if r.finished {
+                            playing.set(false);
+                        }
+                        run.set(Some(r));
+                        on_change.emit(sim);
+                    }
+                })
+            });
+            move || drop(timeout)
+        });
+    }
+
+    let sim = &props.simulator;
+    let current = sim.macros.get(&*selected).cloned();
+
+    let start = |play: bool| {
+        let run = run.clone();
+        let playing = playing.clone();
+        let error = error.clone();
+        let background = background.clone();
+        let number = *selected;
+        let text = current.as_ref().map(|m| m.text.clone()).unwrap_or_default();
+        Callback::from(move |_: MouseEvent| match compile(&text) {
+            Ok(steps) => {
+                error.set(None);
+                run.set(Some(MacroRun::new(number, steps, *background)));
+                playing.set(play);
+            }
+            Err(e) => error.set(Some(e)),
+        })
+    };
+
+    let on_step = {
+        let run = run.clone();
+        let playing = playing.clone();
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(mut r) = (*run).clone() {
+                let mut sim = simulator.clone();
+                r.step(&mut sim);
+                playing.set(false);
+                run.set(Some(r));
+                on_change.emit(sim);
+            }
+        })
+    };
+
+    let on_stop = {
+        let run = run.clone();
+        let playing = playing.clone();
+        Callback::from(move |_: MouseEvent| {
+            playing.set(false);
+            run.set(None);
+        })
+    };
+
+    let on_save = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        let text_ref = text_ref.clone();
+        let error = error.clone();
+        let number = *selected;
+        Callback::from(move |_: MouseEvent| {
+            if let Some(area) = text_ref.cast::<HtmlTextAreaElement>() {
+                let text = area.value();
+                if let Err(e) = compile(&text) {
+                    error.set(Some(e));
+                    return;
+                }
+                error.set(None);
+                let mut sim = simulator.clone();
+                let entry = sim.macros.entry(number).or_insert_with(|| Macro { label: String::new(), text: String::new() });
+                entry.text = text;
+                on_change.emit(sim);
+            }
+        })
+    };
+
+    let on_select = {
+        let selected = selected.clone();
+        let run = run.clone();
+        let playing = playing.clone();
+        let number_ref = number_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(input) = number_ref.cast::<HtmlInputElement>() {
+                if let Ok(n) = input.value().parse::<u32>() {
+                    selected.set(n);
+                    playing.set(false);
+                    run.set(None);
+                }
+            }
+        })
+    };
+
+    let on_background = {
+        let background = background.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                background.set(input.checked());
+            }
+        })
+    };
+
+    // Ajoute une séquence à la fin du texte en cours d'édition
+    let insert = {
+        let text_ref = text_ref.clone();
+        Callback::from(move |notation: String| {
+            if let Some(area) = text_ref.cast::<HtmlTextAreaElement>() {
+                area.set_value(&format!("{}{}", area.value(), notation));
+            }
+        })
+    };
+
+    let learning = sim.learn.as_ref().map(|l| match l.target {
+        Some(n) => format!("Learn en cours → Macro {} ({} touche(s))", n, l.keys.len()),
+        None => "Learn : tapez le numéro de macro puis [Enter]".to_string(),
+    });
+
+    html! {
+        <div class="macros-panel">
+            if let Some(msg) = learning {
+                <p class="macro-learning">{ "● " }{ msg }</p>
+            }
+            <ul class="macro-list">
+                { for sim.macros.iter().map(|(n, m)| {
+                    let selected = selected.clone();
+                    let run = run.clone();
+                    let n = *n;
+                    html! {
+                        <li class={classes!("command-item", (n == *selected).then_some("selected"))}
+                            onclick={Callback::from(move |_| { selected.set(n); run.set(None); })}>
+                            { format!("Macro {} — {}", n, if m.label.is_empty() { m.text.as_str() } else { m.label.as_str() }) }
+                        </li>
+                    }
+                })}
+            </ul>
+            <div class="form-actions">
+                <input ref={number_ref} type="number" min="1" placeholder="N° de macro" />
+                <button class="back-btn" onclick={on_select}>{ "Ouvrir / créer" }</button>
+            </div>
+            <div class="form-group">
+                <label>{ format!("Macro {} (notation du guide)", *selected) }</label>
+                <textarea ref={text_ref.clone()} rows="4" key={format!("macro-{}", *selected)}
+                    value={current.as_ref().map(|m| m.text.clone()).unwrap_or_default()} />
+            </div>
+            <button class="back-btn" onclick={{ let show_panel = show_panel.clone(); Callback::from(move |_| show_panel.set(!*show_panel)) }}>
+                { if *show_panel { "🎹 Masquer le pupitre" } else { "🎹 Saisir au pupitre" } }
+            </button>
+            <button class="back-btn" onclick={{ let show_normalizer = show_normalizer.clone(); Callback::from(move |_| show_normalizer.set(!*show_normalizer)) }}>
+                { if *show_normalizer { "✍️ Masquer la saisie libre" } else { "✍️ Saisie en texte libre" } }
+            </button>
+            if *show_panel {
+                <NotationBuilder use_label="Insérer dans la macro" on_use={insert.clone()} />
+            }
+            if *show_normalizer {
+                <NormalizerPanel use_label="Insérer dans la macro" on_use={insert} />
+            }
+            if let Some(e) = &*error {
+                <p class="sim-message">{ format!("Erreur : {}", e) }</p>
+            }
+            <div class="form-actions">
+                <label><input type="checkbox" checked={*background} onchange={on_background} />{ " Arrière-plan" }</label>
+                <button class="back-btn" onclick={on_save}>{ "💾 Enregistrer" }</button>
+                <button class="back-btn" onclick={start(true)}>{ "▶ Exécuter" }</button>
+                <button class="back-btn" onclick={start(false)}>{ "⏮ Préparer" }</button>
+                <button class="back-btn" onclick={on_step} disabled={run.is_none()}>{ "⏭ Pas suivant" }</button>
+                <button class="back-btn" onclick={on_stop} disabled={run.is_none()}>{ "⏹ Stop" }</button>
+            </div>
+            if let Some(r) = &*run {
+                <div class="macro-debugger">
+                    <h4>{ format!("Débogueur — Macro {}", r.number) }</h4>
+                    <p>
+                        { if r.finished { "Terminée".to_string() } else { format!("Pas {} / {}", r.pc + 1, r.steps.len()) } }
+                        { format!(" — {} pas exécuté(s)", r.executed) }
+                        if r.wait_left > 0.0 {
+                            { format!(" — attente {:.1} s", r.wait_left) }
+                        }
+                    </p>
+                    { for r.loops.iter().map(|l| html! {
+                        <p class="macro-loop">
+                            { format!("Boucle (pas {}) : itération {} / {}", l.begin + 1, l.iteration,
+                                l.count.map(|c| c.to_string()).unwrap_or_else(|| "∞".to_string())) }
+                        </p>
+                    })}
+                    if r.background {
+                        <div class="sim-command-line">
+                            <span class="sim-prompt">{ "Arrière-plan : " }</span>
+                            { render_keys(&r.line) }
+                        </div>
+                    }
+                    if let Some(e) = &r.error {
+                        <p class="sim-message">{ e }</p>
+                    }
+                    <ol class="macro-steps">
+                        { for r.steps.iter().enumerate().map(|(i, s)| html! {
+                            <li class={classes!((i == r.pc && !r.finished).then_some("current"))}>{ s.describe() }</li>
+                        })}
+                    </ol>
+                </div>
+            }
+            <p class="sim-message">
+                { "Enregistrement : [Learn] dans l'onglet Canaux, numéro de macro, [Enter], les touches, puis [Learn] à nouveau." }
+            </p>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_digit_wait_and_loop_count() {
+        let steps = compile("{Loop Begin} [Go] {Wait}[1][0] {Loop End} [Loop Num][1][2][Enter]").unwrap();
+        assert_eq!(steps[0], MacroStep::LoopBegin { end: 3, count: Some(12) });
+        assert_eq!(steps[2], MacroStep::Wait(10.0));
+        assert_eq!(steps.len(), 4);
+    }
+
+    #[test]
+    fn decimal_wait() {
+        assert_eq!(compile("{Wait}[2][.][5]").unwrap(), vec![MacroStep::Wait(2.5)]);
+    }
+
+    #[test]
+    fn missing_numbers_are_errors() {
+        assert!(compile("{Wait} [Go]").is_err());
+        assert!(compile("{Loop Begin} [Loop Num][.] {Loop End}").is_err());
+    }
+}
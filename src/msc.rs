@@ -0,0 +1,601 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::osc::parse_bytes;
+use crate::timecode::{Timecode, TimecodeRate, TIMECODE_RATES};
+
+// --- MIDI Show Control ---
+// Message SysEx universel temps réel : F0 7F <device ID> 02 <format> <commande> <données> F7.
+// Les numéros de cue, liste et chemin s'écrivent en ASCII (chiffres et `.`), séparés par 00.
+
+const SYSEX_START: u8 = 0xF0;
+const SYSEX_END: u8 = 0xF7;
+const UNIVERSAL_REALTIME: u8 = 0x7F;
+const MSC_SUB_ID: u8 = 0x02;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MscCommand {
+    Go,
+    Stop,
+    Resume,
+    TimedGo,
+    Load,
+    Set,
+    Fire,
+    AllOff,
+    Restore,
+    Reset,
+    GoOff,
+}
+
+pub const MSC_COMMANDS: [(MscCommand, u8, &str); 11] = [
+    (MscCommand::Go, 0x01, "GO"),
+    (MscCommand::Stop, 0x02, "STOP"),
+    (MscCommand::Resume, 0x03, "RESUME"),
+    (MscCommand::TimedGo, 0x04, "TIMED_GO"),
+    (MscCommand::Load, 0x05, "LOAD"),
+    (MscCommand::Set, 0x06, "SET"),
+    (MscCommand::Fire, 0x07, "FIRE"),
+    (MscCommand::AllOff, 0x08, "ALL_OFF"),
+    (MscCommand::Restore, 0x09, "RESTORE"),
+    (MscCommand::Reset, 0x0A, "RESET"),
+    (MscCommand::GoOff, 0x0B, "GO_OFF"),
+];
+
+impl MscCommand {
+    pub fn code(self) -> u8 {
+        MSC_COMMANDS.iter().find(|(c, _, _)| *c == self).map(|(_, code, _)| *code).unwrap_or(0)
+    }
+
+    pub fn name(self) -> &'static str {
+        MSC_COMMANDS.iter().find(|(c, _, _)| *c == self).map(|(_, _, name)| *name).unwrap_or("?")
+    }
+
+    fn from_code(code: u8) -> Option<Self> {
+        MSC_COMMANDS.iter().find(|(_, c, _)| *c == code).map(|(command, _, _)| *command)
+    }
+
+    /// Commandes dont les données sont un numéro de cue, de liste et de chemin.
+    fn takes_cue(self) -> bool {
+        matches!(self, MscCommand::Go | MscCommand::Stop | MscCommand::Resume | MscCommand::TimedGo | MscCommand::Load | MscCommand::GoOff)
+    }
+}
+
+/// Formats de commande courants (octet qui suit le sub-ID 02). Eos répond au format Lighting.
+pub const COMMAND_FORMATS: [(u8, &str); 8] = [
+    (0x01, "Lighting (général)"),
+    (0x02, "Moving Lights"),
+    (0x03, "Color Changers"),
+    (0x04, "Strobes"),
+    (0x05, "Lasers"),
+    (0x06, "Chasers"),
+    (0x10, "Sound (général)"),
+    (0x7F, "All-types"),
+];
+
+fn format_label(format: u8) -> String {
+    COMMAND_FORMATS
+        .iter()
+        .find(|(f, _)| *f == format)
+        .map(|(_, label)| label.to_string())
+        .unwrap_or_else(|| format!("format {:02X}", format))
+}
+
+/// 0–111 : appareil seul ; 112–126 : groupe 1–15 ; 127 : tous.
+pub fn device_label(id: u8) -> String {
+    match id {
+        0x00..=0x6F => format!("appareil {}", id),
+        0x70..=0x7E => format!("groupe {}", id - 0x6F),
+        _ => "tous les appareils (all-call)".to_string(),
+    }
+}
+
+// --- Temps MSC ---
+// hr mn sc fr ff : cadence dans les bits 5-6 de l'octet des heures, comme en MTC ;
+// le bit 5 de l'octet des images indique un octet d'état à la place des sous-images.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MscTime {
+    pub timecode: Timecode,
+    /// Centièmes d'image.
+    pub subframes: u8,
+}
+
+impl MscTime {
+    /// Lit `HH:MM:SS:FF`, éventuellement suivi de `.sf` (centièmes d'image).
+    pub fn parse(text: &str, rate: TimecodeRate) -> Result<Self, String> {
+        let (main, sub) = text.trim().split_once('.').unwrap_or((text.trim(), "0"));
+        let subframes = match sub.trim().parse::<u8>() {
+            Ok(n) if n <= 99 => n,
+            _ => return Err(format!("centièmes d'image « {} » hors de 0–99", sub.trim())),
+        };
+        Ok(MscTime { timecode: Timecode::parse(main, rate)?, subframes })
+    }
+
+    fn bytes(&self) -> [u8; 5] {
+        let tc = &self.timecode;
+        [tc.rate.code() << 5 | tc.hours, tc.minutes, tc.seconds, tc.frames, self.subframes]
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let [hr, mn, sc, fr, ff] = bytes else {
+            return Err("champ de temps incomplet (5 octets attendus)".to_string());
+        };
+        Ok(MscTime {
+            timecode: Timecode::new(TimecodeRate::from_code(hr >> 5), hr & 0x1F, mn & 0x3F, sc & 0x3F, fr & 0x1F)?,
+            // Octet d'état au lieu des sous-images : ignoré
+            subframes: if fr & 0x20 == 0 { *ff } else { 0 },
+        })
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = self.timecode.text();
+        if self.subframes > 0 {
+            text.push_str(&format!(".{:02}", self.subframes));
+        }
+        text
+    }
+
+    fn label(&self) -> String {
+        format!("{} ({})", self.text(), self.timecode.rate.label())
+    }
+}
+
+// --- Message ---
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MscMessage {
+    pub device_id: u8,
+    pub format: u8,
+    pub command: MscCommand,
+    /// Numéros ASCII ; vide = champ absent.
+    pub cue: String,
+    pub list: String,
+    pub path: String,
+    /// Obligatoire pour TIMED_GO, facultatif pour SET.
+    pub time: Option<MscTime>,
+    pub control: u16,
+    pub value: u16,
+    pub macro_number: u8,
+}
+
+impl Default for MscMessage {
+    fn default() -> Self {
+        MscMessage {
+            device_id: 1,
+            format: 0x01,
+            command: MscCommand::Go,
+            cue: "1".to_string(),
+            list: String::new(),
+            path: String::new(),
+            time: None,
+            control: 1,
+            value: 0,
+            macro_number: 1,
+        }
+    }
+}
+
+fn check_number(field: &str, text: &str) -> Result<(), String> {
+    if text.is_empty() || text.chars().all(|c| c.is_ascii_digit() || c == '.') && text.matches('.').count() <= 1 && !text.starts_with('.') {
+        Ok(())
+    } else {
+        Err(format!("{} « {} » : seuls les chiffres et un point sont permis", field, text))
+    }
+}
+
+/// Valeur 14 bits, octet de poids faible en premier.
+fn push_14bit(segments: &mut Vec<(Vec<u8>, String)>, value: u16, label: String) {
+    segments.push((vec![(value & 0x7F) as u8, (value >> 7 & 0x7F) as u8], label));
+}
+
+impl MscMessage {
+    /// Octets du message découpés en champs, chacun avec son explication.
+    pub fn segments(&self) -> Result<Vec<(Vec<u8>, String)>, String> {
+        if self.device_id > 0x7F {
+            return Err(format!("device ID {} hors de 0–127", self.device_id));
+        }
+        let mut segments = vec![
+            (vec![SYSEX_START, UNIVERSAL_REALTIME], "SysEx universel temps réel".to_string()),
+            (vec![self.device_id], format!("device ID : {}", device_label(self.device_id))),
+            (vec![MSC_SUB_ID], "sub-ID : MIDI Show Control".to_string()),
+            (vec![self.format], format!("format : {}", format_label(self.format))),
+            (vec![self.command.code()], format!("commande : {}", self.command.name())),
+        ];
+        if self.command == MscCommand::TimedGo {
+            let time = self.time.ok_or("TIMED_GO demande un temps")?;
+            segments.push((time.bytes().to_vec(), format!("temps : {}", time.label())));
+        }
+        if self.command.takes_cue() {
+            check_number("Cue", &self.cue)?;
+            check_number("Liste", &self.list)?;
+            check_number("Chemin", &self.path)?;
+            if self.cue.is_empty() && !self.list.is_empty() || self.list.is_empty() && !self.path.is_empty() {
+                return Err("une liste demande une cue, un chemin demande une liste".to_string());
+            }
+            let fields = [("cue", &self.cue), ("liste", &self.list), ("chemin", &self.path)];
+            for (i, (name, text)) in fields.iter().filter(|(_, t)| !t.is_empty()).enumerate() {
+                if i > 0 {
+                    segments.push((vec![0x00], "séparateur".to_string()));
+                }
+                segments.push((text.as_bytes().to_vec(), format!("{} « {} »", name, text)));
+            }
+        }
+        match self.command {
+            MscCommand::Set => {
+                if self.control > 0x3FFF || self.value > 0x3FFF {
+                    return Err("numéro et valeur de contrôle limités à 0–16383".to_string());
+                }
+                push_14bit(&mut segments, self.control, format!("contrôle {}", self.control));
+                push_14bit(&mut segments, self.value, format!("valeur {}", self.value));
+                if let Some(time) = self.time {
+                    segments.push((time.bytes().to_vec(), format!("temps : {}", time.label())));
+                }
+            }
+            MscCommand::Fire => {
+                if self.macro_number > 0x7F {
+                    return Err(format!("macro {} hors de 0–127", self.macro_number));
+                }
+                segments.push((vec![self.macro_number], format!("macro {}", self.macro_number)));
+            }
+            _ => {}
+        }
+        segments.push((vec![SYSEX_END], "fin de SysEx".to_string()));
+        Ok(segments)
+    }
+
+    pub fn encode(&self) -> Result<Vec<u8>, String> {
+        Ok(self.segments()?.into_iter().flat_map(|(bytes, _)| bytes).collect())
+    }
+
+    /// Phrase lisible, dans le vocabulaire d'Eos.
+    pub fn describe(&self) -> String {
+        let mut target = String::new();
+        if !self.cue.is_empty() {
+            target = format!(" cue {}", self.cue);
+            if !self.list.is_empty() {
+                target = format!(" cue {}/{}", self.list, self.cue);
+            }
+            if !self.path.is_empty() {
+                target.push_str(&format!(" (chemin {})", self.path));
+            }
+        }
+        let action = match self.command {
+            MscCommand::Go if target.is_empty() => "GO : lance la cue suivante".to_string(),
+            MscCommand::Go => format!("GO : lance la{}", target),
+            MscCommand::Stop => format!("STOP : arrête{}", if target.is_empty() { " les fondus en cours".to_string() } else { format!(" la{}", target) }),
+            MscCommand::Resume => format!("RESUME : reprend{}", if target.is_empty() { " les fondus arrêtés".to_string() } else { format!(" la{}", target) }),
+            MscCommand::TimedGo => format!(
+                "TIMED_GO : lance{} en {}",
+                if target.is_empty() { " la cue suivante".to_string() } else { format!(" la{}", target) },
+                self.time.map(|t| t.text()).unwrap_or_default()
+            ),
+            MscCommand::Load => format!("LOAD : prépare la{} en attente", target),
+            MscCommand::Set => format!("SET : contrôle {} à {}", self.control, self.value),
+            MscCommand::Fire => format!("FIRE : lance la macro {}", self.macro_number),
+            MscCommand::AllOff => "ALL_OFF : noir général (mémorisé pour RESTORE)".to_string(),
+            MscCommand::Restore => "RESTORE : rétablit l'état d'avant ALL_OFF".to_string(),
+            MscCommand::Reset => "RESET : revient au début de la conduite".to_string(),
+            MscCommand::GoOff => format!("GO_OFF : éteint{}", if target.is_empty() { " la cue en cours".to_string() } else { format!(" la{}", target) }),
+        };
+        format!("{} — {}, {}", action, device_label(self.device_id), format_label(self.format))
+    }
+}
+
+/// Relit un message MSC complet, de F0 à F7.
+pub fn decode(bytes: &[u8]) -> Result<MscMessage, String> {
+    let [SYSEX_START, rest @ ..] = bytes else {
+        return Err("un message SysEx commence par F0".to_string());
+    };
+    let [body @ .., SYSEX_END] = rest else {
+        return Err("F7 de fin de SysEx manquant".to_string());
+    };
+    let [UNIVERSAL_REALTIME, device_id, MSC_SUB_ID, format, command, data @ ..] = body else {
+        return Err("en-tête MSC attendu : F0 7F <device> 02 <format> <commande>".to_string());
+    };
+    if let Some(b) = body.iter().find(|b| **b > 0x7F) {
+        return Err(format!("octet {:02X} interdit dans un SysEx (données sur 7 bits)", b));
+    }
+    let command = MscCommand::from_code(*command).ok_or_else(|| format!("commande MSC {:02X} inconnue", command))?;
+    let mut message = MscMessage {
+        device_id: *device_id,
+        format: *format,
+        command,
+        cue: String::new(),
+        ..MscMessage::default()
+    };
+    let mut data = data;
+    if command == MscCommand::TimedGo {
+        message.time = Some(MscTime::from_bytes(data.get(..5).unwrap_or(data))?);
+        data = &data[5..];
+    }
+    match command {
+        MscCommand::Set => {
+            let [c0, c1, v0, v1, time @ ..] = data else {
+                return Err("SET attend 4 octets : contrôle puis valeur, poids faible en premier".to_string());
+            };
+            message.control = *c0 as u16 | (*c1 as u16) << 7;
+            message.value = *v0 as u16 | (*v1 as u16) << 7;
+            if !time.is_empty() {
+                message.time = Some(MscTime::from_bytes(time)?);
+            }
+        }
+        MscCommand::Fire => {
+            let [number] = data else {
+                return Err("FIRE attend un seul octet : le numéro de macro".to_string());
+            };
+            message.macro_number = *number;
+        }
+        command if command.takes_cue() => {
+            let mut fields = data.split(|b| *b == 0x00).map(|f| String::from_utf8_lossy(f).into_owned());
+            message.cue = fields.next().unwrap_or_default();
+            message.list = fields.next().unwrap_or_default();
+            message.path = fields.next().unwrap_or_default();
+            if fields.next().is_some() {
+                return Err("plus de trois champs cue / liste / chemin".to_string());
+            }
+            check_number("Cue", &message.cue)?;
+            check_number("Liste", &message.list)?;
+            check_number("Chemin", &message.path)?;
+        }
+        _ if !data.is_empty() => return Err(format!("{} ne prend pas de données", command.name())),
+        _ => {}
+    }
+    Ok(message)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")
+}
+
+// --- Composant Panneau MSC ---
+
+/// Champs du formulaire, gardés en texte tant qu'ils ne sont pas valides.
+#[derive(Clone, PartialEq)]
+struct MscForm {
+    command: MscCommand,
+    device: String,
+    format: u8,
+    cue: String,
+    list: String,
+    path: String,
+    time: String,
+    rate: TimecodeRate,
+    control: String,
+    value: String,
+    macro_number: String,
+}
+
+impl MscForm {
+    fn from_message(message: &MscMessage) -> Self {
+        MscForm {
+            command: message.command,
+            device: message.device_id.to_string(),
+            format: message.format,
+            cue: message.cue.clone(),
+            list: message.list.clone(),
+            path: message.path.clone(),
+            time: message.time.map(|t| t.text()).unwrap_or_else(|| "00:00:05:00".to_string()),
+            rate: message.time.map(|t| t.timecode.rate).unwrap_or(TimecodeRate::Fps30),
+            control: message.control.to_string(),
+            value: message.value.to_string(),
+            macro_number: message.macro_number.to_string(),
+        }
+    }
+
+    fn message(&self) -> Result<MscMessage, String> {
+        let number = |text: &str, name: &str| text.trim().parse::<u16>().map_err(|_| format!("{} « {} » n'est pas un nombre", name, text.trim()));
+        let time = match self.command {
+            MscCommand::TimedGo => Some(MscTime::parse(&self.time, self.rate)?),
+            MscCommand::Set if !self.time.trim().is_empty() => Some(MscTime::parse(&self.time, self.rate)?),
+            _ => None,
+        };
+        let device = number(&self.device, "Device ID")?;
+        let macro_number = number(&self.macro_number, "Macro")?;
+        Ok(MscMessage {
+            device_id: u8::try_from(device).map_err(|_| format!("device ID {} hors de 0–127", device))?,
+            format: self.format,
+            command: self.command,
+            cue: self.cue.trim().to_string(),
+            list: self.list.trim().to_string(),
+            path: self.path.trim().to_string(),
+            time,
+            control: number(&self.control, "Contrôle")?,
+            value: number(&self.value, "Valeur")?,
+            macro_number: u8::try_from(macro_number).map_err(|_| format!("macro {} hors de 0–127", macro_number))?,
+        })
+    }
+}
+
+fn segments_html(segments: &[(Vec<u8>, String)]) -> Html {
+    html! {
+        <table class="msc-bytes">
+            { for segments.iter().map(|(bytes, label)| html! {
+                <tr><td><code>{ hex(bytes) }</code></td><td>{ label }</td></tr>
+            })}
+        </table>
+    }
+}
+
+#[function_component(MscPanel)]
+pub fn msc_panel() -> Html {
+    let form = use_state(|| MscForm::from_message(&MscMessage::default()));
+    let pasted = use_state(String::new);
+
+    // Champ texte du formulaire
+    let text_field = |set: fn(&mut MscForm, String)| {
+        let form = form.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let mut next = (*form).clone();
+                set(&mut next, input.value());
+                form.set(next);
+            }
+        })
+    };
+    // Liste déroulante du formulaire, par index d'option
+    let select_field = |set: fn(&mut MscForm, usize)| {
+        let form = form.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                let mut next = (*form).clone();
+                set(&mut next, select.selected_index().max(0) as usize);
+                form.set(next);
+            }
+        })
+    };
+    let on_paste = {
+        let pasted = pasted.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(area) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                pasted.set(area.value());
+            }
+        })
+    };
+
+    let built = form.message().and_then(|m| Ok((m.segments()?, m)));
+    let decoded = (!pasted.trim().is_empty()).then(|| parse_bytes(&pasted).and_then(|bytes| decode(&bytes)));
+    let command = form.command;
+
+    html! {
+        <div class="msc-panel">
+            <h2>{ "🎼 Composer un message MIDI Show Control" }</h2>
+            <div class="msc-form">
+                <label>{ "Commande" }
+                    <select onchange={select_field(|f, i| f.command = MSC_COMMANDS[i.min(MSC_COMMANDS.len() - 1)].0)}>
+                        { for MSC_COMMANDS.iter().map(|(c, code, name)| html! {
+                            <option selected={*c == command}>{ format!("{} ({:02X})", name, code) }</option>
+                        })}
+                    </select>
+                </label>
+                <label>{ "Device ID" }
+                    <input type="number" min="0" max="127" value={form.device.clone()} oninput={text_field(|f, v| f.device = v)}
+                           title="0–111 : appareil ; 112–126 : groupe ; 127 : tous" />
+                </label>
+                <label>{ "Format" }
+                    <select onchange={select_field(|f, i| f.format = COMMAND_FORMATS[i.min(COMMAND_FORMATS.len() - 1)].0)}>
+                        { for COMMAND_FORMATS.iter().map(|(code, label)| html! {
+                            <option selected={*code == form.format}>{ format!("{:02X} {}", code, label) }</option>
+                        })}
+                    </select>
+                </label>
+                if command.takes_cue() {
+                    <label>{ "Cue" }<input type="text" value={form.cue.clone()} oninput={text_field(|f, v| f.cue = v)} placeholder="12.5" /></label>
+                    <label>{ "Liste" }<input type="text" value={form.list.clone()} oninput={text_field(|f, v| f.list = v)} placeholder="1" /></label>
+                    <label>{ "Chemin" }<input type="text" value={form.path.clone()} oninput={text_field(|f, v| f.path = v)} /></label>
+                }
+                if matches!(command, MscCommand::TimedGo | MscCommand::Set) {
+                    <label>{ if command == MscCommand::Set { "Temps (facultatif)" } else { "Temps" } }
+                        <input type="text" value={form.time.clone()} oninput={text_field(|f, v| f.time = v)} placeholder="HH:MM:SS:FF" />
+                    </label>
+                    <label>{ "Time code" }
+                        <select onchange={select_field(|f, i| f.rate = TimecodeRate::from_code(i as u8))}>
+                            { for TIMECODE_RATES.iter().map(|(r, label)| html! { <option selected={*r == form.rate}>{ *label }</option> }) }
+                        </select>
+                    </label>
+                }
+                if command == MscCommand::Set {
+                    <label>{ "Contrôle" }<input type="number" min="0" max="16383" value={form.control.clone()} oninput={text_field(|f, v| f.control = v)} /></label>
+                    <label>{ "Valeur" }<input type="number" min="0" max="16383" value={form.value.clone()} oninput={text_field(|f, v| f.value = v)} /></label>
+                }
+                if command == MscCommand::Fire {
+                    <label>{ "Macro" }<input type="number" min="0" max="127" value={form.macro_number.clone()} oninput={text_field(|f, v| f.macro_number = v)} /></label>
+                }
+            </div>
+            { match &built {
+                Ok((segments, message)) => html! {
+                    <>
+                        <p class="msc-hex"><code>{ hex(&message.encode().unwrap_or_default()) }</code></p>
+                        <p>{ message.describe() }</p>
+                        { segments_html(segments) }
+                    </>
+                },
+                Err(e) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+            }}
+
+            <h2>{ "🔎 Décoder un message" }</h2>
+            <textarea rows="3" value={(*pasted).clone()} oninput={on_paste}
+                      placeholder="Collez les octets en hexadécimal : F0 7F 01 02 01 01 31 32 2E 35 00 31 F7" />
+            { match decoded {
+                None => html! {},
+                Some(Ok(message)) => {
+                    let load = {
+                        let form = form.clone();
+                        let message = message.clone();
+                        Callback::from(move |_: MouseEvent| form.set(MscForm::from_message(&message)))
+                    };
+                    html! {
+                        <>
+                            <p>{ message.describe() }</p>
+                            { message.segments().map(|s| segments_html(&s)).unwrap_or_default() }
+                            <button class="back-btn" onclick={load}>{ "↑ Charger dans le formulaire" }</button>
+                        </>
+                    }
+                }
+                Some(Err(e)) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn go() -> MscMessage {
+        MscMessage { cue: "12.5".to_string(), list: "2".to_string(), path: "3".to_string(), ..MscMessage::default() }
+    }
+
+    #[test]
+    fn go_round_trips() {
+        let bytes = go().encode().unwrap();
+        assert_eq!(
+            bytes,
+            vec![0xF0, 0x7F, 0x01, 0x02, 0x01, 0x01, b'1', b'2', b'.', b'5', 0x00, b'2', 0x00, b'3', 0xF7]
+        );
+        assert_eq!(decode(&bytes), Ok(go()));
+    }
+
+    #[test]
+    fn timed_go_round_trips() {
+        let message = MscMessage {
+            command: MscCommand::TimedGo,
+            time: Some(MscTime::parse("00:00:05:10.50", TimecodeRate::Fps25).unwrap()),
+            ..MscMessage::default()
+        };
+        assert_eq!(decode(&message.encode().unwrap()), Ok(message));
+    }
+
+    #[test]
+    fn rejects_wrong_sysex_header() {
+        let mut bytes = go().encode().unwrap();
+        assert!(decode(&bytes[1..]).is_err());
+        // Non temps réel (7E) ou sub-ID autre que MSC
+        bytes[1] = 0x7E;
+        assert!(decode(&bytes).is_err());
+        bytes[1] = 0x7F;
+        bytes[3] = 0x01;
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_missing_end() {
+        let bytes = go().encode().unwrap();
+        assert_eq!(decode(&bytes[..bytes.len() - 1]), Err("F7 de fin de SysEx manquant".to_string()));
+    }
+
+    #[test]
+    fn rejects_bad_command_format() {
+        // Commande inconnue
+        assert!(decode(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x7E, 0xF7]).is_err());
+        // Cue non numérique, SET incomplet, FIRE sans macro, ALL_OFF avec données
+        assert!(decode(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x01, b'1', b'a', 0xF7]).is_err());
+        assert!(decode(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x06, 0x01, 0x00, 0x7F, 0xF7]).is_err());
+        assert!(decode(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x07, 0xF7]).is_err());
+        assert!(decode(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x08, 0x01, 0xF7]).is_err());
+        // Plus de trois champs cue / liste / chemin
+        assert!(decode(&[0xF0, 0x7F, 0x01, 0x02, 0x01, 0x01, b'1', 0x00, b'2', 0x00, b'3', 0x00, b'4', 0xF7]).is_err());
+        // Côté encodeur : chemin sans liste
+        assert!(MscMessage { path: "1".to_string(), ..MscMessage::default() }.encode().is_err());
+    }
+}
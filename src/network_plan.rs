@@ -0,0 +1,510 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::Ipv4Addr;
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::sacn::{multicast_address, MAX_UNIVERSE};
+
+// --- Plan réseau d'un système Eos ---
+// On liste les appareils (consoles, RPU, gateways, nodes, tablettes) ; le plan leur attribue une adresse IP
+// dans le sous-réseau choisi, signale doublons et adresses hors sous-réseau, et donne le groupe multicast sACN de chaque univers.
+
+pub const NETWORK_PLAN_STORAGE_KEY: &str = "eos_guide_network_plan";
+
+/// Réseau IPv4 noté `10.101.0.0/16` (ou avec un masque `10.101.0.0/255.255.0.0`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Subnet {
+    pub network: Ipv4Addr,
+    pub prefix: u8,
+}
+
+impl Subnet {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let (address, prefix) = text.trim().split_once('/').ok_or_else(|| format!("« {} » : notation adresse/préfixe attendue (10.101.0.0/16)", text.trim()))?;
+        let address: Ipv4Addr = address.trim().parse().map_err(|_| format!("adresse « {} » invalide", address.trim()))?;
+        let prefix = match prefix.trim().parse::<u8>() {
+            Ok(p) if p <= 30 => p,
+            Ok(p) => return Err(format!("préfixe /{} : 30 au plus pour garder des adresses d'hôtes", p)),
+            Err(_) => {
+                let mask: Ipv4Addr = prefix.trim().parse().map_err(|_| format!("préfixe ou masque « {} » invalide", prefix.trim()))?;
+                let bits = u32::from(mask);
+                if bits.leading_ones() + bits.trailing_zeros() != 32 || bits.leading_ones() > 30 {
+                    return Err(format!("masque {} non contigu ou trop long", mask));
+                }
+                bits.leading_ones() as u8
+            }
+        };
+        let mut subnet = Subnet { network: address, prefix };
+        // Une adresse d'hôte donnée comme réseau est ramenée à son réseau.
+        subnet.network = Ipv4Addr::from(u32::from(address) & subnet.mask_bits());
+        Ok(subnet)
+    }
+
+    fn mask_bits(self) -> u32 {
+        if self.prefix == 0 { 0 } else { u32::MAX << (32 - self.prefix) }
+    }
+
+    pub fn mask(self) -> Ipv4Addr {
+        Ipv4Addr::from(self.mask_bits())
+    }
+
+    pub fn broadcast(self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.network) | !self.mask_bits())
+    }
+
+    pub fn contains(self, ip: Ipv4Addr) -> bool {
+        u32::from(ip) & self.mask_bits() == u32::from(self.network)
+    }
+
+    /// Adresse utilisable par un appareil : dans le réseau, ni adresse de réseau ni broadcast.
+    pub fn is_host(self, ip: Ipv4Addr) -> bool {
+        self.contains(ip) && ip != self.network && ip != self.broadcast()
+    }
+
+    pub fn host_count(self) -> u32 {
+        (!self.mask_bits()).saturating_sub(1)
+    }
+
+    /// Même partie hôte dans ce réseau : 10.101.100.101 devient 192.168.1.101 en /24.
+    pub fn rebase(self, ip: Ipv4Addr) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.network) | (u32::from(ip) & !self.mask_bits()))
+    }
+
+    pub fn text(self) -> String {
+        format!("{}/{}", self.network, self.prefix)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DeviceKind {
+    Console,
+    Rpu,
+    Gateway,
+    Node,
+    Tablet,
+}
+
+pub const DEVICE_KINDS: [DeviceKind; 5] = [DeviceKind::Console, DeviceKind::Rpu, DeviceKind::Gateway, DeviceKind::Node, DeviceKind::Tablet];
+
+impl DeviceKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            DeviceKind::Console => "Console",
+            DeviceKind::Rpu => "RPU",
+            DeviceKind::Gateway => "Gateway",
+            DeviceKind::Node => "Node",
+            DeviceKind::Tablet => "Tablette",
+        }
+    }
+
+    /// Consoles et RPU émettent le sACN ; gateways et nodes s'abonnent aux univers qu'ils sortent en DMX.
+    pub fn sends_sacn(self) -> bool {
+        matches!(self, DeviceKind::Console | DeviceKind::Rpu)
+    }
+
+    pub fn receives_sacn(self) -> bool {
+        matches!(self, DeviceKind::Gateway | DeviceKind::Node)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NetworkDevice {
+    pub kind: DeviceKind,
+    pub name: String,
+    /// Adresse imposée ; sinon attribuée automatiquement.
+    pub ip: Option<Ipv4Addr>,
+    /// Univers sACN émis (console, RPU) ou sortis en DMX (gateway, node).
+    pub universes: Vec<u16>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NetworkPlan {
+    pub subnet: String,
+    /// Première adresse tentée pour chaque type, ramenée dans le sous-réseau courant.
+    pub starts: Vec<(DeviceKind, Ipv4Addr)>,
+    pub devices: Vec<NetworkDevice>,
+}
+
+impl Default for NetworkPlan {
+    fn default() -> Self {
+        let device = |kind, name: &str, universes: &[u16]| NetworkDevice { kind, name: name.to_string(), ip: None, universes: universes.to_vec() };
+        NetworkPlan {
+            subnet: "10.101.0.0/16".to_string(),
+            starts: vec![
+                (DeviceKind::Console, Ipv4Addr::new(10, 101, 100, 101)),
+                (DeviceKind::Rpu, Ipv4Addr::new(10, 101, 100, 111)),
+                (DeviceKind::Gateway, Ipv4Addr::new(10, 101, 1, 101)),
+                (DeviceKind::Node, Ipv4Addr::new(10, 101, 2, 101)),
+                (DeviceKind::Tablet, Ipv4Addr::new(10, 101, 50, 101)),
+            ],
+            devices: vec![
+                device(DeviceKind::Console, "Console principale", &[1, 2, 3, 4, 5, 6]),
+                device(DeviceKind::Console, "Console backup", &[1, 2, 3, 4, 5, 6]),
+                device(DeviceKind::Rpu, "RPU régie", &[]),
+                device(DeviceKind::Gateway, "Gateway jardin", &[1, 2]),
+                device(DeviceKind::Gateway, "Gateway cour", &[3, 4]),
+                device(DeviceKind::Node, "Node cintres", &[5, 6]),
+                device(DeviceKind::Tablet, "Tablette focus", &[]),
+            ],
+        }
+    }
+}
+
+/// `1-4, 9` → [1, 2, 3, 4, 9], triés et sans doublon.
+pub fn parse_universes(text: &str) -> Result<Vec<u16>, String> {
+    let mut universes = BTreeSet::new();
+    let number = |part: &str| {
+        part.trim()
+            .parse::<u16>()
+            .ok()
+            .filter(|u| (1..=MAX_UNIVERSE).contains(u))
+            .ok_or_else(|| format!("univers « {} » : de 1 à {}", part.trim(), MAX_UNIVERSE))
+    };
+    for part in text.split([',', ';']).filter(|p| !p.trim().is_empty()) {
+        match part.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (number(from)?, number(to)?);
+                if from > to {
+                    return Err(format!("plage « {} » à l'envers", part.trim()));
+                }
+                universes.extend(from..=to);
+            }
+            None => {
+                universes.insert(number(part)?);
+            }
+        }
+    }
+    Ok(universes.into_iter().collect())
+}
+
+/// Inverse de `parse_universes` : les suites consécutives sont regroupées en plages.
+pub fn universes_text(universes: &[u16]) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < universes.len() {
+        let start = universes[i];
+        let mut end = start;
+        while i + 1 < universes.len() && universes[i + 1] == end + 1 {
+            end += 1;
+            i += 1;
+        }
+        parts.push(if start == end { start.to_string() } else { format!("{}-{}", start, end) });
+        i += 1;
+    }
+    parts.join(", ")
+}
+
+impl NetworkPlan {
+    fn start_for(&self, kind: DeviceKind) -> Option<Ipv4Addr> {
+        self.starts.iter().find(|(k, _)| *k == kind).map(|(_, ip)| *ip)
+    }
+
+    /// Adresse de chaque appareil, dans l'ordre de la liste. Les adresses imposées sont réservées d'abord ;
+    /// les autres suivent la première adresse de leur type en sautant celles déjà prises. `None` : plus de place.
+    pub fn assign(&self, subnet: Subnet) -> Vec<Option<Ipv4Addr>> {
+        let mut used: BTreeSet<Ipv4Addr> = self.devices.iter().filter_map(|d| d.ip).collect();
+        let mut cursors: BTreeMap<DeviceKind, u32> = BTreeMap::new();
+        let first_host = u32::from(subnet.network) + 1;
+        let last_host = u32::from(subnet.broadcast()) - 1;
+        self.devices
+            .iter()
+            .map(|device| {
+                if device.ip.is_some() {
+                    return device.ip;
+                }
+                let cursor = cursors
+                    .entry(device.kind)
+                    .or_insert_with(|| self.start_for(device.kind).map(|ip| u32::from(subnet.rebase(ip))).unwrap_or(first_host).max(first_host));
+                while *cursor <= last_host && used.contains(&Ipv4Addr::from(*cursor)) {
+                    *cursor += 1;
+                }
+                if *cursor > last_host {
+                    return None;
+                }
+                let ip = Ipv4Addr::from(*cursor);
+                used.insert(ip);
+                *cursor += 1;
+                Some(ip)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum NetworkIssue {
+    /// Plusieurs appareils sur la même adresse.
+    Duplicate { ip: Ipv4Addr, devices: Vec<String> },
+    OutOfSubnet { device: String, ip: Ipv4Addr },
+    /// Adresse de réseau ou de broadcast, inutilisable par un appareil.
+    Reserved { device: String, ip: Ipv4Addr },
+    /// Plus d'adresse libre après la première adresse du type.
+    Unassigned { device: String },
+    /// Univers sorti par une gateway ou un node mais émis par aucune console ni RPU.
+    NoSource { device: String, universe: u16 },
+}
+
+impl NetworkIssue {
+    pub fn describe(&self) -> String {
+        match self {
+            NetworkIssue::Duplicate { ip, devices } => format!("{} : adresse utilisée par {}", ip, devices.join(", ")),
+            NetworkIssue::OutOfSubnet { device, ip } => format!("{} : {} est hors du sous-réseau", device, ip),
+            NetworkIssue::Reserved { device, ip } => format!("{} : {} est l'adresse du réseau ou de broadcast", device, ip),
+            NetworkIssue::Unassigned { device } => format!("{} : plus d'adresse libre dans le sous-réseau après la première adresse du type", device),
+            NetworkIssue::NoSource { device, universe } => format!("{} : l'univers {} n'est émis par aucune console ni RPU", device, universe),
+        }
+    }
+
+    fn concerns(&self, name: &str) -> bool {
+        match self {
+            NetworkIssue::Duplicate { devices, .. } => devices.iter().any(|d| d == name),
+            NetworkIssue::OutOfSubnet { device, .. } | NetworkIssue::Reserved { device, .. } | NetworkIssue::Unassigned { device } | NetworkIssue::NoSource { device, .. } => {
+                device == name
+            }
+        }
+    }
+}
+
+pub fn check(plan: &NetworkPlan, subnet: Subnet, addresses: &[Option<Ipv4Addr>]) -> Vec<NetworkIssue> {
+    let mut issues = Vec::new();
+    let mut by_ip: BTreeMap<Ipv4Addr, Vec<String>> = BTreeMap::new();
+    for (device, ip) in plan.devices.iter().zip(addresses) {
+        let Some(ip) = *ip else {
+            issues.push(NetworkIssue::Unassigned { device: device.name.clone() });
+            continue;
+        };
+        by_ip.entry(ip).or_default().push(device.name.clone());
+        if !subnet.contains(ip) {
+            issues.push(NetworkIssue::OutOfSubnet { device: device.name.clone(), ip });
+        } else if !subnet.is_host(ip) {
+            issues.push(NetworkIssue::Reserved { device: device.name.clone(), ip });
+        }
+    }
+    issues.extend(by_ip.into_iter().filter(|(_, d)| d.len() > 1).map(|(ip, devices)| NetworkIssue::Duplicate { ip, devices }));
+    let sent: BTreeSet<u16> = plan.devices.iter().filter(|d| d.kind.sends_sacn()).flat_map(|d| d.universes.iter().copied()).collect();
+    for device in plan.devices.iter().filter(|d| d.kind.receives_sacn()) {
+        for universe in device.universes.iter().filter(|u| !sent.contains(u)) {
+            issues.push(NetworkIssue::NoSource { device: device.name.clone(), universe: *universe });
+        }
+    }
+    issues
+}
+
+/// Univers sACN du plan avec leur groupe multicast, leurs émetteurs et leurs récepteurs.
+pub struct MulticastRow {
+    pub universe: u16,
+    pub group: Ipv4Addr,
+    pub senders: Vec<String>,
+    pub receivers: Vec<String>,
+}
+
+pub fn multicast_table(plan: &NetworkPlan) -> Vec<MulticastRow> {
+    let universes: BTreeSet<u16> = plan.devices.iter().flat_map(|d| d.universes.iter().copied()).collect();
+    let names = |universe: u16, keep: fn(DeviceKind) -> bool| -> Vec<String> {
+        plan.devices.iter().filter(|d| keep(d.kind) && d.universes.contains(&universe)).map(|d| d.name.clone()).collect()
+    };
+    universes
+        .into_iter()
+        .map(|universe| MulticastRow { universe, group: multicast_address(universe), senders: names(universe, DeviceKind::sends_sacn), receivers: names(universe, DeviceKind::receives_sacn) })
+        .collect()
+}
+
+// --- Composant Plan réseau ---
+
+fn save_plan(plan: &NetworkPlan) {
+    if let Err(e) = LocalStorage::set(NETWORK_PLAN_STORAGE_KEY, plan) {
+        gloo::console::error!(format!("Sauvegarde du plan réseau impossible : {:?}", e));
+    }
+}
+
+type PlanEdit = dyn Fn(&mut NetworkPlan, String) -> Result<(), String>;
+
+fn parse_ip(value: &str) -> Result<Ipv4Addr, String> {
+    value.trim().parse().map_err(|_| format!("adresse IP « {} » invalide", value.trim()))
+}
+
+#[function_component(NetworkPlanner)]
+pub fn network_planner() -> Html {
+    let plan = use_state(|| LocalStorage::get::<NetworkPlan>(NETWORK_PLAN_STORAGE_KEY).unwrap_or_default());
+    let error = use_state(|| None::<String>);
+
+    let subnet = Subnet::parse(&plan.subnet);
+    let addresses = subnet.as_ref().map(|s| plan.assign(*s)).unwrap_or_default();
+    let issues = subnet.as_ref().map(|s| check(&plan, *s, &addresses)).unwrap_or_default();
+    let multicast = multicast_table(&plan);
+
+    // Modifie le plan à partir de la valeur d'un champ et enregistre
+    let on_edit = {
+        let plan = plan.clone();
+        let error = error.clone();
+        move |f: Box<PlanEdit>| {
+            let plan = plan.clone();
+            let error = error.clone();
+            Callback::from(move |e: Event| {
+                let value = if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                    input.value()
+                } else if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                    select.value()
+                } else {
+                    return;
+                };
+                let mut next = (*plan).clone();
+                match f(&mut next, value) {
+                    Ok(()) => {
+                        save_plan(&next);
+                        plan.set(next);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            })
+        }
+    };
+    let on_add = {
+        let plan = plan.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*plan).clone();
+            let number = next.devices.iter().filter(|d| d.kind == DeviceKind::Node).count() + 1;
+            next.devices.push(NetworkDevice { kind: DeviceKind::Node, name: format!("Node {}", number), ip: None, universes: Vec::new() });
+            save_plan(&next);
+            plan.set(next);
+        })
+    };
+    let on_reset = {
+        let plan = plan.clone();
+        Callback::from(move |_: MouseEvent| {
+            let next = NetworkPlan::default();
+            save_plan(&next);
+            plan.set(next);
+        })
+    };
+    let print = Callback::from(|_: MouseEvent| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    });
+
+    html! {
+        <div class="network-planner">
+            <div class="no-print">
+                <h3>{ "Sous-réseau" }</h3>
+                <div class="effect-form">
+                    <label>{ "Réseau" }
+                        <input type="text" value={plan.subnet.clone()} placeholder="10.101.0.0/16" onchange={on_edit(Box::new(|p, v| {
+                            p.subnet = Subnet::parse(&v)?.text();
+                            Ok(())
+                        }))} />
+                    </label>
+                    { for plan.starts.iter().enumerate().map(|(i, (kind, ip))| html! {
+                        <label>{ format!("1re adresse {}", kind.label()) }
+                            <input type="text" value={ip.to_string()} onchange={on_edit(Box::new(move |p, v| {
+                                p.starts[i].1 = parse_ip(&v)?;
+                                Ok(())
+                            }))} />
+                        </label>
+                    })}
+                </div>
+                { match &subnet {
+                    Ok(s) => html! {
+                        <p class="sim-message">{ format!("Masque {} · broadcast {} · {} adresses d'hôtes", s.mask(), s.broadcast(), s.host_count()) }</p>
+                    },
+                    Err(e) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+                }}
+
+                <h3>{ "Appareils" }</h3>
+                <table class="event-table">
+                    <tr><th>{ "Type" }</th><th>{ "Nom" }</th><th>{ "IP imposée" }</th><th>{ "Univers sACN" }</th><th></th></tr>
+                    { for plan.devices.iter().enumerate().map(|(i, device)| html! {
+                        <tr>
+                            <td>
+                                <select onchange={on_edit(Box::new(move |p, v| {
+                                    p.devices[i].kind = DEVICE_KINDS.into_iter().find(|k| k.label() == v).unwrap_or(p.devices[i].kind);
+                                    Ok(())
+                                }))}>
+                                    { for DEVICE_KINDS.iter().map(|k| html! { <option selected={*k == device.kind}>{ k.label() }</option> }) }
+                                </select>
+                            </td>
+                            <td><input type="text" value={device.name.clone()} onchange={on_edit(Box::new(move |p, v| {
+                                let name = v.trim();
+                                if name.is_empty() {
+                                    return Err("un appareil doit avoir un nom".to_string());
+                                }
+                                p.devices[i].name = name.to_string();
+                                Ok(())
+                            }))} /></td>
+                            <td><input type="text" value={device.ip.map(|ip| ip.to_string()).unwrap_or_default()} placeholder="auto" onchange={on_edit(Box::new(move |p, v| {
+                                p.devices[i].ip = if v.trim().is_empty() { None } else { Some(parse_ip(&v)?) };
+                                Ok(())
+                            }))} /></td>
+                            <td><input type="text" value={universes_text(&device.universes)} placeholder="1-4, 9" onchange={on_edit(Box::new(move |p, v| {
+                                p.devices[i].universes = parse_universes(&v)?;
+                                Ok(())
+                            }))} /></td>
+                            <td><button class="back-btn" onclick={{
+                                let plan = plan.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    let mut next = (*plan).clone();
+                                    next.devices.remove(i);
+                                    save_plan(&next);
+                                    plan.set(next);
+                                })
+                            }}>{ "🗑" }</button></td>
+                        </tr>
+                    })}
+                </table>
+                <div class="form-actions">
+                    <button class="back-btn" onclick={on_add}>{ "+ Appareil" }</button>
+                    <button class="back-btn" onclick={on_reset}>{ "⟲ Exemple" }</button>
+                    <button class="back-btn" onclick={print}>{ "🖨️ Imprimer la fiche réseau" }</button>
+                </div>
+                if let Some(e) = &*error {
+                    <p class="sim-message">{ format!("Erreur : {}", e) }</p>
+                }
+                if subnet.is_ok() {
+                    if issues.is_empty() {
+                        <p class="sim-message">{ "✅ Aucun conflit d'adresse." }</p>
+                    } else {
+                        <ul class="template-errors">{ for issues.iter().map(|issue| html! { <li>{ issue.describe() }</li> }) }</ul>
+                    }
+                }
+            </div>
+
+            if let Ok(s) = subnet {
+                <div class="network-sheet printable">
+                    <h2>{ "🌐 Fiche réseau" }</h2>
+                    <p>{ format!("Sous-réseau {} · masque {} · sACN sur le port UDP 5568", s.text(), s.mask()) }</p>
+                    <table class="capture-table">
+                        <tr><th>{ "Appareil" }</th><th>{ "Type" }</th><th>{ "Adresse IP" }</th><th>{ "Masque" }</th><th>{ "Univers sACN" }</th></tr>
+                        { for plan.devices.iter().zip(&addresses).map(|(device, ip)| html! {
+                            <tr class={classes!(issues.iter().any(|issue| issue.concerns(&device.name)).then_some("capture-alert"))}>
+                                <td>{ &device.name }</td>
+                                <td>{ device.kind.label() }</td>
+                                <td><code>{ ip.map(|ip| ip.to_string()).unwrap_or_else(|| "—".to_string()) }</code>{ if device.ip.is_some() { " (imposée)" } else { "" } }</td>
+                                <td><code>{ s.mask().to_string() }</code></td>
+                                <td>{ universes_text(&device.universes) }</td>
+                            </tr>
+                        })}
+                    </table>
+                    <h3>{ "Groupes multicast sACN" }</h3>
+                    if multicast.is_empty() {
+                        <p>{ "Aucun univers renseigné." }</p>
+                    } else {
+                        <table class="capture-table">
+                            <tr><th>{ "Univers" }</th><th>{ "Groupe multicast" }</th><th>{ "Émis par" }</th><th>{ "Reçu par" }</th></tr>
+                            { for multicast.iter().map(|row| html! {
+                                <tr class={classes!(row.senders.is_empty().then_some("capture-alert"))}>
+                                    <td>{ row.universe }</td>
+                                    <td><code>{ row.group.to_string() }</code></td>
+                                    <td>{ if row.senders.is_empty() { "—".to_string() } else { row.senders.join(", ") } }</td>
+                                    <td>{ if row.receivers.is_empty() { "—".to_string() } else { row.receivers.join(", ") } }</td>
+                                </tr>
+                            })}
+                        </table>
+                    }
+                </div>
+            }
+        </div>
+    }
+}
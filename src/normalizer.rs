@@ -0,0 +1,394 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::notation::{render_keys, to_notation, tokenize, KeyToken};
+use crate::{Command, InitialData};
+
+// --- Normalisation du texte libre ---
+// « 1 thru 10 at full », « chan 5 @ 50 enter », « 1 à 10 à fond » → notation du guide.
+// Chaque mot lu garde sa source, pour signaler les ambiguïtés et les mots inconnus.
+
+/// Expressions reconnues (mots en minuscules, sans accents) et touche correspondante.
+/// Les expressions de plusieurs mots passent avant les mots seuls.
+const VOCABULARY: [(&[&str], &str); 64] = [
+    (&["go", "to", "cue"], "Go To Cue"),
+    (&["goto", "cue"], "Go To Cue"),
+    (&["gtc"], "Go To Cue"),
+    (&["record", "only"], "Record Only"),
+    (&["rec", "only"], "Record Only"),
+    (&["q", "only"], "Q Only"),
+    (&["cue", "only"], "Q Only"),
+    (&["rem", "dim"], "Rem Dim"),
+    (&["copy", "to"], "Copy To"),
+    (&["recall", "from"], "Recall From"),
+    (&["release", "all"], "Release All"),
+    (&["at"], "At"),
+    (&["@"], "At"),
+    (&["thru"], "Thru"),
+    (&["thr"], "Thru"),
+    (&["through"], "Thru"),
+    (&["t"], "Thru"),
+    (&["full"], "Full"),
+    (&["ff"], "Full"),
+    (&["fl"], "Full"),
+    (&["fond"], "Full"),
+    (&["out"], "Out"),
+    (&["off"], "Out"),
+    (&["enter"], "Enter"),
+    (&["entree"], "Enter"),
+    (&["ent"], "Enter"),
+    (&["valider"], "Enter"),
+    (&["#"], "Enter"),
+    (&["*"], "Enter"),
+    (&["+"], "+"),
+    (&["plus"], "+"),
+    (&["et"], "+"),
+    (&["and"], "+"),
+    (&["sauf"], "-"),
+    (&["moins"], "-"),
+    (&["minus"], "-"),
+    (&["except"], "-"),
+    (&["record"], "Record"),
+    (&["rec"], "Record"),
+    (&["enregistrer"], "Record"),
+    (&["update"], "Update"),
+    (&["upd"], "Update"),
+    (&["cue"], "Cue"),
+    (&["q"], "Cue"),
+    (&["memoire"], "Cue"),
+    (&["sub"], "Sub"),
+    (&["submaster"], "Sub"),
+    (&["group"], "Group"),
+    (&["groupe"], "Group"),
+    (&["grp"], "Group"),
+    (&["macro"], "Macro"),
+    (&["effect"], "Effect"),
+    (&["effet"], "Effect"),
+    (&["fx"], "Effect"),
+    (&["preset"], "Preset"),
+    (&["palette"], "Palette"),
+    (&["time"], "Time"),
+    (&["temps"], "Time"),
+    (&["sneak"], "Sneak"),
+    (&["clear"], "Clear"),
+    (&["delete"], "Delete"),
+    (&["undo"], "Undo"),
+    (&["address"], "Address"),
+    (&["adresse"], "Address"),
+];
+
+/// Mots sans touche : la cible par défaut d'Eos est le canal, le reste est du liant.
+const IMPLICIT: [&str; 11] = ["chan", "ch", "canal", "canaux", "channel", "channels", "circuit", "circuits", "%", "puis", "then"];
+
+/// Lecture d'un mot (ou d'une expression) de la saisie.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reading {
+    Keys(Vec<KeyToken>),
+    /// Lecture retenue, autre lecture possible et raison du doute.
+    Ambiguous { keys: Vec<KeyToken>, alternative: Vec<KeyToken>, reason: &'static str },
+    Ignored,
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Piece {
+    pub source: String,
+    pub reading: Reading,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Normalized {
+    pub pieces: Vec<Piece>,
+    pub keys: Vec<KeyToken>,
+    /// `[Enter]` ajouté en fin de ligne.
+    pub added_enter: bool,
+}
+
+impl Normalized {
+    pub fn notation(&self) -> String {
+        to_notation(&self.keys)
+    }
+
+    pub fn has_doubts(&self) -> bool {
+        self.pieces.iter().any(|p| matches!(p.reading, Reading::Ambiguous { .. } | Reading::Unknown))
+    }
+}
+
+/// Lexème : texte source et, pour la notation déjà entre crochets, la touche lue.
+#[derive(Clone, Debug)]
+struct Lexeme {
+    text: String,
+    /// Collé au lexème précédent (`1-10`, `1,5`).
+    glued: bool,
+    key: Option<KeyToken>,
+}
+
+fn fold(c: char) -> char {
+    match c {
+        'à' | 'â' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'î' | 'ï' => 'i',
+        'ô' | 'ö' => 'o',
+        'ù' | 'û' | 'ü' => 'u',
+        'ç' => 'c',
+        c => c,
+    }
+}
+
+fn lex(input: &str) -> Vec<Lexeme> {
+    let mut lexemes: Vec<Lexeme> = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut glued = false;
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            glued = false;
+            continue;
+        }
+        let text: String = if matches!(c, '[' | '{' | '«' | '<') {
+            // Notation du guide recopiée telle quelle
+            let close = match c {
+                '[' => ']',
+                '{' => '}',
+                '«' => '»',
+                _ => '>',
+            };
+            let mut text = String::new();
+            for c in chars.by_ref() {
+                text.push(c);
+                if c == close {
+                    break;
+                }
+            }
+            let key = tokenize(&text).into_iter().next();
+            lexemes.push(Lexeme { text, glued, key });
+            glued = true;
+            continue;
+        } else if c.is_ascii_digit() {
+            let mut text = String::new();
+            while let Some(&d) = chars.peek() {
+                if d.is_ascii_digit() || (d == '.' && !text.contains('.')) {
+                    text.push(d);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            text
+        } else if c.is_alphabetic() {
+            let mut text = String::new();
+            while let Some(&d) = chars.peek() {
+                if d.is_alphabetic() {
+                    text.extend(d.to_lowercase().map(fold));
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            text
+        } else {
+            chars.next();
+            c.to_string()
+        };
+        lexemes.push(Lexeme { text, glued, key: None });
+        glued = true;
+    }
+    lexemes
+}
+
+fn is_number(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn is_at_word(text: &str) -> bool {
+    matches!(text, "at" | "@" | "a")
+}
+
+/// Convertit une saisie libre en notation du guide.
+pub fn normalize(input: &str) -> Normalized {
+    let lexemes = lex(input);
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut i = 0;
+    while i < lexemes.len() {
+        let lexeme = &lexemes[i];
+        let prev = i.checked_sub(1).map(|j| lexemes[j].text.as_str());
+        let next = lexemes.get(i + 1);
+        let keys = |label: &str| Reading::Keys(vec![KeyToken::hard(label)]);
+
+        if let Some(key) = &lexeme.key {
+            pieces.push(Piece { source: lexeme.text.clone(), reading: Reading::Keys(vec![key.clone()]) });
+            i += 1;
+            continue;
+        }
+
+        // Expressions du vocabulaire, la plus longue d'abord
+        let phrase = VOCABULARY.iter().find(|(words, _)| {
+            words.iter().enumerate().all(|(k, w)| lexemes.get(i + k).is_some_and(|l| l.key.is_none() && l.text == *w))
+        });
+        let text = lexeme.text.as_str();
+        let (consumed, reading) = if is_number(text) {
+            (1, Reading::Keys(vec![KeyToken::hard(text)]))
+        } else if text == "a" {
+            // « 1 à 10 à 50 » : le premier « à » d'une plage est un Thru.
+            let between_numbers = prev.is_some_and(is_number) && next.is_some_and(|n| is_number(&n.text));
+            let at_later = lexemes[i + 1..].iter().any(|l| is_at_word(&l.text));
+            let (keys, alternative) = if between_numbers && at_later { ("Thru", "At") } else { ("At", "Thru") };
+            (1, Reading::Ambiguous { keys: vec![KeyToken::hard(keys)], alternative: vec![KeyToken::hard(alternative)], reason: "« à » : plage ou niveau" })
+        } else if text == "-" {
+            // « 1-10 » collé est une plage, « 1 - 5 » espacé un retrait de sélection.
+            let range = lexeme.glued && prev.is_some_and(is_number) && next.is_some_and(|n| n.glued && is_number(&n.text));
+            if range {
+                (1, keys("Thru"))
+            } else {
+                (1, Reading::Ambiguous { keys: vec![KeyToken::hard("-")], alternative: vec![KeyToken::hard("Thru")], reason: "« - » : retrait ou plage" })
+            }
+        } else if text == ","
+            && lexeme.glued
+            && matches!(pieces.last(), Some(Piece { reading: Reading::Keys(k), .. }) if k.len() == 1 && k[0].is_number())
+            && next.is_some_and(|n| n.glued && is_number(&n.text))
+        {
+            // « 1,5 » : décimale après Cue ou Time, sinon liste de canaux.
+            let decimal_context = pieces.iter().rev().find_map(|p| match &p.reading {
+                Reading::Keys(k) if !k.iter().any(KeyToken::is_number) => k.last().map(|k| k.is("Cue") || k.is("Time") || k.is("Sneak")),
+                _ => None,
+            });
+            let number = pieces.pop().map(|p| p.source).unwrap_or_default();
+            let decimal = format!("{}.{}", number, next.map(|n| n.text.clone()).unwrap_or_default());
+            let source = format!("{},{}", number, next.map(|n| n.text.as_str()).unwrap_or_default());
+            let as_decimal = vec![KeyToken::hard(&decimal)];
+            let as_list = vec![KeyToken::hard(&number), KeyToken::hard("+"), KeyToken::hard(&next.map(|n| n.text.clone()).unwrap_or_default())];
+            let (keys, alternative) = if decimal_context == Some(true) { (as_decimal, as_list) } else { (as_list, as_decimal) };
+            pieces.push(Piece { source, reading: Reading::Ambiguous { keys, alternative, reason: "« , » : décimale ou liste" } });
+            i += 2;
+            continue;
+        } else if text == "," && !pieces.is_empty() {
+            // Liste de canaux « 1, 3, 5 »
+            (1, keys("+"))
+        } else if text == "," || IMPLICIT.contains(&text) || (phrase.is_none() && !text.chars().any(char::is_alphanumeric)) {
+            (1, Reading::Ignored)
+        } else if let Some((words, label)) = phrase {
+            (words.len(), keys(label))
+        } else {
+            (1, Reading::Unknown)
+        };
+        let source = lexemes[i..i + consumed].iter().map(|l| l.text.as_str()).collect::<Vec<_>>().join(" ");
+        pieces.push(Piece { source, reading });
+        i += consumed;
+    }
+
+    let mut keys: Vec<KeyToken> = pieces
+        .iter()
+        .flat_map(|p| match &p.reading {
+            Reading::Keys(keys) | Reading::Ambiguous { keys, .. } => keys.clone(),
+            Reading::Ignored | Reading::Unknown => Vec::new(),
+        })
+        .collect();
+    // Une ligne complète se termine par [Enter], sauf si elle attend encore une valeur.
+    let incomplete = keys.last().is_none_or(|k| ["Enter", "At", "Thru", "+", "-", "Clear"].iter().any(|l| k.is(l)));
+    let added_enter = !incomplete;
+    if added_enter {
+        keys.push(KeyToken::hard("Enter"));
+    }
+    Normalized { pieces, keys, added_enter }
+}
+
+/// Fiche du guide qui présente la touche : celle dont le titre la cite, sinon le premier exemple d'usage.
+pub fn command_for_key<'a>(data: &'a InitialData, key: &KeyToken) -> Option<&'a Command> {
+    if key.is_number() {
+        return None;
+    }
+    let notation = key.to_notation();
+    data.commands.iter().find(|c| c.action.contains(&notation)).or_else(|| {
+        data.commands.iter().find(|c| c.syntaxes.iter().flatten().any(|s| tokenize(s).contains(key)))
+    })
+}
+
+// --- Composant Normaliseur ---
+#[derive(Properties, PartialEq)]
+pub struct NormalizerPanelProps {
+    pub use_label: AttrValue,
+    pub on_use: Callback<String>,
+    /// Données du guide, pour relier chaque touche à sa fiche.
+    #[prop_or_default]
+    pub data: Option<InitialData>,
+    #[prop_or_default]
+    pub on_show_command: Callback<String>,
+}
+
+#[function_component(NormalizerPanel)]
+pub fn normalizer_panel(props: &NormalizerPanelProps) -> Html {
+    let text = use_state(String::new);
+    let result = normalize(&text);
+
+    let on_input = {
+        let text = text.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                text.set(input.value());
+            }
+        })
+    };
+    let on_use = {
+        let on_use = props.on_use.clone();
+        let notation = result.notation();
+        Callback::from(move |_: MouseEvent| on_use.emit(notation.clone()))
+    };
+
+    let key_link = |key: &KeyToken| {
+        let command = props.data.as_ref().and_then(|data| command_for_key(data, key));
+        match command {
+            Some(cmd) => {
+                let on_show_command = props.on_show_command.clone();
+                let id = cmd.id.clone();
+                html! {
+                    <button class={classes!(key.kind.css_class(), "normalizer-link")} title={format!("Fiche : {}", cmd.action)}
+                            onclick={Callback::from(move |_: MouseEvent| on_show_command.emit(id.clone()))}>
+                        { key.to_notation() }
+                    </button>
+                }
+            }
+            None => html! { <span class={key.kind.css_class()}>{ key.to_notation() }</span> },
+        }
+    };
+
+    html! {
+        <div class="normalizer">
+            <input type="text" value={(*text).clone()} oninput={on_input}
+                   placeholder="1 thru 10 at full, chan 5 @ 50 enter, 1 à 10 à fond…" />
+            if !text.trim().is_empty() {
+                <ul class="normalizer-pieces">
+                    { for result.pieces.iter().map(|piece| html! {
+                        <li class={classes!(match piece.reading {
+                            Reading::Ambiguous { .. } => Some("ambiguous"),
+                            Reading::Unknown => Some("unknown"),
+                            _ => None,
+                        })}>
+                            <code>{ &piece.source }</code>{ " → " }
+                            { match &piece.reading {
+                                Reading::Keys(keys) => html! { for keys.iter().map(key_link) },
+                                Reading::Ambiguous { keys, alternative, reason } => html! {
+                                    <>
+                                        { for keys.iter().map(key_link) }
+                                        <span class="normalizer-note">{ format!("⚠ {} (sinon {})", reason, to_notation(alternative)) }</span>
+                                    </>
+                                },
+                                Reading::Ignored => html! { <span class="normalizer-note">{ "ignoré" }</span> },
+                                Reading::Unknown => html! { <span class="normalizer-note">{ "❓ inconnu" }</span> },
+                            }}
+                        </li>
+                    })}
+                    if result.added_enter {
+                        <li><span class="normalizer-note">{ "[Enter] ajouté en fin de ligne" }</span></li>
+                    }
+                </ul>
+                <div class="sim-command-line">{ render_keys(&result.keys) }</div>
+                if result.has_doubts() {
+                    <p class="sim-message">{ "⚠ Vérifiez les mots signalés avant d'utiliser la séquence." }</p>
+                }
+                <div class="form-actions">
+                    <button class="back-btn" onclick={on_use} disabled={result.keys.is_empty()}>{ props.use_label.clone() }</button>
+                </div>
+            }
+        </div>
+    }
+}
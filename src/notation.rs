@@ -0,0 +1,134 @@
+use yew::prelude::*;
+
+// --- Notation des touches du guide ---
+// Le guide écrit les séquences de touches sous la forme `[1][Thru][10][At][Full][Enter]` :
+// `[..]` = touche physique, `{..}` = softkey, `«..»` ou `<..>` = touche utilisateur.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyKind {
+    Hard,
+    Soft,
+    User,
+}
+
+impl KeyKind {
+    pub fn css_class(self) -> &'static str {
+        match self {
+            KeyKind::Hard => "key key-hard",
+            KeyKind::Soft => "key key-soft",
+            KeyKind::User => "key key-user",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyToken {
+    pub kind: KeyKind,
+    pub label: String,
+}
+
+impl KeyToken {
+    pub fn hard(label: &str) -> Self {
+        KeyToken { kind: KeyKind::Hard, label: label.to_string() }
+    }
+
+    pub fn soft(label: &str) -> Self {
+        KeyToken { kind: KeyKind::Soft, label: label.to_string() }
+    }
+
+    /// Vrai pour une touche numérique du pavé (`[5]`, `[50]`, `[.]`).
+    pub fn is_number(&self) -> bool {
+        self.kind == KeyKind::Hard
+            && !self.label.is_empty()
+            && self.label.chars().all(|c| c.is_ascii_digit() || c == '.')
+    }
+
+    /// Compare le libellé sans tenir compte de la casse ni des espaces.
+    pub fn is(&self, label: &str) -> bool {
+        normalize_label(&self.label) == normalize_label(label)
+    }
+
+    pub fn to_notation(&self) -> String {
+        match self.kind {
+            KeyKind::Hard => format!("[{}]", self.label),
+            KeyKind::Soft => format!("{{{}}}", self.label),
+            KeyKind::User => format!("«{}»", self.label),
+        }
+    }
+}
+
+fn normalize_label(label: &str) -> String {
+    label.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect()
+}
+
+/// Découpe une chaîne en touches. Le texte hors crochets (flèches, commentaires) est ignoré.
+pub fn tokenize(input: &str) -> Vec<KeyToken> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        let (kind, close) = match c {
+            '[' => (KeyKind::Hard, ']'),
+            '{' => (KeyKind::Soft, '}'),
+            '«' => (KeyKind::User, '»'),
+            '<' => (KeyKind::User, '>'),
+            _ => continue,
+        };
+        let label: String = chars.by_ref().take_while(|&c| c != close).collect();
+        let label = label.trim();
+        if !label.is_empty() {
+            tokens.push(KeyToken { kind, label: label.to_string() });
+        }
+    }
+    tokens
+}
+
+/// Forme canonique d'une séquence, pour comparer des saisies équivalentes :
+/// chiffres regroupés (`[1][0]` → `[10]`), `[At][Full]` / `[At][100]` → `[Full]`,
+/// `[At][Out]` / `[At][0]` → `[Out]`, et `[Full][Full]` (double appui qui termine la commande) → `[Full][Enter]`.
+pub fn canonicalize(tokens: &[KeyToken]) -> Vec<KeyToken> {
+    let mut merged: Vec<KeyToken> = Vec::new();
+    for token in tokens {
+        match merged.last_mut() {
+            Some(last) if last.is_number() && token.is_number() => last.label.push_str(&token.label),
+            _ => merged.push(token.clone()),
+        }
+    }
+    let mut canonical: Vec<KeyToken> = Vec::new();
+    let mut i = 0;
+    while i < merged.len() {
+        let token = &merged[i];
+        if token.is("At") {
+            if let Some(next) = merged.get(i + 1) {
+                let value = next.is_number().then(|| next.label.parse::<f32>().ok()).flatten();
+                if next.is("Full") || value == Some(100.0) {
+                    canonical.push(KeyToken::hard("Full"));
+                    i += 2;
+                    continue;
+                }
+                if next.is("Out") || value == Some(0.0) {
+                    canonical.push(KeyToken::hard("Out"));
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+        if token.is("Full") && merged.get(i + 1).is_some_and(|next| next.is("Full")) {
+            canonical.push(KeyToken::hard("Full"));
+            canonical.push(KeyToken::hard("Enter"));
+            i += 2;
+            continue;
+        }
+        canonical.push(token.clone());
+        i += 1;
+    }
+    canonical
+}
+
+pub fn to_notation(tokens: &[KeyToken]) -> String {
+    tokens.iter().map(KeyToken::to_notation).collect()
+}
+
+/// Affiche une suite de touches avec le style des touches de la légende.
+pub fn render_keys(keys: &[KeyToken]) -> Html {
+    keys.iter().map(|k| html! { <span class={k.kind.css_class()}>{ k.to_notation() }</span> }).collect()
+}
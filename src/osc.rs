@@ -0,0 +1,280 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::normalizer::normalize;
+use crate::notation::{render_keys, to_notation, tokenize, KeyKind, KeyToken};
+pub use crate::osc_codec::*;
+
+impl OscArg {
+    fn as_f32(&self) -> Option<f32> {
+        match self {
+            OscArg::Int(v) => Some(*v as f32),
+            OscArg::Float(v) => Some(*v),
+            OscArg::Double(v) => Some(*v as f32),
+            _ => None,
+        }
+    }
+}
+
+// --- Saisie des octets : hexadécimal ou base64 ---
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn to_base64(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+fn from_base64(text: &str) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in text.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = BASE64.iter().position(|b| *b as char == c).ok_or_else(|| format!("caractère base64 invalide « {} »", c))?;
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.chunks(4).map(|c| c.iter().map(|b| format!("{:02x}", b)).collect::<String>()).collect::<Vec<_>>().join(" ")
+}
+
+/// Octets collés en hexadécimal (espaces, `:` et `0x` tolérés) ou en base64.
+pub fn parse_bytes(text: &str) -> Result<Vec<u8>, String> {
+    let hex: String = text.replace("0x", "").chars().filter(|c| !c.is_whitespace() && *c != ':' && *c != ',').collect();
+    if hex.is_empty() {
+        return Err("aucun octet".to_string());
+    }
+    if hex.chars().all(|c| c.is_ascii_hexdigit()) && hex.len().is_multiple_of(2) {
+        return Ok((0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0)).collect());
+    }
+    from_base64(text)
+}
+
+// --- API OSC d'Eos ---
+
+/// Nom de touche pour `/eos/key/…` : minuscules, espaces remplacés par `_`.
+pub fn eos_key_name(key: &KeyToken) -> String {
+    key.label.trim().to_lowercase().replace(' ', "_")
+}
+
+/// Texte de ligne de commande Eos : touches séparées par des espaces, `#` pour [Enter].
+pub fn eos_command_text(keys: &[KeyToken]) -> String {
+    let mut text = String::new();
+    for key in keys {
+        if key.is("Enter") {
+            text.push('#');
+            continue;
+        }
+        if !text.is_empty() && !text.ends_with('#') {
+            text.push(' ');
+        }
+        text.push_str(&key.label);
+    }
+    text
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum EosOscMode {
+    /// Ajoute au command line en cours.
+    Cmd,
+    /// Efface le command line puis tape la séquence.
+    NewCmd,
+    /// Une touche par message.
+    Keys,
+}
+
+pub const EOS_OSC_MODES: [(EosOscMode, &str); 3] = [
+    (EosOscMode::NewCmd, "/eos/newcmd"),
+    (EosOscMode::Cmd, "/eos/cmd"),
+    (EosOscMode::Keys, "/eos/key/…"),
+];
+
+/// Messages Eos pour une séquence du guide. Les touches utilisateur (`<U0>`) n'ont pas d'équivalent
+/// et sont ignorées.
+pub fn eos_messages(keys: &[KeyToken], mode: EosOscMode) -> Vec<OscMessage> {
+    let keys: Vec<KeyToken> = keys.iter().filter(|k| k.kind != KeyKind::User).cloned().collect();
+    match mode {
+        EosOscMode::Cmd => vec![OscMessage::new("/eos/cmd", vec![OscArg::String(eos_command_text(&keys))])],
+        EosOscMode::NewCmd => vec![OscMessage::new("/eos/newcmd", vec![OscArg::String(eos_command_text(&keys))])],
+        EosOscMode::Keys => keys
+            .iter()
+            .flat_map(|k| {
+                // Les nombres se tapent chiffre par chiffre
+                if k.is_number() {
+                    k.label.chars().map(|c| c.to_string()).collect::<Vec<_>>()
+                } else {
+                    vec![eos_key_name(k)]
+                }
+            })
+            .map(|name| OscMessage::new(&format!("/eos/key/{}", name), Vec::new()))
+            .collect(),
+    }
+}
+
+/// Traduction lisible d'un message de ou vers Eos.
+pub fn describe_eos(message: &OscMessage) -> String {
+    let parts: Vec<&str> = message.address.trim_start_matches('/').split('/').collect();
+    let string_arg = message.args.iter().find_map(|a| match a {
+        OscArg::String(s) => Some(s.as_str()),
+        _ => None,
+    });
+    let level = message.args.first().and_then(OscArg::as_f32);
+    let command_line = |text: &str| to_notation(&normalize(text).keys);
+    match parts.as_slice() {
+        ["eos", "cmd"] => format!("Ajoute au command line : {}", command_line(string_arg.unwrap_or_default())),
+        ["eos", "newcmd"] => format!("Nouveau command line : {}", command_line(string_arg.unwrap_or_default())),
+        ["eos", "key", name] => {
+            let state = match level {
+                Some(v) if v > 0.0 => " (appui)",
+                Some(_) => " (relâchement)",
+                None => "",
+            };
+            format!("Touche [{}]{}", name.replace('_', " "), state)
+        }
+        ["eos", "chan", n] => match level {
+            Some(v) => format!("Canal {} à {} %", n, v),
+            None => format!("Sélectionne le canal {}", n),
+        },
+        ["eos", "cue", list, cue, "fire"] => format!("Déclenche la cue {}/{}", list, cue),
+        ["eos", "cue", cue, "fire"] => format!("Déclenche la cue {} (liste par défaut)", cue),
+        ["eos", "sub", n, "fire"] => format!("Bump du submaster {}", n),
+        ["eos", "sub", n] => format!("Submaster {} à {} %", n, level.map(|v| v * 100.0).unwrap_or_default()),
+        ["eos", "macro", n, "fire"] => format!("Lance la macro {}", n),
+        ["eos", "group", n] => format!("Sélectionne le groupe {}", n),
+        ["eos", "user", n] => format!("Passe en utilisateur {}", n),
+        ["eos", "ping"] => "Ping (la console répond sur /eos/out/ping)".to_string(),
+        ["eos", "out", "cmd"] | ["eos", "out", "user", _, "cmd"] => {
+            format!("Retour console, command line : {}", string_arg.unwrap_or_default())
+        }
+        ["eos", "out", rest @ ..] => format!("Retour console : {}", rest.join("/")),
+        ["eos", ..] => "Message Eos non détaillé par l'inspecteur".to_string(),
+        _ => "Message hors de l'API Eos".to_string(),
+    }
+}
+
+// --- Composant Panneau OSC ---
+
+fn packet_html(packet: &OscPacket) -> Html {
+    match packet {
+        OscPacket::Message(message) => html! {
+            <li>
+                <code>{ message.to_text() }</code>
+                <div class="osc-meaning">{ describe_eos(message) }</div>
+            </li>
+        },
+        OscPacket::Bundle(bundle) => html! {
+            <li>
+                <strong>{ format!("#bundle ({})", describe_time_tag(bundle.time_tag)) }</strong>
+                <ul class="osc-packets">{ for bundle.elements.iter().map(packet_html) }</ul>
+            </li>
+        },
+    }
+}
+
+#[function_component(OscPanel)]
+pub fn osc_panel() -> Html {
+    let sequence = use_state(|| "[1][Thru][10][At][Full][Enter]".to_string());
+    let mode = use_state(|| EosOscMode::NewCmd);
+    let as_bundle = use_state(|| false);
+    let pasted = use_state(String::new);
+
+    // Notation du guide, ou texte libre passé au normaliseur
+    let keys = if sequence.contains(['[', '{', '«', '<']) { tokenize(&sequence) } else { normalize(&sequence).keys };
+    let messages = eos_messages(&keys, *mode);
+    let packets: Vec<OscPacket> = if *as_bundle && !messages.is_empty() {
+        vec![OscPacket::Bundle(OscBundle { time_tag: IMMEDIATELY, elements: messages.into_iter().map(OscPacket::Message).collect() })]
+    } else {
+        messages.into_iter().map(OscPacket::Message).collect()
+    };
+
+    let on_sequence = {
+        let sequence = sequence.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                sequence.set(input.value());
+            }
+        })
+    };
+    let on_mode = {
+        let mode = mode.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                if let Some((m, _)) = EOS_OSC_MODES.get(select.selected_index().max(0) as usize) {
+                    mode.set(*m);
+                }
+            }
+        })
+    };
+    let on_bundle = {
+        let as_bundle = as_bundle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                as_bundle.set(input.checked());
+            }
+        })
+    };
+    let on_paste = {
+        let pasted = pasted.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(area) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                pasted.set(area.value());
+            }
+        })
+    };
+
+    let decoded = (!pasted.trim().is_empty()).then(|| parse_bytes(&pasted).and_then(|bytes| decode_packet(&bytes)));
+
+    html! {
+        <div class="osc-panel">
+            <h2>{ "📡 Construire des messages OSC pour Eos" }</h2>
+            <div class="sim-input">
+                <input type="text" value={(*sequence).clone()} oninput={on_sequence}
+                       placeholder="[1][Thru][10][At][Full][Enter] ou 1 thru 10 at full" />
+                <select onchange={on_mode}>
+                    { for EOS_OSC_MODES.iter().map(|(m, label)| html! { <option selected={*m == *mode}>{ *label }</option> }) }
+                </select>
+                <label><input type="checkbox" checked={*as_bundle} onchange={on_bundle} />{ " bundle" }</label>
+            </div>
+            <div class="sim-command-line">{ render_keys(&keys) }</div>
+            <ul class="osc-packets">
+                { for packets.iter().map(|packet| {
+                    let bytes = encode_packet(packet);
+                    html! {
+                        <li>
+                            <ul class="osc-packets">{ packet_html(packet) }</ul>
+                            <div class="osc-bytes"><span>{ "hex " }</span><code>{ to_hex(&bytes) }</code></div>
+                            <div class="osc-bytes"><span>{ "base64 " }</span><code>{ to_base64(&bytes) }</code></div>
+                        </li>
+                    }
+                })}
+            </ul>
+
+            <h2>{ "🔎 Inspecteur de paquets" }</h2>
+            <textarea rows="4" value={(*pasted).clone()} oninput={on_paste}
+                      placeholder="Collez un paquet en hexadécimal (2f656f73…) ou en base64 (L2Vvcy9…)" />
+            { match decoded {
+                None => html! {},
+                Some(Ok(packet)) => html! { <ul class="osc-packets">{ packet_html(&packet) }</ul> },
+                Some(Err(e)) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,310 @@
+// --- Open Sound Control 1.0 / 1.1 ---
+// Paquets en gros-boutiste, chaînes et blobs complétés à un multiple de 4 octets.
+// Un bundle commence par `#bundle`, suivi d'un time tag NTP et d'éléments préfixés par leur taille.
+// Bibliothèque standard uniquement : le relais `bridge/` inclut ce fichier tel quel.
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OscArg {
+    Int(i32),
+    Float(f32),
+    String(String),
+    Blob(Vec<u8>),
+    Long(i64),
+    Double(f64),
+    TimeTag(u64),
+    Char(char),
+    Color(u32),
+    Midi([u8; 4]),
+    True,
+    False,
+    Nil,
+    Impulse,
+}
+
+impl OscArg {
+    pub fn type_tag(&self) -> char {
+        match self {
+            OscArg::Int(_) => 'i',
+            OscArg::Float(_) => 'f',
+            OscArg::String(_) => 's',
+            OscArg::Blob(_) => 'b',
+            OscArg::Long(_) => 'h',
+            OscArg::Double(_) => 'd',
+            OscArg::TimeTag(_) => 't',
+            OscArg::Char(_) => 'c',
+            OscArg::Color(_) => 'r',
+            OscArg::Midi(_) => 'm',
+            OscArg::True => 'T',
+            OscArg::False => 'F',
+            OscArg::Nil => 'N',
+            OscArg::Impulse => 'I',
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            OscArg::Int(v) => v.to_string(),
+            OscArg::Float(v) => format!("{}", v),
+            OscArg::String(v) => format!("\"{}\"", v),
+            OscArg::Blob(v) => format!("{} octet(s)", v.len()),
+            OscArg::Long(v) => v.to_string(),
+            OscArg::Double(v) => format!("{}", v),
+            OscArg::TimeTag(v) => describe_time_tag(*v),
+            OscArg::Char(v) => format!("'{}'", v),
+            OscArg::Color(v) => format!("#{:08x}", v),
+            OscArg::Midi(v) => format!("{:02x} {:02x} {:02x} {:02x}", v[0], v[1], v[2], v[3]),
+            OscArg::True => "vrai".to_string(),
+            OscArg::False => "faux".to_string(),
+            OscArg::Nil => "nil".to_string(),
+            OscArg::Impulse => "impulsion".to_string(),
+        }
+    }
+}
+
+/// Time tag « immédiat » de la norme.
+pub const IMMEDIATELY: u64 = 1;
+
+pub fn describe_time_tag(tag: u64) -> String {
+    if tag == IMMEDIATELY {
+        "immédiat".to_string()
+    } else {
+        format!("{}.{:06} s NTP", tag >> 32, ((tag & 0xffff_ffff) * 1_000_000) >> 32)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OscMessage {
+    pub address: String,
+    pub args: Vec<OscArg>,
+}
+
+impl OscMessage {
+    pub fn new(address: &str, args: Vec<OscArg>) -> Self {
+        OscMessage { address: address.to_string(), args }
+    }
+
+    pub fn type_tags(&self) -> String {
+        std::iter::once(',').chain(self.args.iter().map(OscArg::type_tag)).collect()
+    }
+
+    /// Forme lisible : `/eos/cmd ,s "1 Thru 10#"`.
+    pub fn to_text(&self) -> String {
+        let args: Vec<String> = self.args.iter().map(OscArg::describe).collect();
+        format!("{} {} {}", self.address, self.type_tags(), args.join(" ")).trim_end().to_string()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OscBundle {
+    pub time_tag: u64,
+    pub elements: Vec<OscPacket>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OscPacket {
+    Message(OscMessage),
+    Bundle(OscBundle),
+}
+
+// --- Encodage ---
+
+fn push_padded(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(bytes);
+    while !out.len().is_multiple_of(4) {
+        out.push(0);
+    }
+}
+
+fn push_string(out: &mut Vec<u8>, s: &str) {
+    // Toujours au moins un octet nul de fin
+    out.extend_from_slice(s.as_bytes());
+    out.push(0);
+    push_padded(out, &[]);
+}
+
+pub fn encode_message(message: &OscMessage) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_string(&mut out, &message.address);
+    push_string(&mut out, &message.type_tags());
+    for arg in &message.args {
+        match arg {
+            OscArg::Int(v) => out.extend_from_slice(&v.to_be_bytes()),
+            OscArg::Float(v) => out.extend_from_slice(&v.to_be_bytes()),
+            OscArg::String(v) => push_string(&mut out, v),
+            OscArg::Blob(v) => {
+                out.extend_from_slice(&(v.len() as i32).to_be_bytes());
+                push_padded(&mut out, v);
+            }
+            OscArg::Long(v) => out.extend_from_slice(&v.to_be_bytes()),
+            OscArg::Double(v) => out.extend_from_slice(&v.to_be_bytes()),
+            OscArg::TimeTag(v) => out.extend_from_slice(&v.to_be_bytes()),
+            OscArg::Char(v) => out.extend_from_slice(&(*v as u32).to_be_bytes()),
+            OscArg::Color(v) => out.extend_from_slice(&v.to_be_bytes()),
+            OscArg::Midi(v) => out.extend_from_slice(v),
+            OscArg::True | OscArg::False | OscArg::Nil | OscArg::Impulse => {}
+        }
+    }
+    out
+}
+
+pub fn encode_packet(packet: &OscPacket) -> Vec<u8> {
+    match packet {
+        OscPacket::Message(message) => encode_message(message),
+        OscPacket::Bundle(bundle) => {
+            let mut out = Vec::new();
+            push_string(&mut out, "#bundle");
+            out.extend_from_slice(&bundle.time_tag.to_be_bytes());
+            for element in &bundle.elements {
+                let bytes = encode_packet(element);
+                out.extend_from_slice(&(bytes.len() as i32).to_be_bytes());
+                out.extend_from_slice(&bytes);
+            }
+            out
+        }
+    }
+}
+
+// --- Décodage ---
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(n).filter(|end| *end <= self.bytes.len());
+        let Some(end) = end else {
+            return Err(format!("paquet tronqué à l'octet {} ({} attendu(s))", self.pos, n));
+        };
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn align(&mut self) -> Result<(), String> {
+        let padding = (4 - self.pos % 4) % 4;
+        self.take(padding).map(|_| ())
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let rest = &self.bytes[self.pos..];
+        let len = rest.iter().position(|b| *b == 0).ok_or_else(|| format!("chaîne sans fin à l'octet {}", self.pos))?;
+        let text = String::from_utf8_lossy(self.take(len)?).into_owned();
+        self.take(1)?;
+        self.align()?;
+        Ok(text)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+}
+
+fn decode_message(bytes: &[u8]) -> Result<OscMessage, String> {
+    let mut reader = Reader { bytes, pos: 0 };
+    let address = reader.string()?;
+    if !address.starts_with('/') {
+        return Err(format!("adresse invalide « {} » : doit commencer par /", address));
+    }
+    // OSC 1.0 tolère l'absence de type tags
+    if reader.is_empty() {
+        return Ok(OscMessage { address, args: Vec::new() });
+    }
+    let tags = reader.string()?;
+    let Some(tags) = tags.strip_prefix(',') else {
+        return Err(format!("type tags invalides « {} »", tags));
+    };
+    let mut args = Vec::new();
+    for tag in tags.chars() {
+        let arg = match tag {
+            'i' => OscArg::Int(i32::from_be_bytes(reader.array()?)),
+            'f' => OscArg::Float(f32::from_be_bytes(reader.array()?)),
+            's' | 'S' => OscArg::String(reader.string()?),
+            'b' => {
+                let len = i32::from_be_bytes(reader.array()?);
+                let len = usize::try_from(len).map_err(|_| format!("taille de blob négative ({})", len))?;
+                let blob = reader.take(len)?.to_vec();
+                reader.align()?;
+                OscArg::Blob(blob)
+            }
+            'h' => OscArg::Long(i64::from_be_bytes(reader.array()?)),
+            'd' => OscArg::Double(f64::from_be_bytes(reader.array()?)),
+            't' => OscArg::TimeTag(u64::from_be_bytes(reader.array()?)),
+            'c' => OscArg::Char(char::from_u32(u32::from_be_bytes(reader.array()?)).unwrap_or('?')),
+            'r' => OscArg::Color(u32::from_be_bytes(reader.array()?)),
+            'm' => OscArg::Midi(reader.array()?),
+            'T' => OscArg::True,
+            'F' => OscArg::False,
+            'N' => OscArg::Nil,
+            'I' => OscArg::Impulse,
+            other => return Err(format!("type tag inconnu « {} »", other)),
+        };
+        args.push(arg);
+    }
+    Ok(OscMessage { address, args })
+}
+
+/// Profondeur maximale de bundles imbriqués : au-delà, le paquet est refusé plutôt que
+/// de laisser la récursion épuiser la pile sur un datagramme forgé.
+pub const MAX_BUNDLE_DEPTH: usize = 8;
+
+pub fn decode_packet(bytes: &[u8]) -> Result<OscPacket, String> {
+    decode_packet_at(bytes, 0)
+}
+
+fn decode_packet_at(bytes: &[u8], depth: usize) -> Result<OscPacket, String> {
+    if bytes.is_empty() {
+        return Err("paquet vide".to_string());
+    }
+    if !bytes.len().is_multiple_of(4) {
+        return Err(format!("taille {} non multiple de 4", bytes.len()));
+    }
+    if !bytes.starts_with(b"#bundle\0") {
+        return decode_message(bytes).map(OscPacket::Message);
+    }
+    if depth >= MAX_BUNDLE_DEPTH {
+        return Err(format!("plus de {} bundles imbriqués", MAX_BUNDLE_DEPTH));
+    }
+    let mut reader = Reader { bytes, pos: 8 };
+    let time_tag = u64::from_be_bytes(reader.array()?);
+    let mut elements = Vec::new();
+    while !reader.is_empty() {
+        let len = i32::from_be_bytes(reader.array()?);
+        let len = usize::try_from(len).map_err(|_| format!("taille d'élément négative ({})", len))?;
+        elements.push(decode_packet_at(reader.take(len)?, depth + 1)?);
+    }
+    Ok(OscPacket::Bundle(OscBundle { time_tag, elements }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nested(depth: usize) -> OscPacket {
+        let mut packet = OscPacket::Message(OscMessage::new("/eos/ping", Vec::new()));
+        for _ in 0..depth {
+            packet = OscPacket::Bundle(OscBundle { time_tag: IMMEDIATELY, elements: vec![packet] });
+        }
+        packet
+    }
+
+    #[test]
+    fn round_trips_nested_bundle() {
+        let packet = nested(MAX_BUNDLE_DEPTH);
+        assert_eq!(decode_packet(&encode_packet(&packet)), Ok(packet));
+    }
+
+    #[test]
+    fn rejects_deeply_nested_bundle() {
+        assert!(decode_packet(&encode_packet(&nested(MAX_BUNDLE_DEPTH + 1))).is_err());
+        // Un datagramme de ~64 Kio d'imbrications ne doit pas faire déborder la pile.
+        assert!(decode_packet(&encode_packet(&nested(3270))).is_err());
+    }
+}
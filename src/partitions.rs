@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::simulator::Simulator;
+
+// --- Partitions ---
+// Une partition est un ensemble de canaux ; chaque utilisateur travaille dans une partition
+// et ne peut sélectionner ni enregistrer que les canaux qu'elle contient.
+
+/// Sur la console, la partition 901 donne accès à tous les canaux.
+pub const ALL_CHANNELS_PARTITION: u32 = 901;
+
+/// Numéro de canal le plus élevé accepté par la console.
+pub const MAX_CHANNEL: u32 = 99999;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Partition {
+    pub number: u32,
+    pub label: String,
+    pub channels: Vec<u32>,
+}
+
+impl Partition {
+    pub fn owns(&self, channel: u32) -> bool {
+        self.number == ALL_CHANNELS_PARTITION || self.channels.contains(&channel)
+    }
+}
+
+pub fn default_partitions() -> Vec<Partition> {
+    vec![
+        Partition { number: 1, label: "Conventionnels".to_string(), channels: (1..=10).collect() },
+        Partition { number: 2, label: "Automatiques & LED".to_string(), channels: (11..=24).collect() },
+        Partition { number: ALL_CHANNELS_PARTITION, label: "Tous les canaux".to_string(), channels: Vec::new() },
+    ]
+}
+
+/// Lit une liste de canaux du type `1-10, 21, 23 thru 24`.
+pub fn parse_channel_list(text: &str) -> Result<Vec<u32>, String> {
+    let mut channels = Vec::new();
+    let mut normalized = text.to_lowercase().replace("thru", "-");
+    while normalized.contains(" -") || normalized.contains("- ") {
+        normalized = normalized.replace(" -", "-").replace("- ", "-");
+    }
+    for part in normalized.split([',', ' ', ';']).filter(|p| !p.trim().is_empty()) {
+        let range: Vec<&str> = part.split('-').map(str::trim).collect();
+        // Une plage hors limites est refusée avant d'être développée : `1-4000000000` ne doit rien allouer.
+        let parse = |s: &str| match s.parse::<u32>() {
+            Ok(channel) if (1..=MAX_CHANNEL).contains(&channel) => Ok(channel),
+            Ok(_) => Err(format!("canal hors limites (1-{}) : {}", MAX_CHANNEL, s)),
+            Err(_) => Err(format!("canal invalide : {}", s)),
+        };
+        match range.as_slice() {
+            [single] => channels.push(parse(single)?),
+            [first, last] => {
+                let (first, last) = (parse(first)?, parse(last)?);
+                channels.extend(first.min(last)..=first.max(last));
+            }
+            _ => return Err(format!("plage invalide : {}", part)),
+        }
+    }
+    channels.sort_unstable();
+    channels.dedup();
+    Ok(channels)
+}
+
+/// Affiche une liste de canaux en plages compactes (`1-10, 21`).
+pub fn format_channel_list(channels: &[u32]) -> String {
+    let mut parts = Vec::new();
+    let mut iter = channels.iter().copied().peekable();
+    while let Some(first) = iter.next() {
+        let mut last = first;
+        while iter.peek() == Some(&(last + 1)) {
+            last = iter.next().unwrap_or(last);
+        }
+        parts.push(if first == last { first.to_string() } else { format!("{}-{}", first, last) });
+    }
+    parts.join(", ")
+}
+
+// --- Composant Panneau Partitions ---
+#[derive(Properties, PartialEq)]
+pub struct PartitionsPanelProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(PartitionsPanel)]
+pub fn partitions_panel(props: &PartitionsPanelProps) -> Html {
+    let error = use_state(|| None::<String>);
+    let sim = &props.simulator;
+    let active = sim.active_partition();
+
+    let on_user = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                if let Ok(user) = input.value().parse::<u32>() {
+                    let mut sim = simulator.clone();
+                    sim.current_user = user.max(1);
+                    on_change.emit(sim);
+                }
+            }
+        })
+    };
+
+    html! {
+        <div class="partitions-panel">
+            <div class="form-actions">
+                <label>{ "Utilisateur " }
+                    <input type="number" min="1" value={sim.current_user.to_string()} onchange={on_user} />
+                </label>
+                <span class="sim-message">
+                    { match active {
+                        Some(p) => format!("Partition {} — {}", p.number, p.label),
+                        None => "Aucune partition : contrôle non partitionné".to_string(),
+                    }}
+                </span>
+            </div>
+            <ul class="partition-list">
+                { for sim.partitions.iter().map(|p| {
+                    let number = p.number;
+                    let select = {
+                        let simulator = props.simulator.clone();
+                        let on_change = props.on_change.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let mut sim = simulator.clone();
+                            sim.type_notation(&format!("{{Partition}}[{}][Enter]", number));
+                            on_change.emit(sim);
+                        })
+                    };
+                    let edit = {
+                        let simulator = props.simulator.clone();
+                        let on_change = props.on_change.clone();
+                        let error = error.clone();
+                        Callback::from(move |e: Event| {
+                            let Some(input) = e.target_dyn_into::<HtmlInputElement>() else { return };
+                            match parse_channel_list(&input.value()) {
+                                Ok(channels) => {
+                                    error.set(None);
+                                    let mut sim = simulator.clone();
+                                    if let Some(p) = sim.partitions.iter_mut().find(|p| p.number == number) {
+                                        p.channels = channels;
+                                    }
+                                    on_change.emit(sim);
+                                }
+                                Err(e) => error.set(Some(e)),
+                            }
+                        })
+                    };
+                    let is_active = active.map(|a| a.number) == Some(number);
+                    html! {
+                        <li class={classes!("command-item", is_active.then_some("selected"))}>
+                            <div class="undo-entry">
+                                <strong>{ format!("Partition {} — {}", p.number, p.label) }</strong>
+                                <button class="back-btn" onclick={select}>{ format!("{{Partition}} [{}] [Enter]", number) }</button>
+                            </div>
+                            if p.number == ALL_CHANNELS_PARTITION {
+                                <div class="undo-command">{ "Accès à tous les canaux" }</div>
+                            } else {
+                                <input type="text" value={format_channel_list(&p.channels)} onchange={edit} />
+                            }
+                        </li>
+                    }
+                })}
+            </ul>
+            if let Some(e) = &*error {
+                <p class="sim-message">{ format!("Erreur : {}", e) }</p>
+            }
+            <div class="sim-channel-grid">
+                { for sim.patch.iter().map(|entry| {
+                    let owned = sim.owns(entry.channel);
+                    html! {
+                        <div class={classes!("sim-channel", (!owned).then_some("out-of-partition"))}>
+                            <div class="sim-channel-number">{ entry.channel }</div>
+                            <div class="sim-channel-level">{ if owned { "✔" } else { "🔒" } }</div>
+                        </div>
+                    }
+                })}
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ranges_and_thru() {
+        assert_eq!(parse_channel_list("3-1, 5 thru 6").unwrap(), vec![1, 2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn rejects_channels_beyond_console_limit() {
+        assert!(parse_channel_list("1-4000000000").is_err());
+        assert!(parse_channel_list("100000").is_err());
+        assert!(parse_channel_list("0").is_err());
+        assert_eq!(parse_channel_list("99999").unwrap(), vec![MAX_CHANNEL]);
+    }
+}
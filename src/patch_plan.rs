@@ -0,0 +1,508 @@
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::quiz::{csv_field, data_url};
+use crate::fixture_import::all_profiles;
+use crate::partitions::MAX_CHANNEL;
+
+// --- Plan de patch DMX ---
+// On décrit des types de projecteurs (empreinte, quantité) ; le plan leur attribue des adresses univers par univers
+// selon des règles d'écart et d'alignement, puis signale chevauchements et débordements au-delà de l'adresse 512.
+
+pub const PATCH_PLAN_STORAGE_KEY: &str = "eos_guide_patch_plan";
+pub const UNIVERSE_SIZE: u32 = 512;
+/// Dernier univers adressable sur Eos.
+pub const MAX_UNIVERSE: u32 = 32767;
+/// Dernière adresse absolue : adresse 512 du dernier univers.
+const MAX_ADDRESS: u32 = MAX_UNIVERSE * UNIVERSE_SIZE;
+/// Un écart d'un univers entier ou plus n'a pas de sens.
+pub const MAX_GAP: u32 = UNIVERSE_SIZE - 1;
+
+/// Nombre de fixtures d'une empreinte donnée qui tiennent dans tous les univers, sans dépasser
+/// le nombre de canaux de la console.
+pub fn max_quantity(footprint: u32) -> u32 {
+    (MAX_ADDRESS / footprint.max(1)).min(MAX_CHANNEL)
+}
+
+/// Adresse DMX en univers/adresse, comme Eos l'affiche (1025 = 3/1).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DmxAddress {
+    pub universe: u32,
+    pub address: u32,
+}
+
+impl DmxAddress {
+    pub fn from_absolute(absolute: u32) -> Option<Self> {
+        let index = absolute.checked_sub(1)?;
+        let universe = index / UNIVERSE_SIZE + 1;
+        (universe <= MAX_UNIVERSE).then_some(DmxAddress { universe, address: index % UNIVERSE_SIZE + 1 })
+    }
+
+    pub fn absolute(self) -> u32 {
+        (self.universe - 1) * UNIVERSE_SIZE + self.address
+    }
+
+    /// `3/1` ou une adresse absolue `1025`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        let number = |part: &str| part.trim().parse::<u32>().map_err(|_| format!("« {} » n'est pas un nombre", part.trim()));
+        match text.split_once('/') {
+            Some((universe, address)) => {
+                let (universe, address) = (number(universe)?, number(address)?);
+                if !(1..=MAX_UNIVERSE).contains(&universe) {
+                    return Err(format!("univers {} hors de 1-{}", universe, MAX_UNIVERSE));
+                }
+                if !(1..=UNIVERSE_SIZE).contains(&address) {
+                    return Err(format!("adresse {} hors de 1-512", address));
+                }
+                Ok(DmxAddress { universe, address })
+            }
+            None => DmxAddress::from_absolute(number(text)?).ok_or_else(|| format!("adresse absolue {} hors de 1-{}", text, MAX_UNIVERSE * UNIVERSE_SIZE)),
+        }
+    }
+
+    pub fn text(self) -> String {
+        format!("{}/{}", self.universe, self.address)
+    }
+}
+
+/// Texte univers/adresse d'une adresse absolue, ou l'absolue telle quelle si elle sort de la plage.
+pub fn address_text(absolute: u32) -> String {
+    DmxAddress::from_absolute(absolute).map(DmxAddress::text).unwrap_or_else(|| absolute.to_string())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PlanRow {
+    pub fixture: String,
+    pub footprint: u32,
+    pub quantity: u32,
+    /// Premier canal ; sinon à la suite de la ligne précédente.
+    pub first_channel: Option<u32>,
+    /// Adresse absolue imposée ; sinon adressage automatique.
+    pub start: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PlanRules {
+    /// Adresse absolue de la première fixture.
+    pub start: u32,
+    /// Adresses laissées libres après chaque fixture.
+    pub gap: u32,
+    /// Chaque fixture commence sur une adresse 1 + k × `align` de son univers (1 = pas d'alignement).
+    pub align: u32,
+    /// Une fixture ne chevauche jamais deux univers.
+    pub keep_in_universe: bool,
+    /// Chaque type commence un nouvel univers.
+    pub universe_per_type: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PatchPlan {
+    pub rules: PlanRules,
+    pub rows: Vec<PlanRow>,
+}
+
+impl Default for PatchPlan {
+    fn default() -> Self {
+        let row = |fixture: &str, footprint, quantity| PlanRow { fixture: fixture.to_string(), footprint, quantity, first_channel: None, start: None };
+        PatchPlan {
+            rules: PlanRules { start: 1, gap: 0, align: 1, keep_in_universe: true, universe_per_type: false },
+            rows: vec![row("Par LED RGB", 4, 24), row("Spot asservi CMY", 10, 12), row("Gradateur", 1, 48)],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placement {
+    pub channel: u32,
+    pub fixture: String,
+    pub footprint: u32,
+    /// Adresse absolue de départ.
+    pub start: u32,
+}
+
+impl Placement {
+    pub fn end(&self) -> u32 {
+        self.start.saturating_add(self.footprint.max(1) - 1)
+    }
+}
+
+/// Première adresse alignée à partir de `cursor` dans son univers.
+fn align_address(cursor: u32, align: u32) -> u32 {
+    if align <= 1 {
+        return cursor;
+    }
+    let index = cursor - 1;
+    let offset = index % UNIVERSE_SIZE;
+    let aligned = offset.div_ceil(align) * align;
+    if aligned >= UNIVERSE_SIZE {
+        index - offset + UNIVERSE_SIZE + 1
+    } else {
+        index - offset + aligned + 1
+    }
+}
+
+fn next_universe(cursor: u32) -> u32 {
+    (cursor - 1) / UNIVERSE_SIZE * UNIVERSE_SIZE + UNIVERSE_SIZE + 1
+}
+
+impl PatchPlan {
+    /// Adresse chaque fixture ; celles qui commenceraient au-delà du dernier univers ou du dernier canal
+    /// ne sont pas placées.
+    pub fn place(&self) -> Vec<Placement> {
+        let rules = &self.rules;
+        let gap = rules.gap.min(MAX_GAP);
+        let mut placements = Vec::new();
+        let mut cursor = rules.start.max(1);
+        let mut channel = 1;
+        for (row_index, row) in self.rows.iter().enumerate() {
+            if let Some(first) = row.first_channel {
+                channel = first;
+            }
+            match row.start {
+                Some(start) => cursor = start.max(1),
+                None if rules.universe_per_type && row_index > 0 && !(cursor - 1).is_multiple_of(UNIVERSE_SIZE) => cursor = next_universe(cursor),
+                None => {}
+            }
+            let footprint = row.footprint.max(1);
+            for _ in 0..row.quantity.min(max_quantity(footprint)) {
+                if cursor > MAX_ADDRESS || channel > MAX_CHANNEL || placements.len() >= MAX_CHANNEL as usize {
+                    break;
+                }
+                cursor = align_address(cursor, rules.align);
+                if rules.keep_in_universe && footprint <= UNIVERSE_SIZE && (cursor - 1) % UNIVERSE_SIZE + footprint > UNIVERSE_SIZE {
+                    cursor = align_address(next_universe(cursor), rules.align);
+                }
+                if cursor > MAX_ADDRESS {
+                    break;
+                }
+                placements.push(Placement { channel, fixture: row.fixture.clone(), footprint, start: cursor });
+                let (Some(next_cursor), Some(next_channel)) = (cursor.checked_add(footprint).and_then(|c| c.checked_add(gap)), channel.checked_add(1)) else {
+                    break;
+                };
+                cursor = next_cursor;
+                channel = next_channel;
+            }
+        }
+        placements
+    }
+
+    /// Fixtures demandées qui n'ont pas trouvé d'adresse ou de canal libre.
+    pub fn unplaced(&self, placements: &[Placement]) -> u64 {
+        self.rows.iter().map(|r| r.quantity as u64).sum::<u64>().saturating_sub(placements.len() as u64)
+    }
+
+    /// Fichier d'import de patch CSV de la console (File > Import > CSV) : lignes d'en-tête
+    /// `START_CHANNELS` / `END_CHANNELS` encadrant une ligne par canal.
+    /// Le type de fixture est repris tel quel : à l'import, il doit correspondre au nom d'un type
+    /// de la bibliothèque. Le label numérote les fixtures de chaque type (`Par LED RGB 3`).
+    pub fn to_csv(placements: &[Placement]) -> String {
+        let mut lines = vec!["START_CHANNELS".to_string(), "CHANNEL,ADDRESS,FIXTURE_TYPE,LABEL".to_string()];
+        let mut counts: HashMap<&str, u32> = HashMap::new();
+        for p in placements {
+            let count = counts.entry(p.fixture.as_str()).or_default();
+            *count += 1;
+            let label = format!("{} {}", p.fixture, count);
+            lines.push([p.channel.to_string(), p.start.to_string(), csv_field(&p.fixture), csv_field(&label)].join(","));
+        }
+        lines.push("END_CHANNELS".to_string());
+        lines.join("\n")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlanIssue {
+    /// Deux fixtures partagent des adresses.
+    Overlap { first: u32, second: u32, from: u32, to: u32 },
+    /// La fixture passe l'adresse 512 et déborde sur l'univers suivant.
+    Overflow { channel: u32, start: u32, end: u32 },
+    OutOfRange { channel: u32 },
+    DuplicateChannel { channel: u32 },
+}
+
+impl PlanIssue {
+    pub fn describe(&self) -> String {
+        match self {
+            PlanIssue::Overlap { first, second, from, to } if from == to => format!("Canaux {} et {} : chevauchement sur l'adresse {}", first, second, address_text(*from)),
+            PlanIssue::Overlap { first, second, from, to } => format!("Canaux {} et {} : chevauchement de {} à {}", first, second, address_text(*from), address_text(*to)),
+            PlanIssue::Overflow { channel, start, end } => format!("Canal {} : {} à {} déborde au-delà de l'adresse 512", channel, address_text(*start), address_text(*end)),
+            PlanIssue::OutOfRange { channel } => format!("Canal {} : au-delà de l'univers {}", channel, MAX_UNIVERSE),
+            PlanIssue::DuplicateChannel { channel } => format!("Canal {} attribué plusieurs fois", channel),
+        }
+    }
+}
+
+pub fn check(placements: &[Placement]) -> Vec<PlanIssue> {
+    let mut issues = Vec::new();
+    let mut sorted: Vec<&Placement> = placements.iter().collect();
+    sorted.sort_by_key(|p| p.start);
+    for (i, a) in sorted.iter().enumerate() {
+        for b in sorted[i + 1..].iter().take_while(|b| b.start <= a.end()) {
+            issues.push(PlanIssue::Overlap { first: a.channel, second: b.channel, from: b.start, to: a.end().min(b.end()) });
+        }
+    }
+    for p in placements {
+        if DmxAddress::from_absolute(p.end()).is_none() {
+            issues.push(PlanIssue::OutOfRange { channel: p.channel });
+        } else if (p.start - 1) / UNIVERSE_SIZE != (p.end() - 1) / UNIVERSE_SIZE {
+            issues.push(PlanIssue::Overflow { channel: p.channel, start: p.start, end: p.end() });
+        }
+    }
+    let mut channels: Vec<u32> = placements.iter().map(|p| p.channel).collect();
+    channels.sort_unstable();
+    let mut duplicates: Vec<u32> = channels.windows(2).filter(|w| w[0] == w[1]).map(|w| w[0]).collect();
+    duplicates.dedup();
+    issues.extend(duplicates.into_iter().map(|channel| PlanIssue::DuplicateChannel { channel }));
+    issues
+}
+
+/// Adresses occupées par univers, pour le résumé.
+fn universe_usage(placements: &[Placement]) -> Vec<(u32, u32)> {
+    let mut usage: std::collections::BTreeMap<u32, u32> = std::collections::BTreeMap::new();
+    for p in placements {
+        // Univers par univers plutôt qu'adresse par adresse
+        let end = p.end().min(MAX_ADDRESS);
+        let mut absolute = p.start;
+        while absolute <= end {
+            let universe = (absolute - 1) / UNIVERSE_SIZE + 1;
+            let last = (universe * UNIVERSE_SIZE).min(end);
+            *usage.entry(universe).or_default() += last - absolute + 1;
+            absolute = last + 1;
+        }
+    }
+    usage.into_iter().collect()
+}
+
+// --- Composant Planificateur de patch ---
+
+fn save_plan(plan: &PatchPlan) {
+    if let Err(e) = LocalStorage::set(PATCH_PLAN_STORAGE_KEY, plan) {
+        gloo::console::error!(format!("Sauvegarde du plan de patch impossible : {:?}", e));
+    }
+}
+
+type PlanEdit = dyn Fn(&mut PatchPlan, String) -> Result<(), String>;
+
+/// Champ numérique facultatif : vide = automatique.
+fn optional_number(value: &str) -> Result<Option<u32>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value.parse().map(Some).map_err(|_| format!("« {} » n'est pas un nombre", value))
+}
+
+#[function_component(PatchPlanner)]
+pub fn patch_planner() -> Html {
+    let plan = use_state(|| LocalStorage::get::<PatchPlan>(PATCH_PLAN_STORAGE_KEY).unwrap_or_default());
+    let error = use_state(|| None::<String>);
+    let convert_text = use_state(|| "1025".to_string());
+
+    let placements = plan.place();
+    let issues = check(&placements);
+
+    // Modifie le plan à partir de la valeur d'un champ et enregistre
+    let on_edit = {
+        let plan = plan.clone();
+        let error = error.clone();
+        move |f: Box<PlanEdit>| {
+            let plan = plan.clone();
+            let error = error.clone();
+            Callback::from(move |e: Event| {
+                let value = if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                    if input.type_() == "checkbox" { input.checked().to_string() } else { input.value() }
+                } else if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                    select.value()
+                } else {
+                    return;
+                };
+                let mut next = (*plan).clone();
+                match f(&mut next, value) {
+                    Ok(()) => {
+                        save_plan(&next);
+                        plan.set(next);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            })
+        }
+    };
+    let on_add = {
+        let plan = plan.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*plan).clone();
+            next.rows.push(PlanRow { fixture: "Gradateur".to_string(), footprint: 1, quantity: 1, first_channel: None, start: None });
+            save_plan(&next);
+            plan.set(next);
+        })
+    };
+    let on_reset = {
+        let plan = plan.clone();
+        Callback::from(move |_: MouseEvent| {
+            let next = PatchPlan::default();
+            save_plan(&next);
+            plan.set(next);
+        })
+    };
+    let on_convert = {
+        let convert_text = convert_text.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                convert_text.set(input.value());
+            }
+        })
+    };
+
+    let profiles = all_profiles();
+    let rules = &plan.rules;
+    let usage = universe_usage(&placements);
+    let unplaced = plan.unplaced(&placements);
+    // Canaux signalés, calculés une fois pour tout le tableau.
+    let flagged: HashSet<u32> = issues
+        .iter()
+        .flat_map(|issue| match issue {
+            PlanIssue::Overlap { first, second, .. } => vec![*first, *second],
+            PlanIssue::Overflow { channel, .. } | PlanIssue::OutOfRange { channel } | PlanIssue::DuplicateChannel { channel } => vec![*channel],
+        })
+        .collect();
+
+    html! {
+        <div class="patch-planner">
+            <h3>{ "Conversion d'adresse" }</h3>
+            <div class="sim-input">
+                <input type="text" value={(*convert_text).clone()} oninput={on_convert} placeholder="1025 ou 3/1" />
+                { match DmxAddress::parse(&convert_text) {
+                    Ok(a) => html! { <span>{ "= " }<code>{ a.text() }</code>{ " = adresse absolue " }<code>{ a.absolute() }</code></span> },
+                    Err(e) => html! { <span class="sim-message">{ format!("Erreur : {}", e) }</span> },
+                }}
+            </div>
+
+            <h3>{ "Règles" }</h3>
+            <div class="effect-form">
+                <label>{ "Départ" }
+                    <input type="text" value={address_text(rules.start)} onchange={on_edit(Box::new(|p, v| {
+                        p.rules.start = DmxAddress::parse(&v)?.absolute();
+                        Ok(())
+                    }))} />
+                </label>
+                <label>{ "Écart entre fixtures" }
+                    <input type="number" min="0" max={MAX_GAP.to_string()} value={rules.gap.to_string()} onchange={on_edit(Box::new(|p, v| {
+                        p.rules.gap = v.trim().parse::<u32>().ok().filter(|g| *g <= MAX_GAP).ok_or_else(|| format!("écart « {} » : de 0 à {}", v, MAX_GAP))?;
+                        Ok(())
+                    }))} />
+                </label>
+                <label>{ "Aligner sur" }
+                    <input type="number" min="1" max="512" value={rules.align.to_string()} onchange={on_edit(Box::new(|p, v| {
+                        p.rules.align = v.trim().parse::<u32>().ok().filter(|a| (1..=UNIVERSE_SIZE).contains(a)).ok_or_else(|| format!("alignement « {} » : de 1 à 512", v))?;
+                        Ok(())
+                    }))} />
+                </label>
+                <label><input type="checkbox" checked={rules.keep_in_universe} onchange={on_edit(Box::new(|p, v| {
+                    p.rules.keep_in_universe = v == "true";
+                    Ok(())
+                }))} />{ " Ne pas couper une fixture entre deux univers" }</label>
+                <label><input type="checkbox" checked={rules.universe_per_type} onchange={on_edit(Box::new(|p, v| {
+                    p.rules.universe_per_type = v == "true";
+                    Ok(())
+                }))} />{ " Un univers par type" }</label>
+            </div>
+
+            <h3>{ "Types de fixtures" }</h3>
+            <table class="event-table">
+                <tr><th>{ "Modèle" }</th><th>{ "Type" }</th><th>{ "Empreinte" }</th><th>{ "Quantité" }</th><th>{ "1er canal" }</th><th>{ "Adresse imposée" }</th><th></th></tr>
+                { for plan.rows.iter().enumerate().map(|(i, row)| html! {
+                    <tr>
+                        <td>
+                            <select onchange={on_edit(Box::new(move |p, v| {
+                                if let Some(profile) = all_profiles().into_iter().find(|f| f.name == v) {
+                                    p.rows[i].fixture = profile.name.clone();
+                                    p.rows[i].footprint = profile.footprint();
+                                }
+                                Ok(())
+                            }))}>
+                                <option selected=true>{ "—" }</option>
+                                { for profiles.iter().map(|f| html! { <option>{ &f.name }</option> }) }
+                            </select>
+                        </td>
+                        <td><input type="text" value={row.fixture.clone()} onchange={on_edit(Box::new(move |p, v| {
+                            p.rows[i].fixture = v;
+                            Ok(())
+                        }))} /></td>
+                        <td><input type="number" min="1" max="512" value={row.footprint.to_string()} onchange={on_edit(Box::new(move |p, v| {
+                            p.rows[i].footprint = v.trim().parse::<u32>().ok().filter(|f| (1..=UNIVERSE_SIZE).contains(f)).ok_or_else(|| format!("empreinte « {} » : de 1 à 512", v))?;
+                            Ok(())
+                        }))} /></td>
+                        <td><input type="number" min="0" max={max_quantity(row.footprint).to_string()} value={row.quantity.to_string()} onchange={on_edit(Box::new(move |p, v| {
+                            let max = max_quantity(p.rows[i].footprint);
+                            p.rows[i].quantity = v.trim().parse::<u32>().ok().filter(|q| *q <= max).ok_or_else(|| format!("quantité « {} » : de 0 à {}", v, max))?;
+                            Ok(())
+                        }))} /></td>
+                        <td><input type="text" value={row.first_channel.map(|c| c.to_string()).unwrap_or_default()} placeholder="à la suite" onchange={on_edit(Box::new(move |p, v| {
+                            p.rows[i].first_channel = optional_number(&v)?;
+                            Ok(())
+                        }))} /></td>
+                        <td><input type="text" value={row.start.map(address_text).unwrap_or_default()} placeholder="auto" onchange={on_edit(Box::new(move |p, v| {
+                            p.rows[i].start = if v.trim().is_empty() { None } else { Some(DmxAddress::parse(&v)?.absolute()) };
+                            Ok(())
+                        }))} /></td>
+                        <td><button class="back-btn" onclick={{
+                            let plan = plan.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let mut next = (*plan).clone();
+                                next.rows.remove(i);
+                                save_plan(&next);
+                                plan.set(next);
+                            })
+                        }}>{ "🗑" }</button></td>
+                    </tr>
+                })}
+            </table>
+            <div class="form-actions">
+                <button class="back-btn" onclick={on_add}>{ "+ Type" }</button>
+                <button class="back-btn" onclick={on_reset}>{ "⟲ Exemple" }</button>
+                <a class="back-btn" href={data_url("text/csv", &PatchPlan::to_csv(&placements))} download="patch-eos.csv" title="À importer au pupitre : File > Import > CSV">{ "⬇️ Import patch Eos (CSV)" }</a>
+            </div>
+            if let Some(e) = &*error {
+                <p class="sim-message">{ format!("Erreur : {}", e) }</p>
+            }
+
+            <h3>{ format!("Plan : {} canaux sur {} univers", placements.len(), usage.len()) }</h3>
+            if unplaced > 0 {
+                <p class="sim-message">{ format!("⚠️ {} fixture(s) non placée(s) : plus d'adresse libre avant la fin de l'univers {} ou plus de canal au-delà de {}.", unplaced, MAX_UNIVERSE, MAX_CHANNEL) }</p>
+            }
+            if issues.is_empty() {
+                <p class="sim-message">{ "✅ Aucun chevauchement ni débordement." }</p>
+            } else {
+                <ul class="template-errors">{ for issues.iter().map(|issue| html! { <li>{ issue.describe() }</li> }) }</ul>
+            }
+            <div class="patch-usage">
+                { for usage.iter().map(|(universe, used)| html! {
+                    <div class="patch-universe">
+                        <span>{ format!("Univers {}", universe) }</span>
+                        <div class="patch-bar"><div style={format!("width: {}%", (*used).min(UNIVERSE_SIZE) * 100 / UNIVERSE_SIZE)}></div></div>
+                        <span class="sim-message">{ format!("{} / 512", used) }</span>
+                    </div>
+                })}
+            </div>
+            <table class="capture-table">
+                <tr><th>{ "Canal" }</th><th>{ "Type" }</th><th>{ "Adresse" }</th><th>{ "Univers/adresse" }</th><th>{ "Fin" }</th></tr>
+                { for placements.iter().map(|p| {
+                    html! {
+                        <tr class={classes!(flagged.contains(&p.channel).then_some("capture-alert"))}>
+                            <td>{ p.channel }</td>
+                            <td>{ &p.fixture }</td>
+                            <td>{ p.start }</td>
+                            <td><code>{ address_text(p.start) }</code></td>
+                            <td><code>{ address_text(p.end()) }</code></td>
+                        </tr>
+                    }
+                })}
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,410 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::challenges::load_challenges;
+use crate::{InitialData, Level, LEARNING_PATHS_JSON};
+
+// --- Parcours d'apprentissage ---
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Step {
+    /// Fiche du guide à lire.
+    Command { command: String },
+    Reading { title: String, text: String },
+    /// Défi du simulateur, validé automatiquement une fois réussi.
+    Challenge { challenge: String },
+    /// Exercice à faire dans la Formation.
+    Drill { title: String },
+    /// Manipulation libre dans le simulateur.
+    Simulator { title: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LearningPath {
+    pub id: String,
+    pub level: Level,
+    pub title: String,
+    pub description: String,
+    pub prerequisites: Vec<String>,
+    pub steps: Vec<Step>,
+}
+
+impl LearningPath {
+    pub fn step_key(&self, index: usize) -> String {
+        format!("{}:{}", self.id, index)
+    }
+}
+
+pub fn load_paths() -> Vec<LearningPath> {
+    serde_json::from_str(LEARNING_PATHS_JSON).unwrap_or_else(|e| {
+        web_sys::console::error_1(&format!("Erreur de parsing de LEARNING_PATHS_JSON: {:?}", e).into());
+        Vec::new()
+    })
+}
+
+// --- Progression par profil ---
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct LearnerProfile {
+    pub name: String,
+    /// Étapes terminées (`parcours:index`).
+    pub completed: BTreeSet<String>,
+    /// Dernier parcours ouvert, pour « Reprendre ».
+    pub last_path: Option<String>,
+    /// Défis du simulateur réussis avec ce profil.
+    #[serde(default)]
+    pub challenges: BTreeSet<String>,
+}
+
+impl LearnerProfile {
+    pub fn new(name: &str) -> Self {
+        LearnerProfile { name: name.to_string(), completed: BTreeSet::new(), last_path: None, challenges: BTreeSet::new() }
+    }
+}
+
+/// Toujours au moins un profil : garanti à la création comme au chargement.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(from = "StoredPathsProgress")]
+pub struct PathsProgress {
+    profiles: Vec<LearnerProfile>,
+    current: usize,
+}
+
+#[derive(Deserialize)]
+struct StoredPathsProgress {
+    profiles: Vec<LearnerProfile>,
+    current: usize,
+}
+
+impl From<StoredPathsProgress> for PathsProgress {
+    fn from(stored: StoredPathsProgress) -> Self {
+        if stored.profiles.is_empty() {
+            return PathsProgress::default();
+        }
+        PathsProgress { current: stored.current.min(stored.profiles.len() - 1), profiles: stored.profiles }
+    }
+}
+
+impl Default for PathsProgress {
+    fn default() -> Self {
+        PathsProgress { profiles: vec![LearnerProfile::new("Profil 1")], current: 0 }
+    }
+}
+
+pub const PATHS_STORAGE_KEY: &str = "eos_guide_paths";
+
+impl PathsProgress {
+    pub fn profile(&self) -> &LearnerProfile {
+        &self.profiles[self.current.min(self.profiles.len() - 1)]
+    }
+
+    pub fn profile_mut(&mut self) -> &mut LearnerProfile {
+        let index = self.current.min(self.profiles.len() - 1);
+        &mut self.profiles[index]
+    }
+
+    pub fn add_profile(&mut self, name: &str) {
+        self.profiles.push(LearnerProfile::new(name));
+        self.current = self.profiles.len() - 1;
+    }
+
+    /// Note un défi réussi pour le profil courant ; `false` s'il l'était déjà.
+    pub fn complete_challenge(&mut self, id: &str) -> bool {
+        self.profile_mut().challenges.insert(id.to_string())
+    }
+
+    /// Un défi compte dès que le profil courant l'a réussi.
+    fn is_challenge_done(&self, step: &Step) -> bool {
+        matches!(step, Step::Challenge { challenge } if self.profile().challenges.contains(challenge))
+    }
+
+    pub fn is_done(&self, path: &LearningPath, index: usize) -> bool {
+        self.profile().completed.contains(&path.step_key(index)) || self.is_challenge_done(&path.steps[index])
+    }
+
+    pub fn done_count(&self, path: &LearningPath) -> usize {
+        (0..path.steps.len()).filter(|i| self.is_done(path, *i)).count()
+    }
+
+    pub fn is_complete(&self, path: &LearningPath) -> bool {
+        self.done_count(path) == path.steps.len()
+    }
+
+    /// Un parcours est ouvert quand tous ses prérequis sont terminés.
+    pub fn is_unlocked(&self, path: &LearningPath, paths: &[LearningPath]) -> bool {
+        path.prerequisites.iter().all(|id| paths.iter().find(|p| &p.id == id).is_none_or(|p| self.is_complete(p)))
+    }
+
+    /// Parcours à reprendre : le dernier ouvert s'il n'est pas fini, sinon le premier parcours ouvert et non terminé.
+    pub fn resume<'a>(&self, paths: &'a [LearningPath]) -> Option<&'a LearningPath> {
+        let last = self.profile().last_path.as_ref().and_then(|id| paths.iter().find(|p| &p.id == id));
+        last.filter(|p| !self.is_complete(p)).or_else(|| paths.iter().find(|p| self.is_unlocked(p, paths) && !self.is_complete(p)))
+    }
+}
+
+thread_local! {
+    /// Titres des défis, lus une seule fois dans CHALLENGES_JSON.
+    static CHALLENGE_TITLES: Vec<(String, String)> = load_challenges().into_iter().map(|c| (c.id, c.title)).collect();
+}
+
+fn step_title(step: &Step, data: &InitialData) -> String {
+    match step {
+        Step::Command { command } => {
+            let action = data.commands.iter().find(|c| &c.id == command).map(|c| c.action.as_str()).unwrap_or(command);
+            format!("📘 {}", action)
+        }
+        Step::Reading { title, .. } => format!("📖 {}", title),
+        Step::Challenge { challenge } => {
+            let title = CHALLENGE_TITLES.with(|titles| titles.iter().find(|(id, _)| id == challenge).map(|(_, title)| title.clone()));
+            let title = title.unwrap_or_else(|| challenge.clone());
+            format!("🏆 Défi : {}", title)
+        }
+        Step::Drill { title } => format!("🎓 {}", title),
+        Step::Simulator { title } => format!("🎛️ {}", title),
+    }
+}
+
+// --- Composant Carte « Reprendre » ---
+#[derive(Properties, PartialEq)]
+pub struct ContinueCardProps {
+    pub data: InitialData,
+    pub progress: PathsProgress,
+    /// Ouvre la vue Parcours, sur le parcours donné le cas échéant.
+    pub on_open: Callback<Option<String>>,
+}
+
+#[function_component(ContinueCard)]
+pub fn continue_card(props: &ContinueCardProps) -> Html {
+    let paths = use_memo((), |_| load_paths());
+    let profile = props.progress.profile();
+
+    let Some(path) = props.progress.resume(&paths) else {
+        return html! {
+            <div class="continue-card" onclick={props.on_open.reform(|_: MouseEvent| None)}>
+                <h3>{ format!("🏁 {} : tous les parcours sont terminés", profile.name) }</h3>
+            </div>
+        };
+    };
+    let done = props.progress.done_count(path);
+    let next = (0..path.steps.len()).find(|i| !props.progress.is_done(path, *i));
+    let open = {
+        let id = path.id.clone();
+        props.on_open.reform(move |_: MouseEvent| Some(id.clone()))
+    };
+
+    html! {
+        <div class="continue-card" onclick={open}>
+            <p class="flashcard-prompt">
+                { if done == 0 { "Commencer un parcours" } else { "Reprendre là où vous en étiez" } }
+                { format!(" — {}", profile.name) }
+            </p>
+            <h3>{ &path.title }</h3>
+            <div class="path-progress"><div style={format!("width: {}%", done * 100 / path.steps.len().max(1))}></div></div>
+            <p class="deck-stats">
+                { format!("{}/{} étapes", done, path.steps.len()) }
+                { next.map(|i| format!(" — prochaine : {}", step_title(&path.steps[i], &props.data))).unwrap_or_default() }
+            </p>
+        </div>
+    }
+}
+
+// --- Composant Vue Parcours ---
+#[derive(Properties, PartialEq)]
+pub struct PathsViewProps {
+    pub data: InitialData,
+    pub progress: PathsProgress,
+    pub on_progress: Callback<PathsProgress>,
+    pub on_show_command: Callback<String>,
+    pub on_show_training: Callback<MouseEvent>,
+    pub on_show_simulator: Callback<MouseEvent>,
+}
+
+#[function_component(PathsView)]
+pub fn paths_view(props: &PathsViewProps) -> Html {
+    let paths = use_memo((), |_| load_paths());
+    let new_profile_ref = use_node_ref();
+    let progress = &props.progress;
+
+    // Modifie la progression du profil courant puis la sauvegarde.
+    let update = {
+        let progress = props.progress.clone();
+        let on_progress = props.on_progress.clone();
+        move |f: Box<dyn Fn(&mut PathsProgress)>| {
+            let mut progress = progress.clone();
+            f(&mut progress);
+            on_progress.emit(progress);
+        }
+    };
+
+    let on_profile = {
+        let update = update.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<web_sys::HtmlSelectElement>() {
+                if let Ok(index) = select.value().parse::<usize>() {
+                    update(Box::new(move |p| p.current = index));
+                }
+            }
+        })
+    };
+
+    let add_profile = {
+        let update = update.clone();
+        let new_profile_ref = new_profile_ref.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(input) = new_profile_ref.cast::<HtmlInputElement>() else { return };
+            let name = input.value().trim().to_string();
+            if name.is_empty() {
+                return;
+            }
+            input.set_value("");
+            update(Box::new(move |p| p.add_profile(&name)));
+        })
+    };
+
+    let selected = progress.profile().last_path.as_ref().and_then(|id| paths.iter().find(|p| &p.id == id));
+
+    let profile_bar = html! {
+        <div class="form-actions">
+            <label>{ "Profil " }
+                <select onchange={on_profile}>
+                    { for progress.profiles.iter().enumerate().map(|(i, p)| html! {
+                        <option value={i.to_string()} selected={i == progress.current}>{ &p.name }</option>
+                    })}
+                </select>
+            </label>
+            <input type="text" placeholder="Nouveau profil" ref={new_profile_ref} />
+            <button class="back-btn" onclick={add_profile}>{ "+ Ajouter" }</button>
+        </div>
+    };
+
+    let Some(path) = selected.filter(|p| progress.is_unlocked(p, &paths)) else {
+        return html! {
+            <div id="paths-view" class="view active">
+                <h1>{ "🧭 Parcours" }</h1>
+                { profile_bar }
+                { for Level::ALL.iter().map(|level| html! {
+                    <div class="challenge-level">
+                        <h3>{ level.label() }</h3>
+                        <ul class="command-list">
+                            { for paths.iter().filter(|p| p.level == *level).map(|p| {
+                                let unlocked = progress.is_unlocked(p, &paths);
+                                let done = progress.done_count(p);
+                                let open = {
+                                    let update = update.clone();
+                                    let id = p.id.clone();
+                                    Callback::from(move |_: MouseEvent| {
+                                        if unlocked {
+                                            let id = id.clone();
+                                            update(Box::new(move |p| p.profile_mut().last_path = Some(id.clone())));
+                                        }
+                                    })
+                                };
+                                let missing: Vec<&str> = p
+                                    .prerequisites
+                                    .iter()
+                                    .filter_map(|id| paths.iter().find(|q| &q.id == id))
+                                    .filter(|q| !progress.is_complete(q))
+                                    .map(|q| q.title.as_str())
+                                    .collect();
+                                html! {
+                                    <li class={classes!("command-item", (!unlocked).then_some("locked"))} onclick={open}>
+                                        <strong>{ if unlocked { "" } else { "🔒 " } }{ &p.title }</strong>
+                                        <span class="deck-stats">{ format!(" — {}/{} étapes", done, p.steps.len()) }</span>
+                                        <div class="undo-command">{ &p.description }</div>
+                                        if !unlocked {
+                                            <div class="undo-command">{ format!("Prérequis : {}", missing.join(", ")) }</div>
+                                        }
+                                    </li>
+                                }
+                            })}
+                        </ul>
+                    </div>
+                })}
+            </div>
+        };
+    };
+
+    let back = {
+        let update = update.clone();
+        Callback::from(move |_: MouseEvent| update(Box::new(|p| p.profile_mut().last_path = None)))
+    };
+    let done = progress.done_count(path);
+
+    html! {
+        <div id="paths-view" class="view active">
+            <h1>{ "🧭 " }{ &path.title }</h1>
+            { profile_bar }
+            <div class="form-actions">
+                <button class="back-btn" onclick={back}>{ "← Parcours" }</button>
+                <span class="sim-message">{ format!("{} — {}/{} étapes", path.level.label(), done, path.steps.len()) }</span>
+            </div>
+            <div class="path-progress"><div style={format!("width: {}%", done * 100 / path.steps.len().max(1))}></div></div>
+            <ol class="path-steps">
+                { for path.steps.iter().enumerate().map(|(i, step)| {
+                    let is_done = progress.is_done(path, i);
+                    let key = path.step_key(i);
+                    let mark = {
+                        let update = update.clone();
+                        let key = key.clone();
+                        move || {
+                            let key = key.clone();
+                            update(Box::new(move |p| {
+                                p.profile_mut().completed.insert(key.clone());
+                            }))
+                        }
+                    };
+                    let toggle = {
+                        let update = update.clone();
+                        let key = key.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let key = key.clone();
+                            update(Box::new(move |p| {
+                                let completed = &mut p.profile_mut().completed;
+                                if !completed.remove(&key) {
+                                    completed.insert(key.clone());
+                                }
+                            }))
+                        })
+                    };
+                    let action = match step {
+                        Step::Command { command } => {
+                            let on_show_command = props.on_show_command.clone();
+                            let command = command.clone();
+                            let mark = mark.clone();
+                            html! { <button class="back-btn" onclick={Callback::from(move |_: MouseEvent| {
+                                mark();
+                                on_show_command.emit(command.clone());
+                            })}>{ "Ouvrir la fiche" }</button> }
+                        }
+                        Step::Reading { .. } => html! {},
+                        Step::Challenge { .. } | Step::Drill { .. } => html! {
+                            <button class="back-btn" onclick={props.on_show_training.clone()}>{ "Ouvrir la Formation" }</button>
+                        },
+                        Step::Simulator { .. } => html! {
+                            <button class="back-btn" onclick={props.on_show_simulator.clone()}>{ "Ouvrir le simulateur" }</button>
+                        },
+                    };
+                    let auto = progress.is_challenge_done(step);
+                    html! {
+                        <li class={classes!("command-item", "path-step", is_done.then_some("done"))}>
+                            <div class="undo-entry">
+                                <strong>{ if is_done { "✅ " } else { "⬜ " } }{ step_title(step, &props.data) }</strong>
+                                <span class="action-buttons">
+                                    { action }
+                                    if !auto {
+                                        <button class="back-btn" onclick={toggle}>{ if is_done { "Annuler" } else { "Fait ✔" } }</button>
+                                    }
+                                </span>
+                            </div>
+                            if let Step::Reading { text, .. } = step {
+                                <p class="undo-command">{ text }</p>
+                            }
+                        </li>
+                    }
+                })}
+            </ol>
+        </div>
+    }
+}
@@ -0,0 +1,416 @@
+use gloo::timers::callback::Interval;
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::flashcards::{category_of, StudyProgress};
+use crate::{Command, InitialData};
+
+// --- Questions à choix multiples ---
+// Les mauvaises réponses sont prises dans les commandes de la même catégorie,
+// pour qu'elles soient plausibles ; à défaut, dans tout le guide.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuestionKind {
+    ActionToSyntax,
+    SyntaxToAction,
+    ExampleToMeaning,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Question {
+    pub command_id: String,
+    pub kind: QuestionKind,
+    pub prompt: String,
+    pub choices: Vec<String>,
+    pub answer: usize,
+}
+
+pub const CHOICES: usize = 4;
+
+/// Mélange de Fisher-Yates avec un générateur fourni (`js_sys::Math::random` dans l'application).
+pub fn shuffle<T>(items: &mut [T], random: &mut impl FnMut() -> f64) {
+    for i in (1..items.len()).rev() {
+        let j = ((random() * (i + 1) as f64) as usize).min(i);
+        items.swap(i, j);
+    }
+}
+
+fn first_syntax(cmd: &Command) -> Option<&String> {
+    cmd.syntaxes.as_ref().and_then(|s| s.first())
+}
+
+fn examples(cmd: &Command) -> impl Iterator<Item = (&str, &str)> {
+    cmd.examples.iter().flatten().filter_map(|e| e.split_once('→')).map(|(seq, meaning)| (seq.trim(), meaning.trim()))
+}
+
+/// Réponses possibles pour une sorte de question, tirées d'une commande.
+fn answers_of(cmd: &Command, kind: QuestionKind) -> Vec<String> {
+    match kind {
+        QuestionKind::ActionToSyntax => first_syntax(cmd).cloned().into_iter().collect(),
+        QuestionKind::SyntaxToAction => vec![cmd.action.clone()],
+        QuestionKind::ExampleToMeaning => examples(cmd).map(|(_, meaning)| meaning.to_string()).collect(),
+    }
+}
+
+fn question_for(data: &InitialData, cmd: &Command, kind: QuestionKind, random: &mut impl FnMut() -> f64) -> Option<Question> {
+    let (prompt, correct) = match kind {
+        QuestionKind::ActionToSyntax => (format!("Quelle séquence pour « {} » ?", cmd.action), first_syntax(cmd)?.clone()),
+        QuestionKind::SyntaxToAction => (format!("Que fait {} ?", first_syntax(cmd)?), cmd.action.clone()),
+        QuestionKind::ExampleToMeaning => {
+            let all: Vec<(&str, &str)> = examples(cmd).collect();
+            let (seq, meaning) = all.get((random() * all.len() as f64) as usize % all.len().max(1))?;
+            (format!("Que fait {} ?", seq), meaning.to_string())
+        }
+    };
+    let category = category_of(data, &cmd.subcat);
+    let mut siblings: Vec<String> = Vec::new();
+    let mut others: Vec<String> = Vec::new();
+    for other in data.commands.iter().filter(|c| c.id != cmd.id) {
+        let target = if category.is_some() && category_of(data, &other.subcat) == category { &mut siblings } else { &mut others };
+        target.extend(answers_of(other, kind).into_iter().filter(|a| a != &correct));
+    }
+    shuffle(&mut siblings, random);
+    shuffle(&mut others, random);
+    let mut choices = vec![correct.clone()];
+    for candidate in siblings.into_iter().chain(others) {
+        if choices.len() == CHOICES {
+            break;
+        }
+        if !choices.contains(&candidate) {
+            choices.push(candidate);
+        }
+    }
+    if choices.len() < CHOICES {
+        return None;
+    }
+    shuffle(&mut choices, random);
+    let answer = choices.iter().position(|c| c == &correct)?;
+    Some(Question { command_id: cmd.id.clone(), kind, prompt, choices, answer })
+}
+
+/// Construit un questionnaire de `count` questions, éventuellement limité à une catégorie.
+pub fn generate_quiz(data: &InitialData, category: Option<&str>, count: usize, random: &mut impl FnMut() -> f64) -> Vec<Question> {
+    let kinds = [QuestionKind::ActionToSyntax, QuestionKind::SyntaxToAction, QuestionKind::ExampleToMeaning];
+    let mut candidates: Vec<(&Command, QuestionKind)> = data
+        .commands
+        .iter()
+        .filter(|c| category.is_none_or(|cat| category_of(data, &c.subcat).as_deref() == Some(cat)))
+        .flat_map(|c| kinds.iter().map(move |k| (c, *k)))
+        .collect();
+    shuffle(&mut candidates, random);
+    let mut questions: Vec<Question> = Vec::new();
+    for (cmd, kind) in candidates {
+        if questions.len() == count {
+            break;
+        }
+        // Une seule question par commande, pour couvrir plus de matière.
+        if questions.iter().any(|q| q.command_id == cmd.id) {
+            continue;
+        }
+        questions.extend(question_for(data, cmd, kind, random));
+    }
+    questions
+}
+
+// --- Résultats ---
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AnswerRecord {
+    pub question: String,
+    pub given: Option<String>,
+    pub expected: String,
+    pub correct: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct QuizResult {
+    pub candidate: String,
+    /// Date de passage (ISO 8601).
+    pub date: String,
+    pub category: Option<String>,
+    pub score: usize,
+    pub total: usize,
+    pub duration_s: f64,
+    pub answers: Vec<AnswerRecord>,
+}
+
+pub fn csv_field(text: &str) -> String {
+    format!("\"{}\"", text.replace('"', "\"\""))
+}
+
+impl QuizResult {
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 { 0.0 } else { self.score as f64 * 100.0 / self.total as f64 }
+    }
+
+    /// Export CSV : une ligne d'en-tête par passage, puis une ligne par question.
+    pub fn to_csv(&self) -> String {
+        let mut lines = vec![
+            "candidat;date;catégorie;score;total;durée (s)".to_string(),
+            [
+                csv_field(&self.candidate),
+                csv_field(&self.date),
+                csv_field(self.category.as_deref().unwrap_or("toutes")),
+                self.score.to_string(),
+                self.total.to_string(),
+                format!("{:.0}", self.duration_s),
+            ]
+            .join(";"),
+            String::new(),
+            "question;réponse donnée;réponse attendue;correct".to_string(),
+        ];
+        for a in &self.answers {
+            lines.push(
+                [
+                    csv_field(&a.question),
+                    csv_field(a.given.as_deref().unwrap_or("")),
+                    csv_field(&a.expected),
+                    (if a.correct { "oui" } else { "non" }).to_string(),
+                ]
+                .join(";"),
+            );
+        }
+        lines.join("\n")
+    }
+}
+
+/// Lien de téléchargement d'un texte, sans passer par le réseau.
+pub fn data_url(mime: &str, content: &str) -> String {
+    format!("data:{};charset=utf-8,{}", mime, js_sys::encode_uri_component(content))
+}
+
+// --- Composant Panneau Quiz ---
+const QUESTION_COUNTS: [usize; 3] = [10, 20, 30];
+/// Temps accordé par question, en secondes.
+const SECONDS_PER_QUESTION: f64 = 20.0;
+const TICK_MS: u32 = 500;
+
+#[derive(Properties, PartialEq)]
+pub struct QuizPanelProps {
+    pub data: InitialData,
+    pub progress: StudyProgress,
+    pub on_progress: Callback<StudyProgress>,
+}
+
+#[function_component(QuizPanel)]
+pub fn quiz_panel(props: &QuizPanelProps) -> Html {
+    let candidate_ref = use_node_ref();
+    let category = use_state(|| None::<String>);
+    let count = use_state(|| QUESTION_COUNTS[0]);
+    let questions = use_state(Vec::<Question>::new);
+    let given = use_state(Vec::<Option<usize>>::new);
+    let candidate = use_state(String::new);
+    let started = use_state(|| None::<f64>);
+    let result = use_state(|| None::<QuizResult>);
+    let redraw = use_force_update();
+
+    {
+        let running = started.is_some();
+        use_effect_with(running, move |running| {
+            let interval = running.then(|| Interval::new(TICK_MS, move || redraw.force_update()));
+            move || drop(interval)
+        });
+    }
+
+    let limit = questions.len() as f64 * SECONDS_PER_QUESTION;
+    let elapsed = started.map(|start| (js_sys::Date::now() - start) / 1000.0).unwrap_or(0.0);
+
+    let finish = {
+        let questions = questions.clone();
+        let given = given.clone();
+        let candidate = candidate.clone();
+        let category = category.clone();
+        let started = started.clone();
+        let result = result.clone();
+        let progress = props.progress.clone();
+        let on_progress = props.on_progress.clone();
+        move || {
+            let Some(start) = *started else { return };
+            let answers: Vec<AnswerRecord> = questions
+                .iter()
+                .enumerate()
+                .map(|(i, q)| {
+                    let choice = given.get(i).copied().flatten();
+                    AnswerRecord {
+                        question: q.prompt.clone(),
+                        given: choice.map(|c| q.choices[c].clone()),
+                        expected: q.choices[q.answer].clone(),
+                        correct: choice == Some(q.answer),
+                    }
+                })
+                .collect();
+            let quiz = QuizResult {
+                candidate: candidate.trim().to_string(),
+                date: js_sys::Date::new_0().to_iso_string().into(),
+                category: (*category).clone(),
+                score: answers.iter().filter(|a| a.correct).count(),
+                total: answers.len(),
+                duration_s: ((js_sys::Date::now() - start) / 1000.0).min(questions.len() as f64 * SECONDS_PER_QUESTION),
+                answers,
+            };
+            let mut progress = progress.clone();
+            progress.quiz_results.push(quiz.clone());
+            on_progress.emit(progress);
+            started.set(None);
+            result.set(Some(quiz));
+        }
+    };
+
+    // Fin automatique quand le temps est écoulé.
+    {
+        let time_up = started.is_some() && elapsed >= limit;
+        let finish = finish.clone();
+        use_effect_with(time_up, move |time_up| {
+            if *time_up {
+                finish();
+            }
+        });
+    }
+
+    if let Some(quiz) = &*result {
+        let restart = {
+            let result = result.clone();
+            Callback::from(move |_: MouseEvent| result.set(None))
+        };
+        let filename = format!("quiz-{}-{}.csv", if quiz.candidate.is_empty() { "anonyme" } else { &quiz.candidate }, &quiz.date[..10.min(quiz.date.len())]);
+        return html! {
+            <div class="quiz-panel">
+                <div class="flashcard">
+                    <p class="flashcard-prompt">{ "Résultat" }</p>
+                    <div class="flashcard-front">{ format!("{}/{} — {:.0}%", quiz.score, quiz.total, quiz.percent()) }</div>
+                    <p class="deck-stats">{ format!("{} — {:.0} s", if quiz.candidate.is_empty() { "Anonyme" } else { &quiz.candidate }, quiz.duration_s) }</p>
+                    <ul class="challenge-targets">
+                        { for quiz.answers.iter().map(|a| html! {
+                            <li>
+                                { if a.correct { "✅ " } else { "❌ " } }{ &a.question }
+                                if !a.correct {
+                                    <div class="undo-command">{ format!("Attendu : {}", a.expected) }</div>
+                                }
+                            </li>
+                        })}
+                    </ul>
+                    <div class="action-buttons">
+                        <a class="back-btn" href={data_url("text/csv", &quiz.to_csv())} download={filename}>{ "⬇️ Exporter (CSV)" }</a>
+                        <button class="back-btn" onclick={restart}>{ "Nouveau quiz" }</button>
+                    </div>
+                </div>
+            </div>
+        };
+    }
+
+    if started.is_none() {
+        let on_category = {
+            let category = category.clone();
+            Callback::from(move |e: Event| {
+                if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                    let value = select.value();
+                    category.set((!value.is_empty()).then_some(value));
+                }
+            })
+        };
+        let on_count = {
+            let count = count.clone();
+            Callback::from(move |e: Event| {
+                if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                    count.set(select.value().parse().unwrap_or(QUESTION_COUNTS[0]));
+                }
+            })
+        };
+        let start = {
+            let data = props.data.clone();
+            let category = category.clone();
+            let count = *count;
+            let questions = questions.clone();
+            let given = given.clone();
+            let candidate = candidate.clone();
+            let candidate_ref = candidate_ref.clone();
+            let started = started.clone();
+            Callback::from(move |_: MouseEvent| {
+                let quiz = generate_quiz(&data, category.as_deref(), count, &mut js_sys::Math::random);
+                if quiz.is_empty() {
+                    return;
+                }
+                given.set(vec![None; quiz.len()]);
+                questions.set(quiz);
+                candidate.set(candidate_ref.cast::<HtmlInputElement>().map(|i| i.value()).unwrap_or_default());
+                started.set(Some(js_sys::Date::now()));
+            })
+        };
+        return html! {
+            <div class="quiz-panel">
+                <div class="form-actions">
+                    <input type="text" placeholder="Nom du candidat" value={(*candidate).clone()} ref={candidate_ref} />
+                    <select onchange={on_category}>
+                        <option value="" selected={category.is_none()}>{ "Toutes les catégories" }</option>
+                        { for props.data.categories.iter().filter(|c| {
+                            props.data.commands.iter().any(|cmd| category_of(&props.data, &cmd.subcat).as_deref() == Some(c.id.as_str()))
+                        }).map(|c| html! {
+                            <option value={c.id.clone()} selected={category.as_deref() == Some(c.id.as_str())}>{ format!("{} {}", c.icon, c.name) }</option>
+                        })}
+                    </select>
+                    <select onchange={on_count}>
+                        { for QUESTION_COUNTS.iter().map(|n| html! {
+                            <option value={n.to_string()} selected={*count == *n}>{ format!("{} questions", n) }</option>
+                        })}
+                    </select>
+                    <button class="back-btn" onclick={start}>{ "▶ Commencer" }</button>
+                </div>
+                <p class="sim-message">{ format!("{} s par question. Fonctionne hors ligne.", SECONDS_PER_QUESTION) }</p>
+                if !props.progress.quiz_results.is_empty() {
+                    <div class="tips">
+                        <h4>{ "Passages précédents" }</h4>
+                        <ul>
+                            { for props.progress.quiz_results.iter().rev().take(10).map(|r| html! {
+                                <li>
+                                    { format!("{} — {} — {}/{} ({:.0}%) ", &r.date[..10.min(r.date.len())], if r.candidate.is_empty() { "Anonyme" } else { &r.candidate }, r.score, r.total, r.percent()) }
+                                    <a href={data_url("text/csv", &r.to_csv())} download={format!("quiz-{}.csv", r.date)}>{ "CSV" }</a>
+                                </li>
+                            })}
+                        </ul>
+                    </div>
+                }
+            </div>
+        };
+    }
+
+    let Some(index) = given.iter().position(Option::is_none) else {
+        // Toutes les questions ont une réponse.
+        let finish = finish.clone();
+        return html! {
+            <div class="quiz-panel">
+                <div class="action-buttons">
+                    <button class="back-btn" onclick={Callback::from(move |_: MouseEvent| finish())}>{ "Voir le résultat" }</button>
+                </div>
+            </div>
+        };
+    };
+    let question = &questions[index];
+    let remaining = (limit - elapsed).max(0.0);
+
+    html! {
+        <div class="quiz-panel">
+            <div class="form-actions">
+                <span class="sim-message">{ format!("Question {}/{}", index + 1, questions.len()) }</span>
+                <span class={classes!("quiz-timer", (remaining < 30.0).then_some("urgent"))}>
+                    { format!("⏱ {}:{:02}", remaining as u32 / 60, remaining as u32 % 60) }
+                </span>
+            </div>
+            <div class="path-progress"><div style={format!("width: {}%", index * 100 / questions.len().max(1))}></div></div>
+            <div class="flashcard">
+                <div class="flashcard-front">{ &question.prompt }</div>
+                <div class="quiz-choices">
+                    { for question.choices.iter().enumerate().map(|(c, choice)| {
+                        let given = given.clone();
+                        let onclick = Callback::from(move |_: MouseEvent| {
+                            let mut answers = (*given).clone();
+                            answers[index] = Some(c);
+                            given.set(answers);
+                        });
+                        let is_syntax = matches!(question.kind, QuestionKind::ActionToSyntax);
+                        html! { <button class={classes!("back-btn", "quiz-choice", is_syntax.then_some("syntax"))} {onclick}>{ choice }</button> }
+                    })}
+                </div>
+            </div>
+        </div>
+    }
+}
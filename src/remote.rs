@@ -0,0 +1,403 @@
+use std::cell::RefCell;
+
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{BinaryType, Event, HtmlInputElement, HtmlSelectElement, MessageEvent, WebSocket};
+use yew::prelude::*;
+
+use crate::notation::KeyToken;
+use crate::osc::{decode_packet, eos_messages, encode_message, EosOscMode, OscArg, OscMessage, OscPacket, EOS_OSC_MODES};
+
+// --- Envoi vers une console Eos / Nomad ---
+// Le navigateur ne peut pas émettre d'UDP : les paquets OSC partent en WebSocket binaire vers le relais
+// local `eos-osc-bridge` (dossier `bridge/`), qui les transmet à la console en UDP ou en TCP/SLIP
+// et renvoie ses réponses. Une seule liaison est partagée par toute l'application.
+
+pub const CONSOLE_STORAGE_KEY: &str = "eos_guide_console";
+
+/// Nombre de lignes gardées dans le journal des échanges.
+const LOG_SIZE: usize = 30;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ConsoleSettings {
+    /// Adresse WebSocket du relais.
+    pub url: String,
+    pub mode: EosOscMode,
+}
+
+impl Default for ConsoleSettings {
+    fn default() -> Self {
+        ConsoleSettings { url: "ws://127.0.0.1:8090".to_string(), mode: EosOscMode::NewCmd }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinkStatus {
+    Disconnected,
+    Connecting,
+    Connected,
+    Error(String),
+}
+
+impl LinkStatus {
+    fn label(&self) -> String {
+        match self {
+            LinkStatus::Disconnected => "⚪ Déconnecté".to_string(),
+            LinkStatus::Connecting => "🟡 Connexion au relais…".to_string(),
+            LinkStatus::Connected => "🟢 Connecté au relais".to_string(),
+            LinkStatus::Error(e) => format!("🔴 {}", e),
+        }
+    }
+}
+
+/// Fonctions JavaScript attachées au WebSocket, gardées en vie tant qu'il est ouvert.
+struct Handlers {
+    _open: Closure<dyn FnMut(Event)>,
+    _close: Closure<dyn FnMut(Event)>,
+    _error: Closure<dyn FnMut(Event)>,
+    _message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+struct Link {
+    settings: ConsoleSettings,
+    socket: Option<WebSocket>,
+    handlers: Option<Handlers>,
+    status: LinkStatus,
+    /// Dernier command line renvoyé par la console (`/eos/out/cmd`).
+    echo: Option<String>,
+    log: Vec<String>,
+}
+
+impl Link {
+    fn note(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > LOG_SIZE {
+            self.log.remove(0);
+        }
+    }
+}
+
+thread_local! {
+    static LINK: RefCell<Link> = RefCell::new(Link {
+        settings: LocalStorage::get(CONSOLE_STORAGE_KEY).unwrap_or_default(),
+        socket: None,
+        handlers: None,
+        status: LinkStatus::Disconnected,
+        echo: None,
+        log: Vec::new(),
+    });
+    /// Composants abonnés aux changements de la liaison, par identifiant d'abonnement.
+    static SUBSCRIBERS: RefCell<Vec<(u32, Callback<()>)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_SUBSCRIBER: RefCell<u32> = const { RefCell::new(0) };
+}
+
+fn update(f: impl FnOnce(&mut Link)) {
+    LINK.with(|link| f(&mut link.borrow_mut()));
+    // Prévenus une fois la liaison relâchée : le redessin relit l'état aussitôt.
+    let subscribers: Vec<Callback<()>> = SUBSCRIBERS.with(|s| s.borrow().iter().map(|(_, cb)| cb.clone()).collect());
+    for callback in subscribers {
+        callback.emit(());
+    }
+}
+
+fn subscribe(callback: Callback<()>) -> u32 {
+    let id = NEXT_SUBSCRIBER.with(|next| {
+        let mut next = next.borrow_mut();
+        *next = next.wrapping_add(1);
+        *next
+    });
+    SUBSCRIBERS.with(|s| s.borrow_mut().push((id, callback)));
+    id
+}
+
+fn unsubscribe(id: u32) {
+    SUBSCRIBERS.with(|s| s.borrow_mut().retain(|(other, _)| *other != id));
+}
+
+fn read<T>(f: impl FnOnce(&Link) -> T) -> T {
+    LINK.with(|link| f(&link.borrow()))
+}
+
+pub fn settings() -> ConsoleSettings {
+    read(|link| link.settings.clone())
+}
+
+pub fn save_settings(settings: ConsoleSettings) {
+    if let Err(e) = LocalStorage::set(CONSOLE_STORAGE_KEY, &settings) {
+        gloo::console::error!(format!("Sauvegarde des réglages console impossible : {:?}", e));
+    }
+    update(|link| link.settings = settings);
+}
+
+/// Messages d'un paquet reçu, bundles dépliés.
+fn flatten(packet: OscPacket, out: &mut Vec<OscMessage>) {
+    match packet {
+        OscPacket::Message(message) => out.push(message),
+        OscPacket::Bundle(bundle) => bundle.elements.into_iter().for_each(|p| flatten(p, out)),
+    }
+}
+
+fn receive(bytes: &[u8]) {
+    let mut messages = Vec::new();
+    match decode_packet(bytes) {
+        Ok(packet) => flatten(packet, &mut messages),
+        Err(e) => return update(|link| link.note(format!("← paquet illisible : {}", e))),
+    }
+    update(|link| {
+        for message in messages {
+            let parts: Vec<&str> = message.address.trim_start_matches('/').split('/').collect();
+            if matches!(parts.as_slice(), ["eos", "out", "cmd"] | ["eos", "out", "user", _, "cmd"]) {
+                link.echo = message.args.iter().find_map(|a| match a {
+                    OscArg::String(s) => Some(s.clone()),
+                    _ => None,
+                });
+            }
+            link.note(format!("← {}", message.to_text()));
+        }
+    });
+}
+
+pub fn disconnect() {
+    update(|link| {
+        if let Some(socket) = link.socket.take() {
+            socket.set_onopen(None);
+            socket.set_onclose(None);
+            socket.set_onerror(None);
+            socket.set_onmessage(None);
+            let _ = socket.close();
+        }
+        link.handlers = None;
+        link.status = LinkStatus::Disconnected;
+        link.echo = None;
+    });
+}
+
+pub fn connect(url: &str) {
+    disconnect();
+    let socket = match WebSocket::new(url) {
+        Ok(socket) => socket,
+        Err(_) => return update(|link| link.status = LinkStatus::Error(format!("Adresse WebSocket invalide : {}", url))),
+    };
+    socket.set_binary_type(BinaryType::Arraybuffer);
+
+    let open = Closure::<dyn FnMut(Event)>::new(|_: Event| {
+        update(|link| {
+            link.status = LinkStatus::Connected;
+            link.note("Relais connecté".to_string());
+        })
+    });
+    let close = Closure::<dyn FnMut(Event)>::new(|_: Event| {
+        update(|link| {
+            // Une erreur est suivie d'une fermeture : on garde le message d'erreur.
+            if !matches!(link.status, LinkStatus::Error(_)) {
+                link.status = LinkStatus::Disconnected;
+            }
+            link.socket = None;
+            link.note("Relais déconnecté".to_string());
+        })
+    });
+    let error = Closure::<dyn FnMut(Event)>::new(|_: Event| {
+        update(|link| link.status = LinkStatus::Error("Relais injoignable : eos-osc-bridge est-il lancé ?".to_string()))
+    });
+    let message = Closure::<dyn FnMut(MessageEvent)>::new(|e: MessageEvent| {
+        if let Ok(buffer) = e.data().dyn_into::<js_sys::ArrayBuffer>() {
+            receive(&js_sys::Uint8Array::new(&buffer).to_vec());
+        }
+    });
+    socket.set_onopen(Some(open.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(close.as_ref().unchecked_ref()));
+    socket.set_onerror(Some(error.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(message.as_ref().unchecked_ref()));
+
+    update(|link| {
+        link.socket = Some(socket);
+        link.handlers = Some(Handlers { _open: open, _close: close, _error: error, _message: message });
+        link.status = LinkStatus::Connecting;
+        link.note(format!("Connexion à {}", url));
+    });
+}
+
+/// Envoie une séquence du guide à la console, selon le mode choisi. Renvoie le nombre de messages.
+pub fn send_keys(keys: &[KeyToken]) -> Result<usize, String> {
+    let (socket, mode) = read(|link| (link.socket.clone(), link.settings.mode));
+    let socket = match (socket, read(|link| link.status.clone())) {
+        (Some(socket), LinkStatus::Connected) => socket,
+        _ => return Err("pas de liaison avec le relais".to_string()),
+    };
+    let messages = eos_messages(keys, mode);
+    if messages.is_empty() {
+        return Err("rien à envoyer".to_string());
+    }
+    for message in &messages {
+        socket.send_with_u8_array(&encode_message(message)).map_err(|_| "envoi refusé par le navigateur".to_string())?;
+    }
+    update(|link| {
+        for message in &messages {
+            link.note(format!("→ {}", message.to_text()));
+        }
+    });
+    Ok(messages.len())
+}
+
+// --- Abonnement des composants ---
+
+#[derive(Clone, PartialEq)]
+struct LinkView {
+    status: LinkStatus,
+    echo: Option<String>,
+    log: Vec<String>,
+}
+
+fn view() -> LinkView {
+    read(|link| LinkView { status: link.status.clone(), echo: link.echo.clone(), log: link.log.clone() })
+}
+
+/// État de la liaison, redessiné quand il change : un abonnement par composant, sans minuterie.
+#[hook]
+fn use_link() -> LinkView {
+    let state = use_state_eq(view);
+    {
+        let state = state.clone();
+        use_effect_with((), move |_| {
+            // Rattrape un changement survenu entre le premier rendu et l'abonnement.
+            state.set(view());
+            // `use_state_eq` ne redessine que si la vue a changé.
+            let id = subscribe(Callback::from(move |_| state.set(view())));
+            move || unsubscribe(id)
+        });
+    }
+    (*state).clone()
+}
+
+// --- Composant Bouton d'envoi ---
+
+#[derive(Properties, PartialEq)]
+pub struct SendToConsoleProps {
+    pub keys: Vec<KeyToken>,
+}
+
+#[function_component(SendToConsole)]
+pub fn send_to_console(props: &SendToConsoleProps) -> Html {
+    let link = use_link();
+    let feedback = use_state(|| None::<String>);
+    let connected = link.status == LinkStatus::Connected;
+
+    let onclick = {
+        let keys = props.keys.clone();
+        let feedback = feedback.clone();
+        Callback::from(move |_: MouseEvent| {
+            feedback.set(Some(match send_keys(&keys) {
+                Ok(n) => format!("✔ {} message(s) envoyé(s)", n),
+                Err(e) => format!("Erreur : {}", e),
+            }));
+        })
+    };
+
+    html! {
+        <span class="console-send">
+            <button class="back-btn" onclick={onclick} disabled={!connected || props.keys.is_empty()}
+                    title={if connected { "Envoyer la séquence à la console par OSC" } else { "Connectez le relais dans Outils › Console" }}>
+                { "🖧 Envoyer à la console" }
+            </button>
+            if connected {
+                if let Some(text) = &*feedback {
+                    <span class="console-feedback">{ text }</span>
+                }
+            }
+        </span>
+    }
+}
+
+// --- Composant Écho console ---
+
+#[function_component(ConsoleEcho)]
+pub fn console_echo() -> Html {
+    let link = use_link();
+    if link.status != LinkStatus::Connected {
+        return html! {};
+    }
+    html! {
+        <div class="console-echo" title="Command line renvoyé par la console (/eos/out/cmd)">
+            <span class="sim-prompt">{ "Console : " }</span>
+            <code>{ link.echo.clone().unwrap_or_else(|| "—".to_string()) }</code>
+        </div>
+    }
+}
+
+// --- Composant Panneau Console ---
+
+#[function_component(ConsolePanel)]
+pub fn console_panel() -> Html {
+    let link = use_link();
+    let current = settings();
+    let url = use_state(|| current.url.clone());
+
+    let on_url = {
+        let url = url.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                url.set(input.value());
+            }
+        })
+    };
+    let on_connect = {
+        let url = url.clone();
+        let connected = matches!(link.status, LinkStatus::Connected | LinkStatus::Connecting);
+        Callback::from(move |_: MouseEvent| {
+            if connected {
+                disconnect();
+            } else {
+                save_settings(ConsoleSettings { url: url.trim().to_string(), ..settings() });
+                connect(url.trim());
+            }
+        })
+    };
+    let on_mode = Callback::from(move |e: Event| {
+        if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+            if let Some((mode, _)) = EOS_OSC_MODES.get(select.selected_index().max(0) as usize) {
+                save_settings(ConsoleSettings { mode: *mode, ..settings() });
+            }
+        }
+    });
+    let on_ping = Callback::from(|_: MouseEvent| {
+        let sent = read(|link| link.socket.clone()).map(|socket| socket.send_with_u8_array(&encode_message(&OscMessage::new("/eos/ping", Vec::new()))));
+        if let Some(Ok(())) = sent {
+            update(|link| link.note("→ /eos/ping".to_string()));
+        }
+    });
+
+    let busy = matches!(link.status, LinkStatus::Connected | LinkStatus::Connecting);
+
+    html! {
+        <div class="console-panel">
+            <h2>{ "🖧 Piloter une console ou Nomad" }</h2>
+            <div class="sim-input">
+                <input type="text" value={(*url).clone()} oninput={on_url} disabled={busy} placeholder="ws://127.0.0.1:8090" />
+                <button class="back-btn" onclick={on_connect}>{ if busy { "Déconnecter" } else { "Connecter" } }</button>
+                <select onchange={on_mode} title="Forme des messages envoyés">
+                    { for EOS_OSC_MODES.iter().map(|(m, label)| html! { <option selected={*m == current.mode}>{ *label }</option> }) }
+                </select>
+                <button class="back-btn" onclick={on_ping} disabled={link.status != LinkStatus::Connected}>{ "Ping" }</button>
+            </div>
+            <p class="console-status">{ link.status.label() }</p>
+            <ConsoleEcho />
+            if !link.log.is_empty() {
+                <ul class="console-log">
+                    { for link.log.iter().rev().map(|line| html! { <li><code>{ line }</code></li> }) }
+                </ul>
+            }
+
+            <h3>{ "Mise en route" }</h3>
+            <ol class="console-steps">
+                <li>{ "Sur la console (ou Eos offline / Nomad) : Setup › System › Show Control › OSC, activez OSC RX et OSC TX ; port UDP RX 8000, IP de sortie = cet ordinateur, port UDP TX 8001." }</li>
+                <li>{ "Lancez le relais sur cet ordinateur : " }<code>{ "cargo run -p eos-osc-bridge -- --console 10.101.100.101:8000" }</code></li>
+                <li>{ "Pour OSC sur TCP (port 3032, sans réglage de ports) : " }<code>{ "cargo run -p eos-osc-bridge -- --tcp --console 10.101.100.101:3032" }</code></li>
+                <li>{ "Le relais n'accepte que les pages servies depuis http://127.0.0.1:8080 ou http://localhost:8080 ; ailleurs, ajoutez " }<code>{ "--allow-origin <adresse de cette page>" }</code>{ "." }</li>
+                <li>{ "Sans console sous la main : " }<code>{ "cargo run -p eos-osc-bridge -- fake-console" }</code>{ " puis le relais avec ses réglages par défaut." }</li>
+                <li>{ "Connectez ci-dessus, puis utilisez « 🖧 Envoyer à la console » sur les fiches et dans le simulateur." }</li>
+            </ol>
+        </div>
+    }
+}
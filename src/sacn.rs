@@ -0,0 +1,767 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::Ipv4Addr;
+
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+use crate::capture::{Capture, CaptureLoader, UdpDatagram};
+use crate::timecode::seconds_text;
+
+// --- sACN (ANSI E1.31) ---
+// Trois couches imbriquées : racine ACN (CID de la source), framing (nom, priorité, séquence, univers)
+// et DMP (start code + 512 niveaux). Les paquets étendus portent la synchronisation et la découverte.
+
+pub const SACN_PORT: u16 = 5568;
+const ACN_IDENTIFIER: &[u8; 12] = b"ASC-E1.17\0\0\0";
+
+const VECTOR_ROOT_DATA: u32 = 0x0000_0004;
+const VECTOR_ROOT_EXTENDED: u32 = 0x0000_0008;
+const VECTOR_FRAMING_DATA: u32 = 0x0000_0002;
+const VECTOR_EXTENDED_SYNC: u32 = 0x0000_0001;
+const VECTOR_EXTENDED_DISCOVERY: u32 = 0x0000_0002;
+const VECTOR_DMP_SET_PROPERTY: u8 = 0x02;
+const VECTOR_DISCOVERY_LIST: u32 = 0x0000_0001;
+
+// Bits de l'octet d'options du framing
+const OPTION_PREVIEW: u8 = 0x80;
+const OPTION_TERMINATED: u8 = 0x40;
+const OPTION_FORCE_SYNC: u8 = 0x20;
+
+/// Dernier univers de données.
+pub const MAX_UNIVERSE: u16 = 63999;
+/// Univers réservé aux paquets de découverte.
+pub const DISCOVERY_UNIVERSE: u16 = 64214;
+/// Start code des priorités par adresse (extension ETC).
+pub const START_CODE_PRIORITY: u8 = 0xDD;
+
+/// Adresse multicast d'un univers : 239.255.<octet haut>.<octet bas>.
+pub fn multicast_address(universe: u16) -> Ipv4Addr {
+    let [high, low] = universe.to_be_bytes();
+    Ipv4Addr::new(239, 255, high, low)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SacnData {
+    pub source_name: String,
+    /// 0 à 200, 100 par défaut.
+    pub priority: u8,
+    /// 0 : pas de synchronisation.
+    pub sync_address: u16,
+    pub sequence: u8,
+    pub preview: bool,
+    pub terminated: bool,
+    pub force_sync: bool,
+    pub universe: u16,
+    pub start_code: u8,
+    pub slots: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SacnFrame {
+    Data(SacnData),
+    Sync { sequence: u8, sync_address: u16 },
+    Discovery { source_name: String, page: u8, last_page: u8, universes: Vec<u16> },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SacnPacket {
+    pub cid: [u8; 16],
+    pub frame: SacnFrame,
+}
+
+/// CID au format UUID 8-4-4-4-12.
+pub fn cid_text(cid: &[u8; 16]) -> String {
+    let hex: String = cid.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
+
+fn be16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_be_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn be32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_be_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+/// Vérifie le champ flags & length d'une PDU : flags 0x7, longueur jusqu'à la fin du paquet.
+fn check_pdu(bytes: &[u8], at: usize, layer: &str) -> Result<(), String> {
+    let field = be16(bytes, at);
+    if field >> 12 != 0x7 {
+        return Err(format!("couche {} : flags 0x{:X} au lieu de 0x7", layer, field >> 12));
+    }
+    let length = (field & 0x0FFF) as usize;
+    if length != bytes.len() - at {
+        return Err(format!("couche {} : longueur annoncée {} pour {} octets reçus", layer, length, bytes.len() - at));
+    }
+    Ok(())
+}
+
+/// Nom de source UTF-8 sur 64 octets, terminé par des zéros.
+fn source_name(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+pub fn decode(bytes: &[u8]) -> Result<SacnPacket, String> {
+    if bytes.len() < 38 {
+        return Err(format!("{} octets : trop court pour un paquet sACN", bytes.len()));
+    }
+    if be16(bytes, 0) != 0x0010 || be16(bytes, 2) != 0 || &bytes[4..16] != ACN_IDENTIFIER {
+        return Err("identifiant ACN absent : pas un paquet sACN".to_string());
+    }
+    check_pdu(bytes, 16, "racine")?;
+    let mut cid = [0; 16];
+    cid.copy_from_slice(&bytes[22..38]);
+    let root_vector = be32(bytes, 18);
+    let need = |length: usize| {
+        if bytes.len() < length {
+            Err(format!("{} octets : paquet tronqué (au moins {} attendus)", bytes.len(), length))
+        } else {
+            Ok(())
+        }
+    };
+    need(44)?;
+    check_pdu(bytes, 38, "framing")?;
+    let framing_vector = be32(bytes, 40);
+    let frame = match (root_vector, framing_vector) {
+        (VECTOR_ROOT_DATA, VECTOR_FRAMING_DATA) => {
+            need(126)?;
+            check_pdu(bytes, 115, "DMP")?;
+            if bytes[117] != VECTOR_DMP_SET_PROPERTY || bytes[118] != 0xA1 || be16(bytes, 119) != 0 || be16(bytes, 121) != 1 {
+                return Err("couche DMP : en-tête invalide (vecteur 0x02, type 0xA1, adresse 0, incrément 1 attendus)".to_string());
+            }
+            let count = be16(bytes, 123) as usize;
+            if !(1..=513).contains(&count) || bytes.len() != 125 + count {
+                return Err(format!("couche DMP : {} valeurs annoncées pour {} octets", count, bytes.len() - 125));
+            }
+            let universe = be16(bytes, 113);
+            if universe == 0 || universe > MAX_UNIVERSE {
+                return Err(format!("univers {} hors de 1-{}", universe, MAX_UNIVERSE));
+            }
+            let options = bytes[112];
+            SacnFrame::Data(SacnData {
+                source_name: source_name(&bytes[44..108]),
+                priority: bytes[108],
+                sync_address: be16(bytes, 109),
+                sequence: bytes[111],
+                preview: options & OPTION_PREVIEW != 0,
+                terminated: options & OPTION_TERMINATED != 0,
+                force_sync: options & OPTION_FORCE_SYNC != 0,
+                universe,
+                start_code: bytes[125],
+                slots: bytes[126..].to_vec(),
+            })
+        }
+        (VECTOR_ROOT_EXTENDED, VECTOR_EXTENDED_SYNC) => {
+            need(49)?;
+            SacnFrame::Sync { sequence: bytes[44], sync_address: be16(bytes, 45) }
+        }
+        (VECTOR_ROOT_EXTENDED, VECTOR_EXTENDED_DISCOVERY) => {
+            need(120)?;
+            check_pdu(bytes, 112, "découverte")?;
+            if be32(bytes, 114) != VECTOR_DISCOVERY_LIST || !(bytes.len() - 120).is_multiple_of(2) {
+                return Err("couche découverte : liste d'univers invalide".to_string());
+            }
+            SacnFrame::Discovery {
+                source_name: source_name(&bytes[44..108]),
+                page: bytes[118],
+                last_page: bytes[119],
+                universes: bytes[120..].chunks(2).map(|w| be16(w, 0)).collect(),
+            }
+        }
+        (root, framing) => return Err(format!("vecteurs inconnus : racine 0x{:08X}, framing 0x{:08X}", root, framing)),
+    };
+    Ok(SacnPacket { cid, frame })
+}
+
+impl SacnPacket {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = vec![0x00, 0x10, 0x00, 0x00];
+        out.extend_from_slice(ACN_IDENTIFIER);
+        out.extend_from_slice(&[0, 0]);
+        let root_vector = if matches!(self.frame, SacnFrame::Data(_)) { VECTOR_ROOT_DATA } else { VECTOR_ROOT_EXTENDED };
+        out.extend_from_slice(&root_vector.to_be_bytes());
+        out.extend_from_slice(&self.cid);
+        out.extend_from_slice(&[0, 0]);
+        let name_field = |name: &str| {
+            let mut field = [0u8; 64];
+            let bytes = name.as_bytes();
+            let length = bytes.len().min(63);
+            field[..length].copy_from_slice(&bytes[..length]);
+            field
+        };
+        match &self.frame {
+            SacnFrame::Data(d) => {
+                out.extend_from_slice(&VECTOR_FRAMING_DATA.to_be_bytes());
+                out.extend_from_slice(&name_field(&d.source_name));
+                out.push(d.priority);
+                out.extend_from_slice(&d.sync_address.to_be_bytes());
+                out.push(d.sequence);
+                out.push((if d.preview { OPTION_PREVIEW } else { 0 }) | (if d.terminated { OPTION_TERMINATED } else { 0 }) | (if d.force_sync { OPTION_FORCE_SYNC } else { 0 }));
+                out.extend_from_slice(&d.universe.to_be_bytes());
+                out.extend_from_slice(&[0, 0, VECTOR_DMP_SET_PROPERTY, 0xA1, 0x00, 0x00, 0x00, 0x01]);
+                out.extend_from_slice(&(d.slots.len() as u16 + 1).to_be_bytes());
+                out.push(d.start_code);
+                out.extend_from_slice(&d.slots);
+            }
+            SacnFrame::Sync { sequence, sync_address } => {
+                out.extend_from_slice(&VECTOR_EXTENDED_SYNC.to_be_bytes());
+                out.push(*sequence);
+                out.extend_from_slice(&sync_address.to_be_bytes());
+                out.extend_from_slice(&[0, 0]);
+            }
+            SacnFrame::Discovery { source_name, page, last_page, universes } => {
+                out.extend_from_slice(&VECTOR_EXTENDED_DISCOVERY.to_be_bytes());
+                out.extend_from_slice(&name_field(source_name));
+                out.extend_from_slice(&[0; 4]);
+                out.extend_from_slice(&[0, 0]);
+                out.extend_from_slice(&VECTOR_DISCOVERY_LIST.to_be_bytes());
+                out.push(*page);
+                out.push(*last_page);
+                for universe in universes {
+                    out.extend_from_slice(&universe.to_be_bytes());
+                }
+            }
+        }
+        // Champs flags & length des PDU, une fois le paquet complet
+        let mut pdus = vec![16, 38];
+        match self.frame {
+            SacnFrame::Data(_) => pdus.push(115),
+            SacnFrame::Discovery { .. } => pdus.push(112),
+            SacnFrame::Sync { .. } => {}
+        }
+        let total = out.len();
+        for at in pdus {
+            out[at..at + 2].copy_from_slice(&(0x7000 | (total - at) as u16).to_be_bytes());
+        }
+        out
+    }
+}
+
+// --- Analyse d'une capture ---
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SequenceGap {
+    /// Secondes depuis le début de la capture.
+    pub time: f64,
+    pub expected: u8,
+    pub received: u8,
+}
+
+impl SequenceGap {
+    pub fn missed(&self) -> u8 {
+        self.received.wrapping_sub(self.expected)
+    }
+}
+
+/// Flux d'une source (CID) sur un univers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceStream {
+    pub cid: [u8; 16],
+    pub name: String,
+    pub address: Ipv4Addr,
+    pub universe: u16,
+    pub priority: u8,
+    pub priorities: BTreeSet<u8>,
+    pub packets: usize,
+    pub preview_packets: usize,
+    pub first_time: f64,
+    pub last_time: f64,
+    pub terminated: bool,
+    pub sync_address: u16,
+    pub sequence: Option<u8>,
+    pub gaps: Vec<SequenceGap>,
+    pub out_of_order: usize,
+    /// Derniers niveaux reçus en start code 0, complétés à 512.
+    pub levels: Vec<u8>,
+    pub slot_count: usize,
+    /// Derniers niveaux de priorité par adresse (start code 0xDD), s'il y en a.
+    pub address_priorities: Option<Vec<u8>>,
+    pub other_start_codes: BTreeSet<u8>,
+    /// Adresse multicast qui ne correspond pas à l'univers.
+    pub wrong_destination: Option<Ipv4Addr>,
+}
+
+impl SourceStream {
+    pub fn missed(&self) -> usize {
+        self.gaps.iter().map(|g| g.missed() as usize).sum()
+    }
+
+    /// Source qui pilote vraiment l'univers : ni preview seul, ni flux terminé.
+    pub fn is_live(&self) -> bool {
+        self.preview_packets < self.packets && !self.terminated
+    }
+
+    pub fn rate(&self) -> f64 {
+        let span = self.last_time - self.first_time;
+        if span > 0.0 { (self.packets - 1) as f64 / span } else { 0.0 }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conflict {
+    pub priority: u8,
+    pub names: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SacnReport {
+    pub packets: usize,
+    pub streams: Vec<SourceStream>,
+    /// Par adresse de synchronisation : paquets reçus et sources.
+    pub syncs: BTreeMap<u16, (usize, BTreeSet<String>)>,
+    /// Univers annoncés par chaque source (paquets de découverte).
+    pub discovery: BTreeMap<String, BTreeSet<u16>>,
+    pub errors: Vec<String>,
+}
+
+/// Au-delà, les erreurs ne sont plus listées une à une.
+const MAX_ERRORS: usize = 50;
+
+impl SacnReport {
+    pub fn from_capture(capture: &Capture) -> Self {
+        let start = capture.start_time();
+        let mut report = SacnReport::default();
+        let mut names: BTreeMap<[u8; 16], String> = BTreeMap::new();
+        for datagram in &capture.datagrams {
+            let time = datagram.time - start;
+            match decode(&datagram.payload) {
+                Ok(packet) => {
+                    report.packets += 1;
+                    match packet.frame {
+                        SacnFrame::Data(data) => {
+                            names.insert(packet.cid, data.source_name.clone());
+                            report.add_data(time, packet.cid, datagram, data);
+                        }
+                        SacnFrame::Sync { sync_address, .. } => {
+                            let entry = report.syncs.entry(sync_address).or_default();
+                            entry.0 += 1;
+                            entry.1.insert(names.get(&packet.cid).cloned().unwrap_or_else(|| cid_text(&packet.cid)));
+                        }
+                        SacnFrame::Discovery { source_name, universes, .. } => {
+                            report.discovery.entry(source_name).or_default().extend(universes);
+                        }
+                    }
+                }
+                // Sur un autre port, ce n'était simplement pas du sACN
+                Err(e) if datagram.destination_port == SACN_PORT => {
+                    if report.errors.len() < MAX_ERRORS {
+                        report.errors.push(format!("{} — {} : {}", seconds_text(time), datagram.source, e));
+                    }
+                }
+                Err(_) => {}
+            }
+        }
+        report
+    }
+
+    fn add_data(&mut self, time: f64, cid: [u8; 16], datagram: &UdpDatagram, data: SacnData) {
+        let index = match self.streams.iter().position(|s| s.cid == cid && s.universe == data.universe) {
+            Some(index) => index,
+            None => {
+                self.streams.push(SourceStream {
+                    cid,
+                    name: data.source_name.clone(),
+                    address: datagram.source,
+                    universe: data.universe,
+                    priority: data.priority,
+                    priorities: BTreeSet::new(),
+                    packets: 0,
+                    preview_packets: 0,
+                    first_time: time,
+                    last_time: time,
+                    terminated: false,
+                    sync_address: 0,
+                    sequence: None,
+                    gaps: Vec::new(),
+                    out_of_order: 0,
+                    levels: vec![0; 512],
+                    slot_count: 0,
+                    address_priorities: None,
+                    other_start_codes: BTreeSet::new(),
+                    wrong_destination: None,
+                });
+                self.streams.len() - 1
+            }
+        };
+        let stream = &mut self.streams[index];
+        // E1.31 § 6.7.2 : un écart de -20 à 0 est un paquet en retard ou dupliqué, à ignorer.
+        if let Some(last) = stream.sequence {
+            let step = data.sequence.wrapping_sub(last) as i8;
+            if step <= 0 && step > -20 {
+                stream.out_of_order += 1;
+                return;
+            }
+            if step > 1 {
+                stream.gaps.push(SequenceGap { time, expected: last.wrapping_add(1), received: data.sequence });
+            }
+        }
+        stream.sequence = Some(data.sequence);
+        stream.packets += 1;
+        stream.last_time = time;
+        stream.name = data.source_name;
+        stream.priority = data.priority;
+        stream.priorities.insert(data.priority);
+        stream.sync_address = data.sync_address;
+        stream.terminated = data.terminated;
+        if data.preview {
+            stream.preview_packets += 1;
+        }
+        if datagram.destination.is_multicast() && datagram.destination != multicast_address(data.universe) {
+            stream.wrong_destination = Some(datagram.destination);
+        }
+        let mut slots = data.slots;
+        let count = slots.len();
+        slots.resize(512, 0);
+        match data.start_code {
+            0 => {
+                stream.levels = slots;
+                stream.slot_count = count;
+            }
+            START_CODE_PRIORITY => stream.address_priorities = Some(slots),
+            code => {
+                stream.other_start_codes.insert(code);
+            }
+        }
+    }
+
+    pub fn universes(&self) -> Vec<u16> {
+        let set: BTreeSet<u16> = self.streams.iter().map(|s| s.universe).collect();
+        set.into_iter().collect()
+    }
+
+    pub fn streams_of(&self, universe: u16) -> Vec<&SourceStream> {
+        self.streams.iter().filter(|s| s.universe == universe).collect()
+    }
+
+    /// Plusieurs sources actives en même temps à la priorité la plus haute : le récepteur les fusionne en HTP
+    /// (ou les alterne), ce qui est rarement voulu.
+    pub fn conflict(&self, universe: u16) -> Option<Conflict> {
+        let live: Vec<&SourceStream> = self.streams_of(universe).into_iter().filter(|s| s.is_live()).collect();
+        let priority = live.iter().map(|s| s.priority).max()?;
+        let top: Vec<&SourceStream> = live.into_iter().filter(|s| s.priority == priority).collect();
+        let names: Vec<String> = top
+            .iter()
+            .filter(|a| top.iter().any(|b| a.cid != b.cid && a.first_time <= b.last_time && b.first_time <= a.last_time))
+            .map(|s| s.name.clone())
+            .collect();
+        (names.len() > 1).then_some(Conflict { priority, names })
+    }
+}
+
+// --- Capture de démonstration ---
+
+fn demo_cid(n: u8) -> [u8; 16] {
+    let mut cid = [0x5A, 0xC3, 0x1E, 0x31, 0x00, 0x00, 0x40, 0x00, 0x80, 0x00, 0, 0, 0, 0, 0, 0];
+    cid[15] = n;
+    cid
+}
+
+/// Début de la capture de démonstration (octobre 2025).
+pub const DEMO_START: f64 = 1_760_000_000.0;
+
+/// Niveaux du pupitre de démonstration : montée des adresses 1-10, 11-24 à 50 %.
+pub fn demo_console_levels(tick: u8) -> Vec<u8> {
+    let fade = (tick as usize * 255 / 29) as u8;
+    (0..512).map(|i| if i < 10 { fade } else if i < 24 { 128 } else { 0 }).collect()
+}
+
+/// Niveaux du serveur média de démonstration : 170 pixels RVB qui défilent.
+pub fn demo_media_levels(tick: u8) -> Vec<u8> {
+    (0..170 * 3).map(|i| ((i * 7 + tick as usize) % 256) as u8).collect()
+}
+
+/// Trois secondes à 10 paquets/s : pupitre et backup en conflit sur l'univers 1, un visualiseur en preview,
+/// un serveur média qui perd des paquets, un arrêt de flux, la synchronisation et la découverte.
+pub fn demo_datagrams() -> Vec<UdpDatagram> {
+    let start = DEMO_START;
+    let mut datagrams = Vec::new();
+    let mut push = |time: f64, source: Ipv4Addr, destination: Ipv4Addr, packet: &SacnPacket| {
+        datagrams.push(UdpDatagram { time, source, source_port: 49152, destination, destination_port: SACN_PORT, payload: packet.encode() });
+    };
+    let console = Ipv4Addr::new(10, 101, 100, 101);
+    let backup = Ipv4Addr::new(10, 101, 100, 102);
+    let visualiser = Ipv4Addr::new(10, 101, 100, 150);
+    let media = Ipv4Addr::new(10, 101, 50, 10);
+    let levels = |n: usize, f: &dyn Fn(usize) -> u8| (0..n).map(f).collect::<Vec<u8>>();
+    let data = |name: &str, universe: u16, priority: u8, sequence: u8, slots: Vec<u8>| SacnData {
+        source_name: name.to_string(),
+        priority,
+        sync_address: 0,
+        sequence,
+        preview: false,
+        terminated: false,
+        force_sync: false,
+        universe,
+        start_code: 0,
+        slots,
+    };
+    for tick in 0..30u8 {
+        let time = start + tick as f64 * 0.1;
+        for universe in [1, 2] {
+            let mut d = data("Eos Ti", universe, 100, tick, demo_console_levels(tick));
+            d.sync_address = 7962;
+            push(time, console, multicast_address(universe), &SacnPacket { cid: demo_cid(1), frame: SacnFrame::Data(d) });
+        }
+        push(time + 0.001, console, multicast_address(7962), &SacnPacket { cid: demo_cid(1), frame: SacnFrame::Sync { sequence: tick, sync_address: 7962 } });
+        // Le backup reste à la priorité du pupitre : les deux flux se fusionnent
+        push(time + 0.002, backup, multicast_address(1), &SacnPacket { cid: demo_cid(2), frame: SacnFrame::Data(data("Eos RPU backup", 1, 100, tick, levels(512, &|i| if i < 10 { 200 } else { 0 }))) });
+        let mut preview = data("Visualiseur", 2, 100, tick, levels(512, &|i| (i % 256) as u8));
+        preview.preview = true;
+        push(time + 0.003, visualiser, multicast_address(2), &SacnPacket { cid: demo_cid(3), frame: SacnFrame::Data(preview) });
+        // Le serveur média (séquence qui repasse par 0) perd les paquets 7 à 9 et 20, et renvoie un ancien paquet
+        if !(7..=9).contains(&tick) && tick != 20 {
+            let sequence = if tick == 25 { 23u8.wrapping_add(250) } else { tick.wrapping_add(250) };
+            push(time + 0.004, media, multicast_address(3), &SacnPacket { cid: demo_cid(4), frame: SacnFrame::Data(data("Media server", 3, 120, sequence, demo_media_levels(tick))) });
+        }
+    }
+    // Fin de flux du pupitre sur l'univers 2 : trois paquets avec le bit « Stream Terminated »
+    for n in 0..3u8 {
+        let mut d = data("Eos Ti", 2, 100, 30 + n, vec![0; 512]);
+        d.terminated = true;
+        push(start + 3.0 + n as f64 * 0.01, console, multicast_address(2), &SacnPacket { cid: demo_cid(1), frame: SacnFrame::Data(d) });
+    }
+    push(start + 0.5, console, multicast_address(DISCOVERY_UNIVERSE), &SacnPacket {
+        cid: demo_cid(1),
+        frame: SacnFrame::Discovery { source_name: "Eos Ti".to_string(), page: 0, last_page: 0, universes: vec![1, 2] },
+    });
+    // Un paquet tronqué par un équipement défaillant
+    let mut broken = SacnPacket { cid: demo_cid(5), frame: SacnFrame::Data(data("Node", 4, 100, 0, vec![255; 512])) }.encode();
+    broken.truncate(300);
+    datagrams.push(UdpDatagram {
+        time: start + 1.5,
+        source: Ipv4Addr::new(10, 101, 60, 1),
+        source_port: 49152,
+        destination: multicast_address(4),
+        destination_port: SACN_PORT,
+        payload: broken,
+    });
+    datagrams
+}
+
+// --- Composant Panneau sACN ---
+
+/// Grille 512 adresses d'un univers, 32 par ligne.
+#[derive(Properties, PartialEq)]
+pub struct DmxGridProps {
+    pub levels: Vec<u8>,
+    /// Adresses réellement reçues ; au-delà, les cases sont grisées.
+    pub count: usize,
+}
+
+#[function_component(DmxGrid)]
+pub fn dmx_grid(props: &DmxGridProps) -> Html {
+    html! {
+        <div class="dmx-grid">
+            { for props.levels.iter().enumerate().map(|(i, &level)| {
+                let title = format!("Adresse {} : {} ({} %)", i + 1, level, (level as u32 * 100 + 127) / 255);
+                let class = classes!("dmx-slot", (i >= props.count).then_some("dmx-unused"), (level == 0).then_some("dmx-zero"));
+                html! { <span {class} {title} style={format!("--level: {}%", level as u32 * 100 / 255)}>{ level }</span> }
+            })}
+        </div>
+    }
+}
+
+#[function_component(SacnPanel)]
+pub fn sacn_panel() -> Html {
+    let loaded = use_state(|| None::<(String, Result<Capture, String>)>);
+    let universe = use_state(|| None::<u16>);
+    let stream = use_state(|| 0usize);
+
+    let report = use_memo(loaded.clone(), |loaded| match loaded.as_ref() {
+        Some((_, Ok(capture))) => Some(SacnReport::from_capture(capture)),
+        _ => None,
+    });
+
+    let on_load = {
+        let loaded = loaded.clone();
+        let universe = universe.clone();
+        let stream = stream.clone();
+        Callback::from(move |result| {
+            loaded.set(Some(result));
+            universe.set(None);
+            stream.set(0);
+        })
+    };
+
+    let body = match (loaded.as_ref(), report.as_ref()) {
+        (None, _) => html! { <p class="sim-message">{ "Chargez une capture Wireshark (pcap ou pcapng) : tout est analysé dans le navigateur, rien n'est envoyé." }</p> },
+        (Some((name, Err(e))), _) => html! { <p class="sim-message">{ format!("{} : {}", name, e) }</p> },
+        (Some((name, Ok(capture))), Some(report)) => {
+            let universes = report.universes();
+            let current = universe.filter(|u| universes.contains(u)).or(universes.first().copied());
+            html! {
+                <>
+                    <p class="sim-message">{ format!(
+                        "{} ({}) : {} trames, {} datagrammes UDP, {} paquets sACN, {} univers.",
+                        name, capture.format, capture.frames, capture.datagrams.len(), report.packets, universes.len()
+                    ) }</p>
+                    if !report.errors.is_empty() {
+                        <ul class="template-errors">{ for report.errors.iter().map(|e| html! { <li>{ e }</li> }) }</ul>
+                    }
+                    { universe_table(report, current, &universe, &stream) }
+                    if let Some(u) = current {
+                        { universe_detail(report, u, *stream, &stream) }
+                    }
+                    { sync_and_discovery(report) }
+                </>
+            }
+        }
+        _ => html! {},
+    };
+
+    html! {
+        <div class="sacn-panel">
+            <CaptureLoader {on_load} />
+            { body }
+        </div>
+    }
+}
+
+fn universe_table(report: &SacnReport, current: Option<u16>, universe: &UseStateHandle<Option<u16>>, stream: &UseStateHandle<usize>) -> Html {
+    html! {
+        <table class="capture-table">
+            <tr><th>{ "Univers" }</th><th>{ "Multicast" }</th><th>{ "Sources" }</th><th>{ "Paquets" }</th><th>{ "Perdus" }</th><th>{ "Alertes" }</th></tr>
+            { for report.universes().into_iter().map(|u| {
+                let streams = report.streams_of(u);
+                let mut alerts = Vec::new();
+                if let Some(conflict) = report.conflict(u) {
+                    alerts.push(format!("⚠️ {} sources à la priorité {}", conflict.names.len(), conflict.priority));
+                }
+                if streams.iter().any(|s| s.wrong_destination.is_some()) {
+                    alerts.push("⚠️ adresse multicast incorrecte".to_string());
+                }
+                if streams.iter().any(|s| s.terminated) {
+                    alerts.push("fin de flux".to_string());
+                }
+                let onclick = {
+                    let universe = universe.clone();
+                    let stream = stream.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        universe.set(Some(u));
+                        stream.set(0);
+                    })
+                };
+                html! {
+                    <tr class={classes!("capture-row", (current == Some(u)).then_some("active"))} {onclick}>
+                        <td>{ u }</td>
+                        <td><code>{ multicast_address(u).to_string() }</code></td>
+                        <td>{ streams.len() }</td>
+                        <td>{ streams.iter().map(|s| s.packets).sum::<usize>() }</td>
+                        <td>{ streams.iter().map(|s| s.missed()).sum::<usize>() }</td>
+                        <td>{ alerts.join(" · ") }</td>
+                    </tr>
+                }
+            })}
+        </table>
+    }
+}
+
+fn universe_detail(report: &SacnReport, universe: u16, selected: usize, stream: &UseStateHandle<usize>) -> Html {
+    let streams = report.streams_of(universe);
+    let index = selected.min(streams.len().saturating_sub(1));
+    let Some(current) = streams.get(index) else { return html! {} };
+    let on_select = {
+        let stream = stream.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                stream.set(select.selected_index().max(0) as usize);
+            }
+        })
+    };
+
+    html! {
+        <>
+            <h3>{ format!("Univers {}", universe) }</h3>
+            if let Some(conflict) = report.conflict(universe) {
+                <p class="capture-alert">{ format!(
+                    "Conflit de priorité : {} émettent en même temps à la priorité {}. Le récepteur les fusionne en HTP ; donnez une priorité plus haute à la source principale.",
+                    conflict.names.join(", "), conflict.priority
+                ) }</p>
+            }
+            <table class="capture-table">
+                <tr><th>{ "Source" }</th><th>{ "CID" }</th><th>{ "IP" }</th><th>{ "Priorité" }</th><th>{ "Paquets" }</th><th>{ "Débit" }</th><th>{ "Perdus" }</th><th>{ "Hors ordre" }</th><th>{ "Indicateurs" }</th></tr>
+                { for streams.iter().map(|s| {
+                    let mut flags = Vec::new();
+                    if s.preview_packets > 0 {
+                        flags.push("preview".to_string());
+                    }
+                    if s.terminated {
+                        flags.push("fin de flux".to_string());
+                    }
+                    if s.sync_address != 0 {
+                        flags.push(format!("sync {}", s.sync_address));
+                    }
+                    if s.address_priorities.is_some() {
+                        flags.push("priorité par adresse (0xDD)".to_string());
+                    }
+                    for code in &s.other_start_codes {
+                        flags.push(format!("start code 0x{:02X}", code));
+                    }
+                    if let Some(destination) = s.wrong_destination {
+                        flags.push(format!("⚠️ envoyé à {}", destination));
+                    }
+                    let priorities = s.priorities.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" → ");
+                    html! {
+                        <tr class={classes!((!s.is_live()).then_some("capture-inactive"))}>
+                            <td>{ &s.name }</td>
+                            <td><code title={cid_text(&s.cid)}>{ &cid_text(&s.cid)[..8] }</code></td>
+                            <td>{ s.address.to_string() }</td>
+                            <td>{ priorities }</td>
+                            <td>{ s.packets }</td>
+                            <td>{ format!("{:.1} /s", s.rate()).replace('.', ",") }</td>
+                            <td>{ s.missed() }</td>
+                            <td>{ s.out_of_order }</td>
+                            <td>{ flags.join(" · ") }</td>
+                        </tr>
+                    }
+                })}
+            </table>
+
+            <div class="sim-input">
+                <select onchange={on_select}>
+                    { for streams.iter().enumerate().map(|(i, s)| html! {
+                        <option selected={i == index}>{ format!("{} ({})", s.name, s.address) }</option>
+                    })}
+                </select>
+                <span class="sim-message">{ format!("Derniers niveaux reçus : {} adresses", current.slot_count) }</span>
+            </div>
+            <DmxGrid levels={current.levels.clone()} count={current.slot_count} />
+            if let Some(priorities) = &current.address_priorities {
+                <h4>{ "Priorités par adresse (0xDD)" }</h4>
+                <DmxGrid levels={priorities.clone()} count={512} />
+            }
+            if !current.gaps.is_empty() {
+                <h4>{ "Trous de séquence" }</h4>
+                <ul class="tc-events">
+                    { for current.gaps.iter().map(|g| html! {
+                        <li>{ format!("{} : attendu {}, reçu {} — {} paquet(s) perdu(s)", seconds_text(g.time), g.expected, g.received, g.missed()) }</li>
+                    })}
+                </ul>
+            }
+        </>
+    }
+}
+
+fn sync_and_discovery(report: &SacnReport) -> Html {
+    html! {
+        <>
+            if !report.syncs.is_empty() {
+                <h3>{ "Synchronisation" }</h3>
+                <ul class="tc-events">
+                    { for report.syncs.iter().map(|(address, (count, sources))| html! {
+                        <li>{ format!("Adresse de sync {} : {} paquets de {}", address, count, sources.iter().cloned().collect::<Vec<_>>().join(", ")) }</li>
+                    })}
+                </ul>
+            }
+            if !report.discovery.is_empty() {
+                <h3>{ "Découverte d'univers" }</h3>
+                <ul class="tc-events">
+                    { for report.discovery.iter().map(|(name, universes)| html! {
+                        <li>{ format!("{} annonce : {}", name, universes.iter().map(|u| u.to_string()).collect::<Vec<_>>().join(", ")) }</li>
+                    })}
+                </ul>
+            }
+        </>
+    }
+}
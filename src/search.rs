@@ -0,0 +1,251 @@
+use std::collections::BTreeSet;
+
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::facepanel::NotationBuilder;
+use crate::normalizer::NormalizerPanel;
+use crate::{Command, InitialData, Level};
+
+// --- Étiquettes et filtres ---
+
+/// Longueur maximale d'une étiquette, en caractères.
+pub const MAX_TAG_LEN: usize = 32;
+
+/// Étiquettes en minuscules, sans `#` initial, espaces superflus ni doublons ; les étiquettes vides,
+/// trop longues ou contenant une virgule (le séparateur de saisie) sont écartées.
+pub fn normalize_tags(tags: &[String]) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    tags.iter()
+        .map(|t| t.trim().trim_start_matches('#').trim().to_lowercase())
+        .filter(|t| !t.is_empty() && t.chars().count() <= MAX_TAG_LEN && !t.contains(',') && seen.insert(t.clone()))
+        .collect()
+}
+
+/// Étiquettes saisies séparées par des virgules.
+pub fn parse_tags(text: &str) -> Vec<String> {
+    normalize_tags(&text.split(',').map(str::to_string).collect::<Vec<_>>())
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CommandFilter {
+    pub level: Option<Level>,
+    /// Toutes les étiquettes choisies doivent être présentes.
+    pub tags: BTreeSet<String>,
+}
+
+impl CommandFilter {
+    pub fn matches(&self, cmd: &Command) -> bool {
+        self.level.is_none_or(|level| cmd.level == Some(level)) && self.tags.iter().all(|t| cmd.tags.contains(t))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.level.is_none() && self.tags.is_empty()
+    }
+}
+
+/// Recherche plein texte dans l'action, la description, les syntaxes, les exemples et les étiquettes.
+pub fn matches_query(cmd: &Command, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return true;
+    }
+    let texts = [&cmd.action, &cmd.description]
+        .into_iter()
+        .chain(cmd.syntaxes.iter().flatten())
+        .chain(cmd.examples.iter().flatten())
+        .chain(cmd.tags.iter());
+    let haystack = texts.map(|t| t.to_lowercase()).collect::<Vec<_>>().join("\n");
+    query.split_whitespace().all(|word| haystack.contains(word))
+}
+
+// --- Composant Puces de filtre ---
+#[derive(Properties, PartialEq)]
+pub struct FilterChipsProps {
+    /// Commandes affichées, dont on propose les étiquettes.
+    pub commands: Vec<Command>,
+    pub filter: CommandFilter,
+    pub on_change: Callback<CommandFilter>,
+}
+
+#[function_component(FilterChips)]
+pub fn filter_chips(props: &FilterChipsProps) -> Html {
+    let tags: BTreeSet<&String> = props.commands.iter().flat_map(|c| c.tags.iter()).collect();
+
+    html! {
+        <div class="filter-chips">
+            { for Level::ALL.iter().map(|level| {
+                let active = props.filter.level == Some(*level);
+                let onclick = {
+                    let filter = props.filter.clone();
+                    let on_change = props.on_change.clone();
+                    let level = *level;
+                    Callback::from(move |_: MouseEvent| {
+                        let mut filter = filter.clone();
+                        filter.level = if active { None } else { Some(level) };
+                        on_change.emit(filter);
+                    })
+                };
+                html! { <button class={classes!("chip", "chip-level", active.then_some("active"))} {onclick}>{ level.label() }</button> }
+            })}
+            { for tags.into_iter().map(|tag| {
+                let active = props.filter.tags.contains(tag);
+                let onclick = {
+                    let filter = props.filter.clone();
+                    let on_change = props.on_change.clone();
+                    let tag = tag.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        let mut filter = filter.clone();
+                        if !filter.tags.remove(&tag) {
+                            filter.tags.insert(tag.clone());
+                        }
+                        on_change.emit(filter);
+                    })
+                };
+                html! { <button class={classes!("chip", active.then_some("active"))} {onclick}>{ format!("#{}", tag) }</button> }
+            })}
+            if !props.filter.is_empty() {
+                <button class="chip" onclick={props.on_change.reform(|_: MouseEvent| CommandFilter::default())}>{ "✕ Tout afficher" }</button>
+            }
+        </div>
+    }
+}
+
+/// Badge de niveau et étiquettes d'une commande.
+pub fn command_badges(cmd: &Command) -> Html {
+    html! {
+        <span class="command-badges">
+            if let Some(level) = cmd.level {
+                <span class="chip chip-level">{ level.label() }</span>
+            }
+            { for cmd.tags.iter().map(|t| html! { <span class="chip">{ format!("#{}", t) }</span> }) }
+        </span>
+    }
+}
+
+// --- Composant Édition du niveau et des étiquettes ---
+#[derive(Properties, PartialEq)]
+pub struct MetadataEditorProps {
+    pub command: Command,
+    pub on_save: Callback<Command>,
+}
+
+#[function_component(MetadataEditor)]
+pub fn metadata_editor(props: &MetadataEditorProps) -> Html {
+    let level = use_state(|| props.command.level);
+    let tags = use_state(|| props.command.tags.join(", "));
+
+    let on_level = {
+        let level = level.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                // Option 0 : pas de niveau
+                level.set((select.selected_index() as usize).checked_sub(1).and_then(|i| Level::ALL.get(i).copied()));
+            }
+        })
+    };
+    let on_tags = {
+        let tags = tags.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                tags.set(input.value());
+            }
+        })
+    };
+    let parsed = parse_tags(&tags);
+    let changed = *level != props.command.level || parsed != props.command.tags;
+    let on_save = {
+        let command = props.command.clone();
+        let on_save = props.on_save.clone();
+        let level = *level;
+        let parsed = parsed.clone();
+        Callback::from(move |_: MouseEvent| {
+            on_save.emit(Command { level, tags: parsed.clone(), ..command.clone() });
+        })
+    };
+
+    html! {
+        <div class="sim-input" title="Étiquettes séparées par des virgules ; une liste vide reste vide">
+            <select onchange={on_level}>
+                <option selected={level.is_none()}>{ "— Niveau —" }</option>
+                { for Level::ALL.iter().map(|l| html! { <option selected={*level == Some(*l)}>{ l.label() }</option> }) }
+            </select>
+            <input type="text" value={(*tags).clone()} oninput={on_tags} placeholder="tracking, sécurité…" />
+            <button class="back-btn" onclick={on_save} disabled={!changed}>{ "🏷️ Enregistrer" }</button>
+        </div>
+    }
+}
+
+// --- Composant Résultats de recherche ---
+#[derive(Properties, PartialEq)]
+pub struct SearchResultsProps {
+    pub data: InitialData,
+    pub on_show_command: Callback<String>,
+}
+
+#[function_component(SearchResults)]
+pub fn search_results(props: &SearchResultsProps) -> Html {
+    let query = use_state(String::new);
+    let filter = use_state(CommandFilter::default);
+    let show_panel = use_state(|| false);
+    let show_normalizer = use_state(|| false);
+
+    let on_input = {
+        let query = query.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                query.set(input.value());
+            }
+        })
+    };
+    let on_filter = {
+        let filter = filter.clone();
+        Callback::from(move |f: CommandFilter| filter.set(f))
+    };
+
+    let found: Vec<Command> = props.data.commands.iter().filter(|c| matches_query(c, &query)).cloned().collect();
+    let active = !query.trim().is_empty() || !filter.is_empty();
+
+    html! {
+        <div class="search">
+            <div class="sim-input">
+                <input class="search-input" type="search" placeholder="🔍 Rechercher une commande, une touche, une étiquette…" value={(*query).clone()} oninput={on_input} />
+                <button class="back-btn" title="Rechercher une séquence au pupitre"
+                        onclick={{ let show_panel = show_panel.clone(); Callback::from(move |_| show_panel.set(!*show_panel)) }}>{ "🎹" }</button>
+                <button class="back-btn" title="Convertir du texte libre en séquence de touches"
+                        onclick={{ let show_normalizer = show_normalizer.clone(); Callback::from(move |_| show_normalizer.set(!*show_normalizer)) }}>{ "✍️" }</button>
+            </div>
+            if *show_panel {
+                <NotationBuilder use_label="🔍 Rechercher cette séquence" on_use={{
+                    let query = query.clone();
+                    Callback::from(move |notation: String| query.set(notation))
+                }} />
+            }
+            if *show_normalizer {
+                <NormalizerPanel use_label="🔍 Rechercher cette séquence" data={props.data.clone()}
+                                 on_show_command={props.on_show_command.clone()} on_use={{
+                    let query = query.clone();
+                    Callback::from(move |notation: String| query.set(notation))
+                }} />
+            }
+            <FilterChips commands={found.clone()} filter={(*filter).clone()} on_change={on_filter} />
+            if active {
+                <div class="command-list">
+                    { for found.iter().filter(|c| filter.matches(c)).map(|cmd| {
+                        let onclick = {
+                            let on_show_command = props.on_show_command.clone();
+                            let id = cmd.id.clone();
+                            Callback::from(move |_: MouseEvent| on_show_command.emit(id.clone()))
+                        };
+                        html! {
+                            <div class="command-item" {onclick}>
+                                <h3 class="command-title">{ &cmd.action }</h3>
+                                { command_badges(cmd) }
+                            </div>
+                        }
+                    })}
+                </div>
+            }
+        </div>
+    }
+}
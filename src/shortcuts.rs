@@ -0,0 +1,206 @@
+use std::collections::BTreeSet;
+
+use yew::prelude::*;
+
+use crate::notation::{KeyKind, KeyToken};
+use crate::InitialData;
+
+// --- Raccourcis clavier ---
+// Équivalents clavier des touches physiques pour Eos offline / Nomad.
+// Un raccourci s'écrit comme `KeyboardEvent.key`, précédé des modificateurs : `Alt+R`, `Ctrl+Z`.
+// Les chiffres, `.`, `+` et `-` se tapent directement.
+
+/// Touche physique, raccourcis (le premier est celui affiché), rubrique de l'aide-mémoire.
+const SHORTCUTS: [(&str, &[&str], &str); 35] = [
+    ("At", &["@", "A"], "Pavé"),
+    ("Thru", &["T"], "Pavé"),
+    ("Full", &["F"], "Pavé"),
+    ("Out", &["O"], "Pavé"),
+    ("Enter", &["Enter"], "Pavé"),
+    ("Clear", &["Backspace"], "Pavé"),
+    ("Sneak", &["N"], "Pavé"),
+    ("Home", &["Home"], "Pavé"),
+    ("Last", &["PageUp"], "Pavé"),
+    ("Next", &["PageDown"], "Pavé"),
+    ("Record", &["R"], "Enregistrement"),
+    ("Record Only", &["Alt+R"], "Enregistrement"),
+    ("Update", &["U"], "Enregistrement"),
+    ("Cue", &["Q"], "Enregistrement"),
+    ("Q Only", &["Alt+Q"], "Enregistrement"),
+    ("Go To Cue", &["Alt+G"], "Enregistrement"),
+    ("Delete", &["Delete"], "Enregistrement"),
+    ("Time", &["I"], "Enregistrement"),
+    ("Block", &["B"], "Enregistrement"),
+    ("Assert", &["Alt+A"], "Enregistrement"),
+    ("Trace", &["Alt+T"], "Enregistrement"),
+    ("Undo", &["Ctrl+Z"], "Enregistrement"),
+    ("Group", &["G"], "Cibles"),
+    ("Sub", &["S"], "Cibles"),
+    ("Macro", &["M"], "Cibles"),
+    ("Effect", &["E"], "Cibles"),
+    ("Preset", &["P"], "Cibles"),
+    ("Palette", &["Alt+P"], "Cibles"),
+    ("Copy To", &["Alt+C"], "Cibles"),
+    ("Recall From", &["Alt+F"], "Cibles"),
+    ("Rem Dim", &["Alt+D"], "Cibles"),
+    ("Learn", &["L"], "Cibles"),
+    ("Live", &["F1"], "Affichage"),
+    ("Blind", &["F2"], "Affichage"),
+    ("Help", &["H"], "Affichage"),
+];
+
+const SECTIONS: [&str; 4] = ["Pavé", "Enregistrement", "Cibles", "Affichage"];
+
+fn is_direct(label: &str) -> bool {
+    label.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | '+' | '-'))
+}
+
+/// Raccourci affiché pour une touche, `None` si elle ne se tape pas au clavier.
+pub fn shortcut_for(key: &KeyToken) -> Option<String> {
+    if key.kind != KeyKind::Hard {
+        return None;
+    }
+    if is_direct(&key.label) {
+        return Some(key.label.clone());
+    }
+    SHORTCUTS.iter().find(|(label, _, _)| key.is(label)).map(|(_, combos, _)| combos[0].to_string())
+}
+
+/// Touche correspondant à un appui clavier (`key` au format `KeyboardEvent.key`).
+pub fn key_for(key: &str, ctrl: bool, alt: bool) -> Option<KeyToken> {
+    if !ctrl && !alt && key.chars().count() == 1 && is_direct(key) {
+        return Some(KeyToken::hard(key));
+    }
+    let pressed = combo(key, ctrl, alt);
+    SHORTCUTS
+        .iter()
+        .find(|(_, combos, _)| combos.iter().any(|c| c.eq_ignore_ascii_case(&pressed)))
+        .map(|(label, _, _)| KeyToken::hard(label))
+}
+
+fn combo(key: &str, ctrl: bool, alt: bool) -> String {
+    let mut out = String::new();
+    if ctrl {
+        out.push_str("Ctrl+");
+    }
+    if alt {
+        out.push_str("Alt+");
+    }
+    out.push_str(key);
+    out
+}
+
+pub fn key_for_event(e: &KeyboardEvent) -> Option<KeyToken> {
+    // Avec Alt, certaines dispositions produisent un autre caractère : on repart du code physique.
+    let key = match e.code().strip_prefix("Key") {
+        Some(letter) if e.alt_key() || e.ctrl_key() => letter.to_string(),
+        _ => e.key(),
+    };
+    key_for(&key, e.ctrl_key(), e.alt_key())
+}
+
+/// Libellé français d'une touche du clavier.
+fn key_name(key: &str) -> &str {
+    match key {
+        "Enter" => "Entrée",
+        "Backspace" => "⌫ Retour arrière",
+        "Delete" => "Suppr",
+        "Home" => "Début",
+        "PageUp" => "Page préc.",
+        "PageDown" => "Page suiv.",
+        other => other,
+    }
+}
+
+fn render_combo(combo: &str) -> Html {
+    let parts: Vec<&str> = if combo == "+" { vec!["+"] } else { combo.split('+').collect() };
+    html! {
+        <span class="kbd-combo">
+            { for parts.iter().enumerate().map(|(i, part)| html! {
+                <>
+                    if i > 0 { { "+" } }
+                    <kbd>{ key_name(part) }</kbd>
+                </>
+            })}
+        </span>
+    }
+}
+
+/// Rendu « clavier » d'une séquence : raccourcis pour les touches physiques, clic pour les autres.
+pub fn keyboard_html(keys: &[KeyToken]) -> Html {
+    html! {
+        <span class="keyboard-sequence">
+            { for keys.iter().map(|key| match shortcut_for(key) {
+                Some(combo) => render_combo(&combo),
+                // Paramètre à remplacer (`[touche]`, `[n]`)
+                None if key.label.starts_with(|c: char| c.is_lowercase()) => html! { <em>{ &key.label }</em> },
+                None => html! { <span class="kbd-mouse" title="Pas de raccourci : clic à la souris">{ format!("🖱 {}", key.to_notation()) }</span> },
+            })}
+        </span>
+    }
+}
+
+// --- Composant Aide-mémoire des raccourcis ---
+#[derive(Properties, PartialEq)]
+pub struct ShortcutSheetProps {
+    pub data: InitialData,
+}
+
+#[function_component(ShortcutSheet)]
+pub fn shortcut_sheet(props: &ShortcutSheetProps) -> Html {
+    // Touches physiques du guide sans équivalent clavier.
+    let missing = use_memo(props.data.clone(), |data| {
+        let keys: BTreeSet<String> = data
+            .commands
+            .iter()
+            .flat_map(|c| c.syntaxes.iter().flatten().chain(c.examples.iter().flatten()))
+            .flat_map(|s| crate::notation::tokenize(s))
+            .filter(|k| k.kind == KeyKind::Hard && k.label.starts_with(|c: char| c.is_ascii_uppercase()))
+            .filter(|k| shortcut_for(k).is_none())
+            .map(|k| k.label)
+            .collect();
+        keys.into_iter().collect::<Vec<_>>()
+    });
+
+    let print = Callback::from(|_: MouseEvent| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    });
+
+    html! {
+        <div class="shortcut-sheet printable">
+            <div class="form-actions no-print">
+                <button class="back-btn" onclick={print}>{ "🖨️ Imprimer l'aide-mémoire" }</button>
+            </div>
+            <h2>{ "⌨️ Raccourcis clavier Eos offline / Nomad" }</h2>
+            <p>{ "Chiffres, point, + et - se tapent directement. Les softkeys et touches utilisateur restent à la souris." }</p>
+            <div class="shortcut-columns">
+                { for SECTIONS.iter().map(|section| html! {
+                    <table class="shortcut-table">
+                        <tr><th colspan="2">{ *section }</th></tr>
+                        { for SHORTCUTS.iter().filter(|(_, _, s)| s == section).map(|(label, combos, _)| html! {
+                            <tr>
+                                <td><span class="key key-hard">{ *label }</span></td>
+                                <td>
+                                    { for combos.iter().enumerate().map(|(i, c)| html! {
+                                        <>
+                                            if i > 0 { { " ou " } }
+                                            { render_combo(c) }
+                                        </>
+                                    })}
+                                </td>
+                            </tr>
+                        })}
+                    </table>
+                })}
+            </div>
+            if !missing.is_empty() {
+                <p class="sim-message">
+                    { "Sans raccourci (au pupitre ou à la souris) : " }
+                    { missing.join(", ") }
+                </p>
+            }
+        </div>
+    }
+}
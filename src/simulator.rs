@@ -0,0 +1,984 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::cues::{diff, format_cue_number, CueList, Levels};
+use crate::effects::{Effect, EffectsPanel};
+use crate::event_list::EventListPanel;
+use crate::facepanel::Facepanel;
+use crate::fixture_import::FixtureLibraryPanel;
+use crate::macros::{default_macros, LearnSession, Macro, MacrosPanel};
+use crate::notation::{render_keys, to_notation, tokenize, KeyToken};
+use crate::partitions::{default_partitions, Partition, PartitionsPanel};
+use crate::remote::{ConsoleEcho, SendToConsole};
+use crate::shortcuts::key_for_event;
+use crate::undo::{push_undo, ShowSnapshot, UndoEntry, UndoKind, UndoPanel};
+
+// --- Modèle de fixture multi-paramètres ---
+// Les paramètres sont rangés dans les catégories IFCB de la console
+// (Intensité, Focus, Couleur, Beam). Toutes les valeurs sont en pourcentage 0-100.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParamCategory {
+    Intensity,
+    Focus,
+    Color,
+    Beam,
+}
+
+pub const CATEGORIES: [ParamCategory; 4] = [
+    ParamCategory::Intensity,
+    ParamCategory::Focus,
+    ParamCategory::Color,
+    ParamCategory::Beam,
+];
+
+impl ParamCategory {
+    pub fn letter(self) -> &'static str {
+        match self {
+            ParamCategory::Intensity => "I",
+            ParamCategory::Focus => "F",
+            ParamCategory::Color => "C",
+            ParamCategory::Beam => "B",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ParamCategory::Intensity => "Intensité",
+            ParamCategory::Focus => "Focus",
+            ParamCategory::Color => "Couleur",
+            ParamCategory::Beam => "Beam",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ParamKind {
+    Intensity,
+    Pan,
+    Tilt,
+    Red,
+    Green,
+    Blue,
+    Cyan,
+    Magenta,
+    Yellow,
+    White,
+    Amber,
+    ColorWheel,
+    Gobo,
+    Zoom,
+    Strobe,
+    Iris,
+    Edge,
+    Prism,
+    /// Canal réservé ou non reconnu (contrôle, macro, réglages) : occupé mais jamais piloté.
+    Control,
+}
+
+/// Paramètres pilotables depuis le command line (`Control` n'en fait pas partie).
+pub const PARAM_KINDS: [ParamKind; 18] = [
+    ParamKind::Intensity,
+    ParamKind::Pan,
+    ParamKind::Tilt,
+    ParamKind::Red,
+    ParamKind::Green,
+    ParamKind::Blue,
+    ParamKind::Cyan,
+    ParamKind::Magenta,
+    ParamKind::Yellow,
+    ParamKind::White,
+    ParamKind::Amber,
+    ParamKind::ColorWheel,
+    ParamKind::Gobo,
+    ParamKind::Zoom,
+    ParamKind::Strobe,
+    ParamKind::Iris,
+    ParamKind::Edge,
+    ParamKind::Prism,
+];
+
+impl ParamKind {
+    pub fn category(self) -> ParamCategory {
+        match self {
+            ParamKind::Intensity => ParamCategory::Intensity,
+            ParamKind::Pan | ParamKind::Tilt => ParamCategory::Focus,
+            ParamKind::Red
+            | ParamKind::Green
+            | ParamKind::Blue
+            | ParamKind::Cyan
+            | ParamKind::Magenta
+            | ParamKind::Yellow
+            | ParamKind::White
+            | ParamKind::Amber
+            | ParamKind::ColorWheel => ParamCategory::Color,
+            ParamKind::Gobo
+            | ParamKind::Zoom
+            | ParamKind::Strobe
+            | ParamKind::Iris
+            | ParamKind::Edge
+            | ParamKind::Prism
+            | ParamKind::Control => ParamCategory::Beam,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ParamKind::Intensity => "Intens",
+            ParamKind::Pan => "Pan",
+            ParamKind::Tilt => "Tilt",
+            ParamKind::Red => "Red",
+            ParamKind::Green => "Green",
+            ParamKind::Blue => "Blue",
+            ParamKind::Cyan => "Cyan",
+            ParamKind::Magenta => "Magenta",
+            ParamKind::Yellow => "Yellow",
+            ParamKind::White => "White",
+            ParamKind::Amber => "Amber",
+            ParamKind::ColorWheel => "Color Wheel",
+            ParamKind::Gobo => "Gobo",
+            ParamKind::Zoom => "Zoom",
+            ParamKind::Strobe => "Strobe",
+            ParamKind::Iris => "Iris",
+            ParamKind::Edge => "Edge",
+            ParamKind::Prism => "Prism",
+            ParamKind::Control => "Control",
+        }
+    }
+
+    /// Retrouve un paramètre depuis une touche du type `{Pan}` ou `[Zoom]`.
+    pub fn from_key(key: &KeyToken) -> Option<Self> {
+        PARAM_KINDS.iter().copied().find(|p| key.is(p.label()) || (*p == ParamKind::Intensity && key.is("Intensity")))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ParameterDef {
+    pub kind: ParamKind,
+    /// Valeur de « home » (défaut) en pourcentage.
+    pub home: f32,
+    /// Paramètre 16 bits (coarse/fine) : occupe deux adresses DMX.
+    pub fine: bool,
+}
+
+impl ParameterDef {
+    pub fn new(kind: ParamKind, home: f32, fine: bool) -> Self {
+        ParameterDef { kind, home, fine }
+    }
+
+    pub fn slots(&self) -> u32 {
+        if self.fine { 2 } else { 1 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FixtureProfile {
+    pub id: String,
+    pub name: String,
+    pub parameters: Vec<ParameterDef>,
+}
+
+impl FixtureProfile {
+    /// Nombre d'adresses DMX occupées par la fixture.
+    pub fn footprint(&self) -> u32 {
+        self.parameters.iter().map(ParameterDef::slots).sum()
+    }
+
+    pub fn parameter(&self, kind: ParamKind) -> Option<&ParameterDef> {
+        self.parameters.iter().find(|p| p.kind == kind)
+    }
+
+    pub fn has_category(&self, category: ParamCategory) -> bool {
+        self.parameters.iter().any(|p| p.kind.category() == category)
+    }
+}
+
+pub fn builtin_profiles() -> Vec<FixtureProfile> {
+    use ParamKind::*;
+    vec![
+        FixtureProfile {
+            id: "dimmer".to_string(),
+            name: "Gradateur".to_string(),
+            parameters: vec![ParameterDef::new(Intensity, 0.0, false)],
+        },
+        FixtureProfile {
+            id: "led-rgb".to_string(),
+            name: "Par LED RGB".to_string(),
+            parameters: vec![
+                ParameterDef::new(Intensity, 0.0, false),
+                ParameterDef::new(Red, 100.0, false),
+                ParameterDef::new(Green, 100.0, false),
+                ParameterDef::new(Blue, 100.0, false),
+            ],
+        },
+        FixtureProfile {
+            id: "spot-cmy".to_string(),
+            name: "Spot asservi CMY".to_string(),
+            parameters: vec![
+                ParameterDef::new(Intensity, 0.0, false),
+                ParameterDef::new(Pan, 50.0, true),
+                ParameterDef::new(Tilt, 50.0, true),
+                ParameterDef::new(Cyan, 0.0, false),
+                ParameterDef::new(Magenta, 0.0, false),
+                ParameterDef::new(Yellow, 0.0, false),
+                ParameterDef::new(Gobo, 0.0, false),
+                ParameterDef::new(Zoom, 50.0, false),
+            ],
+        },
+    ]
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PatchEntry {
+    pub channel: u32,
+    pub profile_id: String,
+    /// Adresse DMX absolue de départ (1 = univers 1 / adresse 1, 513 = 2/1...).
+    pub address: u32,
+}
+
+// --- État du simulateur ---
+#[derive(Clone, Debug, PartialEq)]
+pub struct Simulator {
+    pub profiles: Vec<FixtureProfile>,
+    pub patch: Vec<PatchEntry>,
+    pub levels: Levels,
+    pub selection: Vec<u32>,
+    pub command_line: Vec<KeyToken>,
+    /// Dernière ligne exécutée, `[Enter]` compris : c'est elle qu'on peut renvoyer à une vraie console.
+    pub last_line: Vec<KeyToken>,
+    pub message: Option<String>,
+    pub effects: Vec<Effect>,
+    pub macros: BTreeMap<u32, Macro>,
+    pub learn: Option<LearnSession>,
+    pub cues: CueList,
+    pub current_cue: Option<f32>,
+    pub history: Vec<UndoEntry>,
+    pub partitions: Vec<Partition>,
+    /// Partition choisie par chaque utilisateur (`{Partition}[n][Enter]`).
+    pub user_partitions: BTreeMap<u32, u32>,
+    pub current_user: u32,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        // Patch de démonstration : 10 gradateurs, 4 pars LED, 4 spots asservis.
+        let mut patch = Vec::new();
+        for ch in 1..=10 {
+            patch.push(PatchEntry { channel: ch, profile_id: "dimmer".to_string(), address: ch });
+        }
+        for (i, ch) in (11..=14).enumerate() {
+            patch.push(PatchEntry { channel: ch, profile_id: "led-rgb".to_string(), address: 21 + 4 * i as u32 });
+        }
+        for (i, ch) in (21..=24).enumerate() {
+            patch.push(PatchEntry { channel: ch, profile_id: "spot-cmy".to_string(), address: 101 + 10 * i as u32 });
+        }
+        Simulator::new(builtin_profiles(), patch)
+    }
+}
+
+/// Élément du command line une fois les chiffres regroupés.
+enum Item<'a> {
+    Number(f32),
+    Key(&'a KeyToken),
+}
+
+impl Simulator {
+    pub fn new(profiles: Vec<FixtureProfile>, patch: Vec<PatchEntry>) -> Self {
+        let mut sim = Simulator {
+            profiles,
+            patch,
+            levels: BTreeMap::new(),
+            selection: Vec::new(),
+            command_line: Vec::new(),
+            last_line: Vec::new(),
+            message: None,
+            effects: vec![Effect::new(1)],
+            macros: default_macros(),
+            learn: None,
+            cues: CueList::default(),
+            current_cue: None,
+            history: Vec::new(),
+            partitions: default_partitions(),
+            user_partitions: BTreeMap::new(),
+            current_user: 1,
+        };
+        let channels: Vec<u32> = sim.patch.iter().map(|p| p.channel).collect();
+        for ch in channels {
+            let homes = sim.home_values(ch);
+            sim.levels.insert(ch, homes);
+        }
+        sim
+    }
+
+    pub fn profile_for(&self, channel: u32) -> Option<&FixtureProfile> {
+        let entry = self.patch.iter().find(|p| p.channel == channel)?;
+        self.profiles.iter().find(|p| p.id == entry.profile_id)
+    }
+
+    /// Patche un canal (en remplaçant son patch éventuel) et le ramène à ses valeurs de home.
+    pub fn patch_channel(&mut self, entry: PatchEntry) {
+        let channel = entry.channel;
+        self.patch.retain(|p| p.channel != channel);
+        self.patch.push(entry);
+        self.patch.sort_by_key(|p| p.channel);
+        let homes = self.home_values(channel);
+        self.levels.insert(channel, homes);
+    }
+
+    fn home_values(&self, channel: u32) -> BTreeMap<ParamKind, f32> {
+        self.profile_for(channel)
+            .map(|profile| profile.parameters.iter().map(|p| (p.kind, p.home)).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn value(&self, channel: u32, kind: ParamKind) -> Option<f32> {
+        self.levels.get(&channel)?.get(&kind).copied()
+    }
+
+    /// Vrai si tous les paramètres de la catégorie sont à leur valeur de home.
+    pub fn is_home(&self, channel: u32, category: ParamCategory) -> bool {
+        let Some(profile) = self.profile_for(channel) else { return true };
+        profile
+            .parameters
+            .iter()
+            .filter(|p| p.kind.category() == category && p.kind != ParamKind::Control)
+            .all(|p| self.value(channel, p.kind).unwrap_or(p.home) == p.home)
+    }
+
+    /// Valeurs DMX (adresse absolue, niveau) émises par un canal selon son patch.
+    pub fn dmx_slots(&self, channel: u32) -> Vec<(u32, u8)> {
+        let (Some(entry), Some(profile)) = (self.patch.iter().find(|p| p.channel == channel), self.profile_for(channel)) else {
+            return Vec::new();
+        };
+        let mut slots = Vec::new();
+        let mut address = entry.address;
+        for param in &profile.parameters {
+            // Les canaux Control partagent une même clé : chacun garde sa propre valeur de home.
+            let pct = match param.kind {
+                ParamKind::Control => param.home,
+                kind => self.value(channel, kind).unwrap_or(param.home),
+            };
+            if param.fine {
+                let raw = (pct / 100.0 * 65535.0).round() as u16;
+                slots.push((address, (raw >> 8) as u8));
+                slots.push((address + 1, (raw & 0xff) as u8));
+            } else {
+                slots.push((address, (pct / 100.0 * 255.0).round() as u8));
+            }
+            address += param.slots();
+        }
+        slots
+    }
+
+    /// Appui sur une touche : `[Enter]` exécute le command line, `[Clear]` efface la dernière touche.
+    pub fn press(&mut self, key: KeyToken) {
+        if key.is("Learn") {
+            self.toggle_learn();
+            return;
+        }
+        if let Some(session) = self.learn.as_mut() {
+            if session.target.is_none() {
+                // Saisie du numéro de macro : [Learn] [1] [Enter]
+                if key.is_number() {
+                    session.number.push_str(&key.label);
+                } else if key.is("Enter") {
+                    match session.number.parse::<u32>() {
+                        Ok(n) => {
+                            session.target = Some(n);
+                            self.message = Some(format!("Learn : enregistrement de la Macro {}", n));
+                        }
+                        Err(_) => {
+                            self.learn = None;
+                            self.message = Some("Erreur : numéro de macro invalide".to_string());
+                        }
+                    }
+                }
+                return;
+            }
+            session.keys.push(key.clone());
+        }
+        if key.is("Enter") {
+            let line = std::mem::take(&mut self.command_line);
+            self.last_line = line.iter().cloned().chain(std::iter::once(key)).collect();
+            let before = self.snapshot();
+            let result = self.execute(&line);
+            if let (Ok(_), Some(kind)) = (&result, undo_kind(&line)) {
+                if self.snapshot() != before {
+                    push_undo(&mut self.history, kind, to_notation(&line), before);
+                }
+            }
+            self.message = Some(match result {
+                Ok(msg) => msg,
+                Err(err) => format!("Erreur : {}", err),
+            });
+        } else if key.is("Clear") {
+            self.command_line.pop();
+        } else {
+            self.command_line.push(key);
+        }
+    }
+
+    /// `[Learn]` démarre l'enregistrement ; un second appui range les touches capturées dans la macro.
+    fn toggle_learn(&mut self) {
+        match self.learn.take() {
+            None => {
+                self.learn = Some(LearnSession::default());
+                self.message = Some("Learn : tapez le numéro de macro puis [Enter]".to_string());
+            }
+            Some(LearnSession { target: Some(n), keys, .. }) => {
+                let text = to_notation(&keys);
+                let label = self.macros.get(&n).map(|m| m.label.clone()).unwrap_or_default();
+                self.macros.insert(n, Macro { label, text });
+                self.message = Some(format!("Macro {} enregistrée ({} touche(s))", n, keys.len()));
+            }
+            Some(_) => self.message = Some("Learn annulé".to_string()),
+        }
+    }
+
+    /// Tape une séquence en notation du guide, touche par touche.
+    pub fn type_notation(&mut self, notation: &str) {
+        for key in tokenize(notation) {
+            self.press(key);
+        }
+    }
+
+    fn snapshot(&self) -> ShowSnapshot {
+        ShowSnapshot {
+            levels: self.levels.clone(),
+            selection: self.selection.clone(),
+            cues: self.cues.clone(),
+            current_cue: self.current_cue,
+            user_partitions: self.user_partitions.clone(),
+        }
+    }
+
+    /// Annule le pas `index` de l'historique et tous les suivants.
+    pub fn undo_to(&mut self, index: usize) -> Option<String> {
+        if index >= self.history.len() {
+            return None;
+        }
+        let undone: Vec<UndoEntry> = self.history.drain(index..).collect();
+        let first = undone.first()?;
+        let label = format!("Undo : {} ({} commande(s))", first.kind.label(), undone.iter().map(|e| e.commands.len()).sum::<usize>());
+        let before = first.before.clone();
+        self.levels = before.levels;
+        self.selection = before.selection;
+        self.cues = before.cues;
+        self.current_cue = before.current_cue;
+        self.user_partitions = before.user_partitions;
+        self.message = Some(label.clone());
+        Some(label)
+    }
+
+    pub fn active_partition(&self) -> Option<&Partition> {
+        let number = self.user_partitions.get(&self.current_user)?;
+        self.partitions.iter().find(|p| p.number == *number)
+    }
+
+    /// Vrai si l'utilisateur courant peut contrôler le canal.
+    pub fn owns(&self, channel: u32) -> bool {
+        self.active_partition().is_none_or(|p| p.owns(channel))
+    }
+
+    /// Filtre de propriété détaché de `self`, pour les opérations qui modifient le simulateur.
+    fn ownership(&self) -> impl Fn(u32) -> bool {
+        let partition = self.active_partition().cloned();
+        move |ch| partition.as_ref().is_none_or(|p| p.owns(ch))
+    }
+
+    /// Valeurs de home de tous les canaux patchés : l'état d'avant la première cue.
+    pub fn home_levels(&self) -> Levels {
+        self.patch.iter().map(|p| (p.channel, self.home_values(p.channel))).collect()
+    }
+
+    fn execute(&mut self, line: &[KeyToken]) -> Result<String, String> {
+        let items = group_digits(line)?;
+        let mut pos = 0;
+
+        if let Some(Item::Key(k)) = items.first() {
+            if CUE_KEYS.iter().any(|c| k.is(c)) {
+                return self.execute_cue_command(&items);
+            }
+            if k.is("Partition") {
+                return self.select_partition(&items[1..]);
+            }
+        }
+        let mut filtered = 0;
+
+        // 1. Sélection de canaux : n, n [Thru] m, + n, - n
+        if matches!(items.first(), Some(Item::Number(_))) {
+            let patched: BTreeSet<u32> = self.patch.iter().map(|p| p.channel).collect();
+            // Ordre de saisie dans `selection`, appartenance dans `chosen`
+            let mut selection: Vec<u32> = Vec::new();
+            let mut chosen: HashSet<u32> = HashSet::new();
+            let mut op_add = true;
+            while pos < items.len() {
+                match &items[pos] {
+                    Item::Number(n) => {
+                        let first = *n as u32;
+                        let mut range = vec![first];
+                        if let (Some(Item::Key(k)), Some(Item::Number(last))) = (items.get(pos + 1), items.get(pos + 2)) {
+                            if k.is("Thru") {
+                                // Seuls les canaux patchés de la plage comptent : `1 Thru 99999999` reste bon marché.
+                                let last = *last as u32;
+                                range = patched.range(first.min(last)..=first.max(last)).copied().collect();
+                                if first > last {
+                                    range.reverse();
+                                }
+                                pos += 2;
+                            }
+                        }
+                        for ch in range {
+                            if !op_add {
+                                chosen.remove(&ch);
+                            } else if chosen.insert(ch) {
+                                selection.push(ch);
+                            }
+                        }
+                        pos += 1;
+                    }
+                    Item::Key(k) if k.is("+") => {
+                        op_add = true;
+                        pos += 1;
+                    }
+                    Item::Key(k) if k.is("-") => {
+                        op_add = false;
+                        pos += 1;
+                    }
+                    Item::Key(_) => break,
+                }
+            }
+            // Un canal retiré puis rajouté figure deux fois : on garde la première place.
+            let mut seen = HashSet::new();
+            selection.retain(|ch| chosen.contains(ch) && patched.contains(ch) && seen.insert(*ch));
+            if selection.is_empty() {
+                return Err("aucun canal patché dans la sélection".to_string());
+            }
+            // Comme sur la console, les canaux hors de la partition sont retirés de la sélection.
+            let requested = selection.len();
+            selection.retain(|ch| self.owns(*ch));
+            filtered = requested - selection.len();
+            if selection.is_empty() {
+                return Err("tous les canaux demandés sont hors de votre partition".to_string());
+            }
+            self.selection = selection;
+        }
+
+        // 2. Paramètre ciblé (par défaut l'intensité)
+        let mut param = ParamKind::Intensity;
+        if let Some(Item::Key(k)) = items.get(pos) {
+            if let Some(kind) = ParamKind::from_key(k) {
+                param = kind;
+                pos += 1;
+            }
+        }
+
+        // 3. Action
+        let rest = &items[pos..];
+        let result = match rest {
+            [] => Ok(format!("{} canal(aux) sélectionné(s)", self.selection.len())),
+            [Item::Key(k), Item::Number(v)] if k.is("At") => self.set_selected(param, *v),
+            [Item::Key(k)] if k.is("Full") => self.set_selected(param, 100.0),
+            [Item::Key(k)] if k.is("Out") => self.set_selected(param, 0.0),
+            [Item::Key(a), Item::Key(k)] if a.is("At") && k.is("Full") => self.set_selected(param, 100.0),
+            [Item::Key(a), Item::Key(k)] if a.is("At") && k.is("Out") => self.set_selected(param, 0.0),
+            [Item::Key(k)] if k.is("Home") => self.home_selected(),
+            [Item::Key(k), ..] => Err(format!("touche non gérée par le simulateur : {}", k.to_notation())),
+            [Item::Number(_), ..] => Err("valeur inattendue".to_string()),
+        };
+        match result {
+            Ok(msg) if filtered > 0 => Ok(format!("{} — {} canal(aux) hors partition ignoré(s)", msg, filtered)),
+            other => other,
+        }
+    }
+
+    /// `{Partition}[n][Enter]` : change la partition de l'utilisateur courant.
+    fn select_partition(&mut self, rest: &[Item]) -> Result<String, String> {
+        let [Item::Number(n)] = rest else { return Err("syntaxe attendue : {Partition}[n][Enter]".to_string()) };
+        let number = *n as u32;
+        let partition = self.partitions.iter().find(|p| p.number == number).ok_or(format!("la partition {} n'existe pas", number))?;
+        let label = partition.label.clone();
+        self.user_partitions.insert(self.current_user, number);
+        let before = self.selection.len();
+        let selection: Vec<u32> = self.selection.iter().copied().filter(|ch| self.owns(*ch)).collect();
+        self.selection = selection;
+        let dropped = before - self.selection.len();
+        Ok(format!("Utilisateur {} : partition {} ({}), {} canal(aux) retiré(s) de la sélection", self.current_user, number, label, dropped))
+    }
+
+    /// `[Record]`, `[Update]`, `[Delete]`, `[Go To Cue]` et `[Undo]`.
+    fn execute_cue_command(&mut self, items: &[Item]) -> Result<String, String> {
+        let Some(Item::Key(command)) = items.first() else { return Err("commande vide".to_string()) };
+        let mut rest = &items[1..];
+        if let [Item::Key(k), tail @ ..] = rest {
+            if k.is("Cue") {
+                rest = tail;
+            }
+        }
+        let base = self.home_levels();
+        if command.is("Record") {
+            let cue_only = matches!(rest.last(), Some(Item::Key(k)) if k.is("Q Only"));
+            if cue_only {
+                rest = &rest[..rest.len() - 1];
+            }
+            let number = match rest {
+                [] => self.cues.next_number(),
+                [Item::Number(n)] => *n,
+                _ => return Err("syntaxe attendue : [Record][Cue][n][Enter]".to_string()),
+            };
+            let stored = self.cues.get(number).map(|c| c.moves.clone()).unwrap_or_default();
+            let replaced = self.cues.record(number, &self.levels, &base, self.ownership());
+            if cue_only {
+                let recorded = self.cues.get(number).map(|c| c.moves.clone()).unwrap_or_default();
+                self.cues.stop_tracking(number, &diff(&recorded, &stored), &base);
+            }
+            self.current_cue = Some(number);
+            let moves = self.cues.get(number).map(|c| c.moves.len()).unwrap_or(0);
+            let verb = if replaced { "remplacée" } else { "enregistrée" };
+            let scope = if cue_only { ", Q Only" } else { "" };
+            Ok(format!("Cue {} {} ({} canal(aux) en mouvement{})", format_cue_number(number), verb, moves, scope))
+        } else if command.is("Update") {
+            let number = self.current_cue.ok_or("aucune cue active à mettre à jour")?;
+            self.cues.record(number, &self.levels, &base, self.ownership());
+            Ok(format!("Cue {} mise à jour", format_cue_number(number)))
+        } else if command.is("Delete") {
+            let [Item::Number(n)] = rest else { return Err("syntaxe attendue : [Delete][Cue][n][Enter]".to_string()) };
+            if !self.cues.delete(*n) {
+                return Err(format!("la cue {} n'existe pas", format_cue_number(*n)));
+            }
+            if self.current_cue == Some(*n) {
+                self.current_cue = None;
+            }
+            Ok(format!("Cue {} supprimée", format_cue_number(*n)))
+        } else if command.is("Go To Cue") {
+            match rest {
+                [Item::Key(k)] if k.is("Out") => {
+                    self.levels = base;
+                    self.current_cue = None;
+                    Ok("Go To Cue Out : retour à l'état de départ".to_string())
+                }
+                [Item::Number(n)] => {
+                    if self.cues.get(*n).is_none() {
+                        return Err(format!("la cue {} n'existe pas", format_cue_number(*n)));
+                    }
+                    self.levels = self.cues.state_at(&base, *n, true);
+                    self.current_cue = Some(*n);
+                    Ok(format!("Cue {} chargée", format_cue_number(*n)))
+                }
+                _ => Err("syntaxe attendue : [Go To Cue][n][Enter]".to_string()),
+            }
+        } else {
+            let last = self.history.len().checked_sub(1).ok_or("rien à annuler")?;
+            self.undo_to(last).ok_or_else(|| "rien à annuler".to_string())
+        }
+    }
+
+    fn set_selected(&mut self, param: ParamKind, value: f32) -> Result<String, String> {
+        if self.selection.is_empty() {
+            return Err("aucun canal sélectionné".to_string());
+        }
+        let value = value.clamp(0.0, 100.0);
+        let mut applied = 0;
+        for ch in self.selection.clone() {
+            if self.profile_for(ch).and_then(|p| p.parameter(param)).is_some() {
+                self.levels.entry(ch).or_default().insert(param, value);
+                applied += 1;
+            }
+        }
+        if applied == 0 {
+            return Err(format!("aucun canal sélectionné ne possède le paramètre {}", param.label()));
+        }
+        Ok(format!("{} à {}% sur {} canal(aux)", param.label(), value, applied))
+    }
+
+    /// `[Home]` : ramène tous les paramètres non-intensité à leur valeur par défaut.
+    fn home_selected(&mut self) -> Result<String, String> {
+        if self.selection.is_empty() {
+            return Err("aucun canal sélectionné".to_string());
+        }
+        for ch in self.selection.clone() {
+            let homes = self.home_values(ch);
+            let levels = self.levels.entry(ch).or_default();
+            for (kind, home) in homes {
+                if kind != ParamKind::Intensity {
+                    levels.insert(kind, home);
+                }
+            }
+        }
+        Ok(format!("Home sur {} canal(aux) (intensité inchangée)", self.selection.len()))
+    }
+}
+
+/// Touches qui ouvrent une commande de cue plutôt qu'une sélection de canaux.
+const CUE_KEYS: [&str; 5] = ["Record", "Update", "Delete", "Go To Cue", "Undo"];
+
+/// Type de pas d'undo produit par un command line ; `None` pour `[Undo]` lui-même.
+fn undo_kind(line: &[KeyToken]) -> Option<UndoKind> {
+    let first = line.first()?;
+    if first.is("Undo") {
+        None
+    } else if first.is("Record") {
+        Some(UndoKind::Record)
+    } else if first.is("Update") {
+        Some(UndoKind::Update)
+    } else if first.is("Delete") {
+        Some(UndoKind::Delete)
+    } else if first.is("Go To Cue") {
+        Some(UndoKind::Playback)
+    } else {
+        Some(UndoKind::Manual)
+    }
+}
+
+/// Regroupe les touches numériques consécutives (`[1][0]` → 10).
+fn group_digits(line: &[KeyToken]) -> Result<Vec<Item<'_>>, String> {
+    let mut items = Vec::new();
+    let mut digits = String::new();
+    for key in line {
+        if key.is_number() {
+            digits.push_str(&key.label);
+            continue;
+        }
+        if !digits.is_empty() {
+            items.push(Item::Number(parse_number(&digits)?));
+            digits.clear();
+        }
+        items.push(Item::Key(key));
+    }
+    if !digits.is_empty() {
+        items.push(Item::Number(parse_number(&digits)?));
+    }
+    Ok(items)
+}
+
+fn parse_number(digits: &str) -> Result<f32, String> {
+    digits.parse::<f32>().map_err(|_| format!("nombre invalide : {}", digits))
+}
+
+// --- Composant Vue Simulateur ---
+#[derive(Clone, Copy, PartialEq)]
+enum SimTab {
+    Channels,
+    Effects,
+    Macros,
+    Undo,
+    Partitions,
+    Facepanel,
+    Timecode,
+    Fixtures,
+}
+
+const SIM_TABS: [(SimTab, &str); 8] = [
+    (SimTab::Channels, "Canaux"),
+    (SimTab::Effects, "🌀 Effets"),
+    (SimTab::Macros, "⚙️ Macros"),
+    (SimTab::Undo, "↶ Undo"),
+    (SimTab::Partitions, "🔒 Partitions"),
+    (SimTab::Facepanel, "🎹 Pupitre"),
+    (SimTab::Timecode, "⏱️ Time code"),
+    (SimTab::Fixtures, "💡 Fixtures"),
+];
+
+const CUE_KEYPAD: [&str; 7] = ["Record", "Update", "Delete", "Cue", "Go To Cue", "Q Only", "Undo"];
+const KEYPAD: [&str; 16] = ["7", "8", "9", "Thru", "4", "5", "6", "At", "1", "2", "3", "Full", "0", "+", "-", "Out"];
+
+#[derive(Properties, PartialEq)]
+pub struct SimulatorViewProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(SimulatorView)]
+pub fn simulator_view(props: &SimulatorViewProps) -> Html {
+    let input_ref = use_node_ref();
+    let tab = use_state(|| SimTab::Channels);
+
+    let press = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        move |key: KeyToken| {
+            let simulator = simulator.clone();
+            let on_change = on_change.clone();
+            Callback::from(move |_: MouseEvent| {
+                let mut sim = simulator.clone();
+                sim.press(key.clone());
+                on_change.emit(sim);
+            })
+        }
+    };
+
+    let on_submit = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        let input_ref = input_ref.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let mut sim = simulator.clone();
+                sim.type_notation(&input.value());
+                input.set_value("");
+                on_change.emit(sim);
+            }
+        })
+    };
+
+    // Saisie au clavier, avec les raccourcis Eos offline, quand la ligne de commande a le focus.
+    let on_keydown = {
+        let simulator = props.simulator.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if let Some(key) = key_for_event(&e) {
+                e.prevent_default();
+                let mut sim = simulator.clone();
+                sim.press(key);
+                on_change.emit(sim);
+            }
+        })
+    };
+
+    let sim = &props.simulator;
+
+    html! {
+        <div id="simulator-view" class="view active">
+            <h1>{ "🎛️ Simulateur" }</h1>
+            <div class="sim-tabs">
+                { for SIM_TABS.iter().map(|(t, label)| {
+                    let tab = tab.clone();
+                    let t = *t;
+                    html! {
+                        <button class={classes!("back-btn", (*tab == t).then_some("active"))}
+                                onclick={Callback::from(move |_| tab.set(t))}>{ *label }</button>
+                    }
+                })}
+            </div>
+            <div class="sim-command-line" tabindex="0" onkeydown={on_keydown}
+                 title="Cliquez ici puis tapez au clavier (raccourcis Eos offline)">
+                <span class="sim-prompt">{ "LIVE : " }</span>
+                { render_keys(&sim.command_line) }
+            </div>
+            if let Some(msg) = &sim.message {
+                <p class="sim-message">{ msg }</p>
+            }
+            <ConsoleEcho />
+            <form class="sim-input" onsubmit={on_submit}>
+                <input ref={input_ref} type="text" placeholder="[1][Thru][10][At][50][Enter]" />
+                <button type="submit" class="back-btn">{ "Exécuter" }</button>
+                <SendToConsole keys={sim.last_line.clone()} />
+            </form>
+            if *tab == SimTab::Effects {
+                <EffectsPanel simulator={sim.clone()} on_change={props.on_change.clone()} />
+            } else if *tab == SimTab::Macros {
+                <MacrosPanel simulator={sim.clone()} on_change={props.on_change.clone()} />
+            } else if *tab == SimTab::Undo {
+                <UndoPanel simulator={sim.clone()} on_change={props.on_change.clone()} />
+            } else if *tab == SimTab::Partitions {
+                <PartitionsPanel simulator={sim.clone()} on_change={props.on_change.clone()} />
+            } else if *tab == SimTab::Timecode {
+                <EventListPanel simulator={sim.clone()} on_change={props.on_change.clone()} />
+            } else if *tab == SimTab::Fixtures {
+                <FixtureLibraryPanel simulator={sim.clone()} on_change={props.on_change.clone()} />
+            } else if *tab == SimTab::Facepanel {
+                <Facepanel highlight={sim.command_line.clone()} on_key={{
+                    let simulator = props.simulator.clone();
+                    let on_change = props.on_change.clone();
+                    Callback::from(move |key: KeyToken| {
+                        let mut sim = simulator.clone();
+                        sim.press(key);
+                        on_change.emit(sim);
+                    })
+                }} />
+            } else {
+                <div class="sim-keypad">
+                    { for KEYPAD.iter().map(|label| html! {
+                        <button class="key key-hard" onclick={press(KeyToken::hard(label))}>{ *label }</button>
+                    })}
+                    <button class="key key-hard" onclick={press(KeyToken::hard("Home"))}>{ "Home" }</button>
+                    <button class="key key-hard" onclick={press(KeyToken::hard("Clear"))}>{ "Clear" }</button>
+                    <button class="key key-hard" onclick={press(KeyToken::hard("Learn"))}>{ "Learn" }</button>
+                    <button class="key key-hard sim-enter" onclick={press(KeyToken::hard("Enter"))}>{ "Enter" }</button>
+                </div>
+                <div class="sim-param-keys">
+                    { for CUE_KEYPAD.iter().map(|label| html! {
+                        <button class="key key-hard" onclick={press(KeyToken::hard(label))}>{ *label }</button>
+                    })}
+                </div>
+                <div class="sim-param-keys">
+                    { for PARAM_KINDS.iter().skip(1).map(|p| html! {
+                        <button class="key key-soft" onclick={press(KeyToken::soft(p.label()))}>{ p.label() }</button>
+                    })}
+                </div>
+                <div class="sim-channel-grid">
+                    { for sim.patch.iter().map(|entry| {
+                        let ch = entry.channel;
+                        let intensity = sim.value(ch, ParamKind::Intensity).unwrap_or(0.0);
+                        let selected = sim.selection.contains(&ch);
+                        let footprint = sim.profile_for(ch).map(FixtureProfile::footprint).unwrap_or(1);
+                        html! {
+                            <div class={classes!("sim-channel", selected.then_some("selected"), (!sim.owns(ch)).then_some("out-of-partition"))}
+                                 title={format!("Adresse {} - {}", entry.address, entry.address + footprint - 1)}>
+                                <div class="sim-channel-number">{ ch }</div>
+                                <div class="sim-channel-level">{ format!("{:.0}", intensity) }</div>
+                                <div class="sim-categories">
+                                    { for CATEGORIES.iter().skip(1).map(|cat| {
+                                        let present = sim.profile_for(ch).map(|p| p.has_category(*cat)).unwrap_or(false);
+                                        let moved = present && !sim.is_home(ch, *cat);
+                                        html! {
+                                            <span class={classes!("sim-cat", present.then_some("present"), moved.then_some("moved"))}>
+                                                { cat.letter() }
+                                            </span>
+                                        }
+                                    })}
+                                </div>
+                            </div>
+                        }
+                    })}
+                </div>
+                if !sim.cues.cues.is_empty() {
+                    <div class="sim-cues">
+                        <span class="sim-prompt">{ "Cues : " }</span>
+                        { for sim.cues.cues.iter().map(|cue| {
+                            let active = sim.current_cue == Some(cue.number);
+                            html! {
+                                <span class={classes!("sim-cue", active.then_some("active"))}
+                                      title={format!("{} canal(aux) en mouvement", cue.moves.len())}>
+                                    { format_cue_number(cue.number) }
+                                </span>
+                            }
+                        })}
+                    </div>
+                }
+                if !sim.selection.is_empty() {
+                    <table class="sim-params">
+                        <tr>
+                            <th>{ "Canal" }</th>
+                            { for CATEGORIES.iter().map(|cat| html! { <th>{ cat.label() }</th> }) }
+                            <th>{ "DMX" }</th>
+                        </tr>
+                        { for sim.selection.iter().filter_map(|ch| sim.profile_for(*ch).map(|p| (*ch, p))).map(|(ch, profile)| html! {
+                            <tr>
+                                <td>{ format!("{} ({})", ch, profile.name) }</td>
+                                { for CATEGORIES.iter().map(|cat| html! {
+                                    <td>
+                                        { for profile.parameters.iter().filter(|p| p.kind.category() == *cat).map(|p| {
+                                            let value = sim.value(ch, p.kind).unwrap_or(p.home);
+                                            html! {
+                                                <div class={classes!((value != p.home).then_some("moved"))}>
+                                                    { format!("{} {:.0}", p.kind.label(), value) }
+                                                </div>
+                                            }
+                                        })}
+                                    </td>
+                                })}
+                                <td class="sim-dmx">
+                                    { sim.dmx_slots(ch).iter().map(|(addr, v)| format!("{}:{}", addr, v)).collect::<Vec<_>>().join(" ") }
+                                </td>
+                            </tr>
+                        })}
+                    </table>
+                }
+            }
+        </div>
+    }
+}
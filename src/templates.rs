@@ -0,0 +1,294 @@
+use serde::{Deserialize, Serialize};
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::notation::{canonicalize, render_keys, to_notation, tokenize, KeyKind, KeyToken};
+use crate::remote::SendToConsole;
+
+// --- Modèles de commandes ---
+// Une syntaxe modèle contient des paramètres en minuscules, comme les syntaxes du guide
+// (`[Help] + [touche]`) : `[Sub][sub]{Solo}[Enter]`. Chaque paramètre a un type et des bornes.
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaceholderKind {
+    Channel,
+    /// Un canal ou une plage : `5`, `1-10`, `1 thru 10`.
+    Range,
+    Level,
+    Cue,
+    /// En secondes.
+    Time,
+    Sub,
+    Address,
+    Number,
+}
+
+impl PlaceholderKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            PlaceholderKind::Channel => "Canal",
+            PlaceholderKind::Range => "Canaux",
+            PlaceholderKind::Level => "Niveau (%)",
+            PlaceholderKind::Cue => "Cue",
+            PlaceholderKind::Time => "Temps (s)",
+            PlaceholderKind::Sub => "Submaster",
+            PlaceholderKind::Address => "Adresse DMX",
+            PlaceholderKind::Number => "Numéro",
+        }
+    }
+
+    /// Bornes par défaut, inclusives.
+    fn bounds(self) -> (f64, f64) {
+        match self {
+            PlaceholderKind::Channel | PlaceholderKind::Range => (1.0, 99999.0),
+            PlaceholderKind::Level => (0.0, 100.0),
+            PlaceholderKind::Cue => (0.01, 9999.99),
+            PlaceholderKind::Time => (0.0, 3600.0),
+            PlaceholderKind::Sub => (1.0, 999.0),
+            PlaceholderKind::Address => (1.0, 32768.0),
+            PlaceholderKind::Number => (1.0, 9999.0),
+        }
+    }
+
+    /// Les cues et les temps acceptent des décimales.
+    fn decimals(self) -> bool {
+        matches!(self, PlaceholderKind::Cue | PlaceholderKind::Time)
+    }
+
+    fn example(self) -> &'static str {
+        match self {
+            PlaceholderKind::Channel => "1",
+            PlaceholderKind::Range => "1-10",
+            PlaceholderKind::Level => "50",
+            PlaceholderKind::Cue => "5",
+            PlaceholderKind::Time => "2",
+            PlaceholderKind::Sub => "101",
+            PlaceholderKind::Address => "513",
+            PlaceholderKind::Number => "1",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Placeholder {
+    /// Nom tel qu'il apparaît dans la syntaxe : `[sub]`.
+    pub name: String,
+    pub kind: PlaceholderKind,
+    /// Libellé du champ ; celui du type par défaut.
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+    /// Valeur proposée à l'ouverture de l'assistant.
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+impl Placeholder {
+    pub fn label(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.kind.label().to_string())
+    }
+
+    fn initial_value(&self) -> String {
+        self.default.clone().unwrap_or_else(|| self.kind.example().to_string())
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let (min, max) = self.kind.bounds();
+        (self.min.unwrap_or(min), self.max.unwrap_or(max))
+    }
+
+    fn parse_number(&self, text: &str) -> Result<f64, String> {
+        let (min, max) = self.bounds();
+        let value: f64 = text
+            .trim()
+            .replace(',', ".")
+            .parse()
+            .map_err(|_| format!("{} : « {} » n'est pas un nombre", self.label(), text.trim()))?;
+        if !self.kind.decimals() && value.fract() != 0.0 {
+            return Err(format!("{} : nombre entier attendu", self.label()));
+        }
+        if value < min || value > max {
+            return Err(format!("{} : {} hors limites ({} à {})", self.label(), format_value(value), format_value(min), format_value(max)));
+        }
+        Ok(value)
+    }
+
+    /// Touches et texte de la phrase pour une valeur saisie.
+    pub fn fill(&self, text: &str) -> Result<(Vec<KeyToken>, String), String> {
+        if text.trim().is_empty() {
+            return Err(format!("{} : valeur manquante", self.label()));
+        }
+        if self.kind == PlaceholderKind::Range {
+            let lower = text.to_lowercase();
+            let bounds = ["-", "thru", " à ", " a "].iter().find_map(|sep| {
+                lower.split_once(sep).filter(|(a, b)| !a.trim().is_empty() && !b.trim().is_empty())
+            });
+            if let Some((first, last)) = bounds {
+                let (first, last) = (self.parse_number(first)?, self.parse_number(last)?);
+                if first >= last {
+                    return Err(format!("{} : le début de la plage doit précéder la fin", self.label()));
+                }
+                let keys = vec![
+                    KeyToken::hard(&format_value(first)),
+                    KeyToken::hard("Thru"),
+                    KeyToken::hard(&format_value(last)),
+                ];
+                return Ok((keys, format!("{} à {}", format_value(first), format_value(last))));
+            }
+        }
+        let value = format_value(self.parse_number(text)?);
+        Ok((vec![KeyToken::hard(&value)], value))
+    }
+}
+
+fn format_value(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CommandTemplate {
+    pub syntax: String,
+    /// Phrase en clair ; `{nom}` est remplacé par la valeur du paramètre.
+    pub sentence: String,
+    pub params: Vec<Placeholder>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filled {
+    pub notation: String,
+    pub keys: Vec<KeyToken>,
+    pub sentence: String,
+}
+
+impl CommandTemplate {
+    fn param_index(&self, key: &KeyToken) -> Option<usize> {
+        if key.kind != KeyKind::Hard {
+            return None;
+        }
+        self.params.iter().position(|p| p.name == key.label)
+    }
+
+    /// Remplit le modèle ; renvoie toutes les erreurs de saisie d'un coup.
+    pub fn fill(&self, values: &[String]) -> Result<Filled, Vec<String>> {
+        let mut errors = Vec::new();
+        let mut filled = Vec::new();
+        for (i, param) in self.params.iter().enumerate() {
+            match param.fill(values.get(i).map(String::as_str).unwrap_or_default()) {
+                Ok(result) => filled.push(result),
+                Err(e) => errors.push(e),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let mut keys = Vec::new();
+        for key in tokenize(&self.syntax) {
+            match self.param_index(&key) {
+                Some(i) => keys.extend(filled[i].0.iter().cloned()),
+                None => keys.push(key),
+            }
+        }
+        let keys = canonicalize(&keys);
+
+        let mut sentence = self.sentence.clone();
+        for (param, (_, text)) in self.params.iter().zip(&filled) {
+            sentence = sentence.replace(&format!("{{{}}}", param.name), text);
+        }
+        Ok(Filled { notation: to_notation(&keys), keys, sentence })
+    }
+}
+
+// --- Composant Assistant « Remplir » ---
+#[derive(Properties, PartialEq)]
+pub struct TemplateWizardProps {
+    pub template: CommandTemplate,
+    pub on_send_to_simulator: Callback<String>,
+}
+
+#[function_component(TemplateWizard)]
+pub fn template_wizard(props: &TemplateWizardProps) -> Html {
+    let template = &props.template;
+    let values = {
+        let defaults: Vec<String> = template.params.iter().map(Placeholder::initial_value).collect();
+        use_state(move || defaults)
+    };
+    let copied = use_state(|| false);
+
+    let result = template.fill(&values);
+
+    let on_input = |i: usize| {
+        let values = values.clone();
+        let copied = copied.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let mut next = (*values).clone();
+                if let Some(value) = next.get_mut(i) {
+                    *value = input.value();
+                }
+                values.set(next);
+                copied.set(false);
+            }
+        })
+    };
+
+    let actions = match &result {
+        Ok(filled) => {
+            let send = {
+                let on_send = props.on_send_to_simulator.clone();
+                let notation = filled.notation.clone();
+                Callback::from(move |_: MouseEvent| on_send.emit(notation.clone()))
+            };
+            let copy = {
+                let notation = filled.notation.clone();
+                let copied = copied.clone();
+                Callback::from(move |_: MouseEvent| {
+                    if let Some(window) = web_sys::window() {
+                        // La promesse est ignorée : l'écriture réussit dès que la page a le focus.
+                        let _ = window.navigator().clipboard().write_text(&notation);
+                        copied.set(true);
+                    }
+                })
+            };
+            html! {
+                <>
+                    <div class="sim-command-line">{ render_keys(&filled.keys) }</div>
+                    <p class="template-sentence">{ &filled.sentence }</p>
+                    <div class="form-actions">
+                        <button class="back-btn" onclick={send}>{ "🎛️ Envoyer au simulateur" }</button>
+                        <button class="back-btn" onclick={copy}>{ if *copied { "✔ Copié" } else { "📋 Copier" } }</button>
+                        <SendToConsole keys={filled.keys.clone()} />
+                    </div>
+                </>
+            }
+        }
+        Err(errors) => html! {
+            <ul class="template-errors">
+                { for errors.iter().map(|e| html! { <li>{ e }</li> }) }
+            </ul>
+        },
+    };
+
+    html! {
+        <div class="template-wizard">
+            <div class="template-fields">
+                { for template.params.iter().enumerate().map(|(i, p)| {
+                    let (min, max) = p.bounds();
+                    html! {
+                        <label>
+                            { p.label() }
+                            <input type="text" value={values.get(i).cloned().unwrap_or_default()} oninput={on_input(i)}
+                                   placeholder={format!("{} à {}", format_value(min), format_value(max))} />
+                        </label>
+                    }
+                })}
+            </div>
+            { actions }
+        </div>
+    }
+}
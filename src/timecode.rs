@@ -0,0 +1,576 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::osc::parse_bytes;
+
+// --- Time code SMPTE ---
+// Un time code compte des images : HH:MM:SS:FF à cadence nominale (24, 25 ou 30 i/s).
+// En 29,97 drop-frame, les numéros d'image 0 et 1 sont sautés au début de chaque minute
+// sauf toutes les dix minutes, pour rester à l'heure malgré les 30000/1001 images par seconde.
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum TimecodeRate {
+    Fps24,
+    Fps25,
+    Fps2997Drop,
+    Fps30,
+}
+
+/// Dans l'ordre du code de cadence MTC / MSC (bits 5-6 de l'octet des heures).
+pub const TIMECODE_RATES: [(TimecodeRate, &str); 4] = [
+    (TimecodeRate::Fps24, "24 i/s"),
+    (TimecodeRate::Fps25, "25 i/s"),
+    (TimecodeRate::Fps2997Drop, "29,97 i/s drop-frame"),
+    (TimecodeRate::Fps30, "30 i/s"),
+];
+
+/// Images réelles par tranche de dix minutes en drop-frame : 10 × 60 × 30 − 9 × 2.
+const DROP_FRAMES_PER_10_MIN: i64 = 17982;
+/// Images d'une minute qui perd ses deux premiers numéros.
+const DROP_FRAMES_PER_MIN: i64 = 1798;
+
+impl TimecodeRate {
+    pub fn code(self) -> u8 {
+        TIMECODE_RATES.iter().position(|(r, _)| *r == self).unwrap_or(0) as u8
+    }
+
+    pub fn from_code(code: u8) -> Self {
+        TIMECODE_RATES[(code & 3) as usize].0
+    }
+
+    /// Images par seconde du compteur HH:MM:SS:FF.
+    pub fn nominal_fps(self) -> u8 {
+        match self {
+            TimecodeRate::Fps24 => 24,
+            TimecodeRate::Fps25 => 25,
+            TimecodeRate::Fps2997Drop | TimecodeRate::Fps30 => 30,
+        }
+    }
+
+    /// Images par seconde d'horloge.
+    pub fn real_fps(self) -> f64 {
+        match self {
+            TimecodeRate::Fps2997Drop => 30000.0 / 1001.0,
+            rate => rate.nominal_fps() as f64,
+        }
+    }
+
+    pub fn is_drop(self) -> bool {
+        self == TimecodeRate::Fps2997Drop
+    }
+
+    pub fn label(self) -> &'static str {
+        TIMECODE_RATES[self.code() as usize].1
+    }
+
+    /// Nombre d'images en 24 heures, après quoi le compteur repart de zéro.
+    pub fn frames_per_day(self) -> i64 {
+        if self.is_drop() {
+            DROP_FRAMES_PER_10_MIN * 6 * 24
+        } else {
+            self.nominal_fps() as i64 * 86_400
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Timecode {
+    pub rate: TimecodeRate,
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: u8,
+    pub frames: u8,
+}
+
+impl Timecode {
+    /// Vérifie les champs, y compris les numéros sautés en drop-frame.
+    pub fn new(rate: TimecodeRate, hours: u8, minutes: u8, seconds: u8, frames: u8) -> Result<Self, String> {
+        if hours > 23 || minutes > 59 || seconds > 59 {
+            return Err(format!("{:02}:{:02}:{:02} n'est pas une heure valide", hours, minutes, seconds));
+        }
+        if frames >= rate.nominal_fps() {
+            return Err(format!("image {} hors de 0–{} à {}", frames, rate.nominal_fps() - 1, rate.label()));
+        }
+        if rate.is_drop() && seconds == 0 && frames < 2 && !minutes.is_multiple_of(10) {
+            return Err(format!("{:02}:{:02}:00;{:02} n'existe pas en drop-frame : la minute commence à ;02", hours, minutes, frames));
+        }
+        Ok(Timecode { rate, hours, minutes, seconds, frames })
+    }
+
+    /// Lit `HH:MM:SS:FF` ; `;` ou `.` avant les images sont acceptés.
+    pub fn parse(text: &str, rate: TimecodeRate) -> Result<Self, String> {
+        let parts: Vec<&str> = text.trim().split([':', ';', '.']).collect();
+        let [h, m, s, f] = parts.as_slice() else {
+            return Err(format!("« {} » : attendu HH:MM:SS:FF", text.trim()));
+        };
+        let number = |part: &str| part.trim().parse::<u8>().map_err(|_| format!("« {} » n'est pas un nombre", part.trim()));
+        Timecode::new(rate, number(h)?, number(m)?, number(s)?, number(f)?)
+    }
+
+    /// Rang de l'image depuis 00:00:00:00.
+    pub fn to_frames(self) -> i64 {
+        let fps = self.rate.nominal_fps() as i64;
+        let total_minutes = self.hours as i64 * 60 + self.minutes as i64;
+        let nominal = (total_minutes * 60 + self.seconds as i64) * fps + self.frames as i64;
+        if self.rate.is_drop() {
+            nominal - 2 * (total_minutes - total_minutes / 10)
+        } else {
+            nominal
+        }
+    }
+
+    /// Time code d'un rang d'image ; les rangs hors d'une journée bouclent sur 24 heures.
+    pub fn from_frames(frames: i64, rate: TimecodeRate) -> Self {
+        let mut frames = frames.rem_euclid(rate.frames_per_day());
+        if rate.is_drop() {
+            // On rajoute les numéros sautés pour retrouver un compteur à 30 i/s.
+            let tens = frames / DROP_FRAMES_PER_10_MIN;
+            let rest = frames % DROP_FRAMES_PER_10_MIN;
+            frames += 18 * tens + if rest > 1 { 2 * ((rest - 2) / DROP_FRAMES_PER_MIN) } else { 0 };
+        }
+        let fps = rate.nominal_fps() as i64;
+        Timecode {
+            rate,
+            hours: (frames / (fps * 3600)) as u8,
+            minutes: (frames / (fps * 60) % 60) as u8,
+            seconds: (frames / fps % 60) as u8,
+            frames: (frames % fps) as u8,
+        }
+    }
+
+    /// Secondes d'horloge écoulées depuis 00:00:00:00.
+    pub fn to_seconds(self) -> f64 {
+        self.to_frames() as f64 / self.rate.real_fps()
+    }
+
+    /// Image la plus proche d'un instant en secondes.
+    pub fn from_seconds(seconds: f64, rate: TimecodeRate) -> Self {
+        Timecode::from_frames((seconds * rate.real_fps()).round() as i64, rate)
+    }
+
+    pub fn add_frames(&self, frames: i64) -> Self {
+        Timecode::from_frames(self.to_frames() + frames, self.rate)
+    }
+
+    /// Même instant à une autre cadence (arrondi à l'image).
+    pub fn convert(&self, rate: TimecodeRate) -> Self {
+        Timecode::from_seconds(self.to_seconds(), rate)
+    }
+
+    pub fn text(&self) -> String {
+        let separator = if self.rate.is_drop() { ';' } else { ':' };
+        format!("{:02}:{:02}:{:02}{}{:02}", self.hours, self.minutes, self.seconds, separator, self.frames)
+    }
+}
+
+/// Durée en images, signée, affichée comme un time code précédé de `-` si négative.
+pub fn duration_text(frames: i64, rate: TimecodeRate) -> String {
+    let sign = if frames < 0 { "-" } else { "" };
+    // Une durée se compte comme un rang d'image, sans boucler sur 24 heures tant qu'elle tient dans une journée.
+    format!("{}{}", sign, Timecode::from_frames(frames.abs(), rate).text())
+}
+
+pub fn seconds_text(seconds: f64) -> String {
+    format!("{:.3} s", seconds).replace('.', ",")
+}
+
+// --- MIDI Time Code ---
+// Quart d'image : F1 0nvvvv, huit messages (n = 0 à 7) pour un time code complet transmis sur deux images.
+// Image complète : F0 7F <canal> 01 01 hh mm ss ff F7, la cadence dans les bits 5-6 de hh.
+
+const MTC_QUARTER_FRAME: u8 = 0xF1;
+const MTC_PIECES: [&str; 8] = [
+    "images, poids faible",
+    "images, poids fort",
+    "secondes, poids faible",
+    "secondes, poids fort",
+    "minutes, poids faible",
+    "minutes, poids fort",
+    "heures, poids faible",
+    "heures, poids fort et cadence",
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum MtcEvent {
+    QuarterFrame { piece: u8, value: u8 },
+    /// Huit quarts d'image reçus ; le time code est celui de la première image, l'affichage a deux images de plus.
+    Complete(Timecode),
+    FullFrame(Timecode),
+    Invalid(String),
+}
+
+impl MtcEvent {
+    pub fn describe(&self) -> String {
+        match self {
+            MtcEvent::QuarterFrame { piece, value } => {
+                format!("Quart d'image {} : {} = {:X}", piece, MTC_PIECES[*piece as usize & 7], value)
+            }
+            MtcEvent::Complete(tc) => format!("Time code reconstitué : {} ({}), à l'affichage {}", tc.text(), tc.rate.label(), tc.add_frames(2).text()),
+            MtcEvent::FullFrame(tc) => format!("Image complète (locate) : {} ({})", tc.text(), tc.rate.label()),
+            MtcEvent::Invalid(e) => format!("Erreur : {}", e),
+        }
+    }
+}
+
+fn timecode_from_mtc(hours_byte: u8, minutes: u8, seconds: u8, frames: u8) -> Result<Timecode, String> {
+    Timecode::new(TimecodeRate::from_code(hours_byte >> 5), hours_byte & 0x1F, minutes, seconds, frames)
+}
+
+/// Décode un flux MTC : quarts d'image et images complètes, dans l'ordre d'arrivée.
+pub fn decode_mtc(bytes: &[u8]) -> Vec<MtcEvent> {
+    let mut events = Vec::new();
+    let mut pieces: [Option<u8>; 8] = [None; 8];
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            MTC_QUARTER_FRAME => {
+                let Some(data) = bytes.get(i + 1) else {
+                    events.push(MtcEvent::Invalid("quart d'image tronqué".to_string()));
+                    break;
+                };
+                let (piece, value) = (data >> 4 & 7, data & 0x0F);
+                // Un time code se reconstitue à partir du quart 0 ; la lecture à rebours finit sur le quart 0.
+                if piece == 0 {
+                    pieces = [None; 8];
+                }
+                pieces[piece as usize] = Some(value);
+                events.push(MtcEvent::QuarterFrame { piece, value });
+                if let [Some(f0), Some(f1), Some(s0), Some(s1), Some(m0), Some(m1), Some(h0), Some(h1)] = pieces {
+                    events.push(match timecode_from_mtc(h1 << 4 | h0, m1 << 4 | m0, s1 << 4 | s0, f1 << 4 | f0) {
+                        Ok(tc) => MtcEvent::Complete(tc),
+                        Err(e) => MtcEvent::Invalid(e),
+                    });
+                    pieces = [None; 8];
+                }
+                i += 2;
+            }
+            0xF0 => {
+                let end = bytes[i..].iter().position(|b| *b == 0xF7).map(|p| i + p);
+                let Some(end) = end else {
+                    events.push(MtcEvent::Invalid("SysEx sans F7 de fin".to_string()));
+                    break;
+                };
+                events.push(match &bytes[i + 1..end] {
+                    [0x7F, _, 0x01, 0x01, hh, mm, ss, ff] => match timecode_from_mtc(*hh, *mm, *ss, *ff) {
+                        Ok(tc) => MtcEvent::FullFrame(tc),
+                        Err(e) => MtcEvent::Invalid(e),
+                    },
+                    _ => MtcEvent::Invalid("SysEx qui n'est pas une image complète MTC (F0 7F <canal> 01 01 …)".to_string()),
+                });
+                i = end + 1;
+            }
+            other => {
+                events.push(MtcEvent::Invalid(format!("octet {:02X} inattendu dans un flux MTC", other)));
+                i += 1;
+            }
+        }
+    }
+    events
+}
+
+/// Les huit quarts d'image qui transmettent un time code.
+pub fn mtc_quarter_frames(tc: &Timecode) -> Vec<u8> {
+    let hours = tc.rate.code() << 5 | tc.hours;
+    let values = [tc.frames, tc.seconds, tc.minutes, hours];
+    (0..8u8).flat_map(|piece| {
+        let byte = values[piece as usize / 2];
+        let nibble = if piece.is_multiple_of(2) { byte & 0x0F } else { byte >> 4 };
+        [MTC_QUARTER_FRAME, piece << 4 | nibble]
+    })
+    .collect()
+}
+
+/// Message d'image complète pour tous les appareils (canal 7F).
+pub fn mtc_full_frame(tc: &Timecode) -> Vec<u8> {
+    vec![0xF0, 0x7F, 0x7F, 0x01, 0x01, tc.rate.code() << 5 | tc.hours, tc.minutes, tc.seconds, tc.frames, 0xF7]
+}
+
+// --- LTC ---
+// Mot de 80 bits par image, transmis bit de poids faible en premier : chiffres BCD du time code,
+// bits utilisateur, indicateur drop-frame (bit 10) et mot de synchronisation 0011 1111 1111 1101.
+// Sous forme d'octets dans l'ordre de transmission, le mot de synchro donne FC BF.
+
+const LTC_SYNC: [u8; 2] = [0xFC, 0xBF];
+
+fn ltc_bits(frame: &[u8; 10], start: usize, count: usize) -> u8 {
+    (0..count).fold(0, |value, i| {
+        let bit = start + i;
+        value | (frame[bit / 8] >> (bit % 8) & 1) << i
+    })
+}
+
+/// Décode un mot LTC de 10 octets. La cadence n'y figure pas, hors drop-frame : `rate` sert de repli.
+pub fn decode_ltc(bytes: &[u8], rate: TimecodeRate) -> Result<(Timecode, u32), String> {
+    let frame: &[u8; 10] = bytes.try_into().map_err(|_| format!("un mot LTC fait 10 octets, pas {}", bytes.len()))?;
+    if frame[8..] != LTC_SYNC {
+        return Err(format!("mot de synchro {:02X} {:02X} au lieu de FC BF", frame[8], frame[9]));
+    }
+    let rate = if ltc_bits(frame, 10, 1) == 1 { TimecodeRate::Fps2997Drop } else if rate.is_drop() { TimecodeRate::Fps30 } else { rate };
+    let bcd = |units: usize, tens: usize, tens_bits: usize| ltc_bits(frame, units, 4) + 10 * ltc_bits(frame, tens, tens_bits);
+    let tc = Timecode::new(rate, bcd(48, 56, 2), bcd(32, 40, 3), bcd(16, 24, 3), bcd(0, 8, 2))?;
+    // Bits utilisateur : huit groupes de 4 bits, du groupe 1 (bits 4-7) au groupe 8 (bits 60-63)
+    let user = [4, 12, 20, 28, 36, 44, 52, 60].iter().enumerate().fold(0u32, |acc, (i, start)| acc | (ltc_bits(frame, *start, 4) as u32) << (4 * i));
+    Ok((tc, user))
+}
+
+/// Mot LTC d'un time code, bits utilisateur à zéro.
+pub fn encode_ltc(tc: &Timecode) -> Vec<u8> {
+    let mut frame = [0u8; 10];
+    let mut set = |start: usize, count: usize, value: u8| {
+        for i in 0..count {
+            let bit = start + i;
+            frame[bit / 8] |= (value >> i & 1) << (bit % 8);
+        }
+    };
+    set(0, 4, tc.frames % 10);
+    set(8, 2, tc.frames / 10);
+    set(10, 1, tc.rate.is_drop() as u8);
+    set(16, 4, tc.seconds % 10);
+    set(24, 3, tc.seconds / 10);
+    set(32, 4, tc.minutes % 10);
+    set(40, 3, tc.minutes / 10);
+    set(48, 4, tc.hours % 10);
+    set(56, 2, tc.hours / 10);
+    frame[8..].copy_from_slice(&LTC_SYNC);
+    frame.to_vec()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")
+}
+
+// --- Composant Calculatrice time code ---
+
+#[derive(Clone, Copy, PartialEq)]
+enum InputUnit {
+    Timecode,
+    Frames,
+    Seconds,
+}
+
+const INPUT_UNITS: [(InputUnit, &str); 3] = [
+    (InputUnit::Timecode, "HH:MM:SS:FF"),
+    (InputUnit::Frames, "images"),
+    (InputUnit::Seconds, "secondes"),
+];
+
+fn read_value(text: &str, unit: InputUnit, rate: TimecodeRate) -> Result<Timecode, String> {
+    let text = text.trim();
+    match unit {
+        InputUnit::Timecode => Timecode::parse(text, rate),
+        InputUnit::Frames => text.parse::<i64>().map(|f| Timecode::from_frames(f, rate)).map_err(|_| format!("« {} » n'est pas un nombre d'images", text)),
+        InputUnit::Seconds => text
+            .replace(',', ".")
+            .parse::<f64>()
+            .map(|s| Timecode::from_seconds(s, rate))
+            .map_err(|_| format!("« {} » n'est pas un nombre de secondes", text)),
+    }
+}
+
+fn conversions_html(tc: &Timecode) -> Html {
+    html! {
+        <table class="tc-table">
+            <tr><th>{ "Time code" }</th><td><code>{ tc.text() }</code></td></tr>
+            <tr><th>{ "Rang d'image" }</th><td>{ tc.to_frames() }</td></tr>
+            <tr><th>{ "Temps réel" }</th><td>{ seconds_text(tc.to_seconds()) }</td></tr>
+            { for TIMECODE_RATES.iter().filter(|(r, _)| *r != tc.rate).map(|(r, label)| html! {
+                <tr><th>{ format!("En {}", label) }</th><td><code>{ tc.convert(*r).text() }</code></td></tr>
+            })}
+        </table>
+    }
+}
+
+#[function_component(TimecodePanel)]
+pub fn timecode_panel() -> Html {
+    let rate = use_state(|| TimecodeRate::Fps30);
+    let unit = use_state(|| InputUnit::Timecode);
+    let value = use_state(|| "01:00:00:00".to_string());
+    let other = use_state(|| "00:10:00:00".to_string());
+    let pasted = use_state(String::new);
+
+    let text_input = |state: &UseStateHandle<String>| {
+        let state = state.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                state.set(input.value());
+            }
+        })
+    };
+    let on_rate = {
+        let rate = rate.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                rate.set(TimecodeRate::from_code(select.selected_index().max(0) as u8));
+            }
+        })
+    };
+    let on_unit = {
+        let unit = unit.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                if let Some((u, _)) = INPUT_UNITS.get(select.selected_index().max(0) as usize) {
+                    unit.set(*u);
+                }
+            }
+        })
+    };
+    let on_paste = {
+        let pasted = pasted.clone();
+        Callback::from(move |e: InputEvent| {
+            if let Some(area) = e.target_dyn_into::<HtmlTextAreaElement>() {
+                pasted.set(area.value());
+            }
+        })
+    };
+
+    let current = read_value(&value, *unit, *rate);
+    let offset = Timecode::parse(&other, *rate);
+    let decoded = (!pasted.trim().is_empty()).then(|| parse_bytes(&pasted));
+
+    html! {
+        <div class="timecode-panel">
+            <h2>{ "⏱️ Convertir un time code" }</h2>
+            <div class="sim-input">
+                <select onchange={on_rate}>
+                    { for TIMECODE_RATES.iter().map(|(r, label)| html! { <option selected={*r == *rate}>{ *label }</option> }) }
+                </select>
+                <input type="text" value={(*value).clone()} oninput={text_input(&value)} />
+                <select onchange={on_unit}>
+                    { for INPUT_UNITS.iter().map(|(u, label)| html! { <option selected={*u == *unit}>{ *label }</option> }) }
+                </select>
+            </div>
+            { match &current {
+                Ok(tc) => conversions_html(tc),
+                Err(e) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+            }}
+
+            <h2>{ "➕ Offset et durée" }</h2>
+            <div class="sim-input">
+                <label>{ "Second time code " }<input type="text" value={(*other).clone()} oninput={text_input(&other)} /></label>
+            </div>
+            { match (&current, &offset) {
+                (Ok(a), Ok(b)) => {
+                    let span = b.to_frames() - a.to_frames();
+                    html! {
+                        <table class="tc-table">
+                            <tr><th>{ "Premier + second (offset)" }</th><td><code>{ a.add_frames(b.to_frames()).text() }</code></td></tr>
+                            <tr><th>{ "Premier − second" }</th><td><code>{ a.add_frames(-b.to_frames()).text() }</code></td></tr>
+                            <tr><th>{ "Durée de l'un à l'autre" }</th>
+                                <td><code>{ duration_text(span, *rate) }</code>{ format!(" — {} images, {}", span, seconds_text(span as f64 / rate.real_fps())) }</td></tr>
+                        </table>
+                    }
+                }
+                (_, Err(e)) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+                _ => html! {},
+            }}
+
+            if let Ok(tc) = &current {
+                <h2>{ "📤 Messages correspondants" }</h2>
+                <table class="tc-table">
+                    <tr><th>{ "MTC, 8 quarts d'image" }</th><td><code>{ hex(&mtc_quarter_frames(tc)) }</code></td></tr>
+                    <tr><th>{ "MTC, image complète" }</th><td><code>{ hex(&mtc_full_frame(tc)) }</code></td></tr>
+                    <tr><th>{ "Mot LTC (80 bits)" }</th><td><code>{ hex(&encode_ltc(tc)) }</code></td></tr>
+                </table>
+            }
+
+            <h2>{ "🔎 Décoder du MTC ou un mot LTC" }</h2>
+            <textarea rows="3" value={(*pasted).clone()} oninput={on_paste}
+                      placeholder="F1 00 F1 10 F1 20 … ou F0 7F 7F 01 01 61 00 00 00 F7 ou 10 octets LTC finissant par FC BF" />
+            { match decoded {
+                None => html! {},
+                Some(Err(e)) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+                Some(Ok(bytes)) if bytes.ends_with(&LTC_SYNC) => match decode_ltc(&bytes, *rate) {
+                    Ok((tc, user)) => html! {
+                        <p>{ format!("LTC : {} ({}), bits utilisateur {:08X}", tc.text(), tc.rate.label(), user) }</p>
+                    },
+                    Err(e) => html! { <p class="sim-message">{ format!("Erreur : {}", e) }</p> },
+                },
+                Some(Ok(bytes)) => html! {
+                    <ul class="tc-events">
+                        { for decode_mtc(&bytes).iter().map(|event| html! {
+                            <li class={classes!(matches!(event, MtcEvent::Complete(_) | MtcEvent::FullFrame(_)).then_some("tc-complete"))}>
+                                { event.describe() }
+                            </li>
+                        })}
+                    </ul>
+                },
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn df(text: &str) -> Timecode {
+        Timecode::parse(text, TimecodeRate::Fps2997Drop).unwrap()
+    }
+
+    #[test]
+    fn drop_frame_skips_two_numbers_each_minute() {
+        assert_eq!(df("00:00:59;29").to_frames(), 1799);
+        assert_eq!(df("00:00:59;29").add_frames(1).text(), "00:01:00;02");
+        assert_eq!(Timecode::from_frames(1800, TimecodeRate::Fps2997Drop), df("00:01:00;02"));
+        assert!(Timecode::parse("00:01:00;00", TimecodeRate::Fps2997Drop).is_err());
+        assert!(Timecode::parse("00:01:00;01", TimecodeRate::Fps2997Drop).is_err());
+    }
+
+    #[test]
+    fn drop_frame_keeps_every_tenth_minute() {
+        assert_eq!(df("00:10:00;00").to_frames(), DROP_FRAMES_PER_10_MIN);
+        assert_eq!(df("00:09:59;29").add_frames(1).text(), "00:10:00;00");
+        assert_eq!(df("00:10:00;00").add_frames(1).text(), "00:10:00;01");
+        assert_eq!(df("00:10:59;29").add_frames(1).text(), "00:11:00;02");
+        assert_eq!(df("01:00:00;00").to_frames(), DROP_FRAMES_PER_10_MIN * 6);
+    }
+
+    #[test]
+    fn drop_frame_round_trips() {
+        for frames in 0..2 * DROP_FRAMES_PER_10_MIN + 100 {
+            let tc = Timecode::from_frames(frames, TimecodeRate::Fps2997Drop);
+            assert!(Timecode::new(tc.rate, tc.hours, tc.minutes, tc.seconds, tc.frames).is_ok(), "{}", tc.text());
+            assert_eq!(tc.to_frames(), frames, "{}", tc.text());
+        }
+        // Le compteur boucle sur 24 heures
+        assert_eq!(Timecode::from_frames(TimecodeRate::Fps2997Drop.frames_per_day(), TimecodeRate::Fps2997Drop), df("00:00:00;00"));
+        assert_eq!(Timecode::from_frames(-1, TimecodeRate::Fps2997Drop), df("23:59:59;29"));
+    }
+
+    #[test]
+    fn decodes_eight_quarter_frames() {
+        // 01:02:03:04 à 25 i/s : octet des heures 0x21 (cadence 1 dans les bits 5-6)
+        let bytes = [0xF1, 0x04, 0xF1, 0x10, 0xF1, 0x23, 0xF1, 0x30, 0xF1, 0x42, 0xF1, 0x50, 0xF1, 0x61, 0xF1, 0x72];
+        let expected = Timecode::new(TimecodeRate::Fps25, 1, 2, 3, 4).unwrap();
+        let events = decode_mtc(&bytes);
+        assert_eq!(events.len(), 9);
+        assert_eq!(events[0], MtcEvent::QuarterFrame { piece: 0, value: 4 });
+        assert_eq!(events[8], MtcEvent::Complete(expected));
+        assert_eq!(mtc_quarter_frames(&expected), bytes);
+        // Sans le quart 0, rien n'est reconstitué
+        assert!(!decode_mtc(&bytes[2..]).iter().any(|e| matches!(e, MtcEvent::Complete(_))));
+    }
+
+    #[test]
+    fn decodes_full_frame() {
+        let tc = df("10:20:30;15");
+        assert_eq!(decode_mtc(&mtc_full_frame(&tc)), vec![MtcEvent::FullFrame(tc)]);
+        assert!(matches!(decode_mtc(&[0xF0, 0x7F, 0x7F, 0x01]).as_slice(), [MtcEvent::Invalid(_)]));
+    }
+
+    #[test]
+    fn ltc_round_trips() {
+        let tc = df("10:20:30;15");
+        let word = encode_ltc(&tc);
+        assert_eq!(&word[8..], &LTC_SYNC);
+        // Le bit drop-frame l'emporte sur la cadence de repli
+        assert_eq!(decode_ltc(&word, TimecodeRate::Fps25), Ok((tc, 0)));
+        let tc25 = Timecode::new(TimecodeRate::Fps25, 23, 59, 59, 24).unwrap();
+        assert_eq!(decode_ltc(&encode_ltc(&tc25), TimecodeRate::Fps25), Ok((tc25, 0)));
+        let mut bad = word.clone();
+        bad[9] = 0;
+        assert!(decode_ltc(&bad, TimecodeRate::Fps25).is_err());
+        assert!(decode_ltc(&word[..9], TimecodeRate::Fps25).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use yew::prelude::*;
+
+use crate::artnet::ArtnetPanel;
+use crate::msc::MscPanel;
+use crate::network_plan::NetworkPlanner;
+use crate::osc::OscPanel;
+use crate::patch_plan::PatchPlanner;
+use crate::remote::ConsolePanel;
+use crate::sacn::SacnPanel;
+use crate::shortcuts::ShortcutSheet;
+use crate::timecode::TimecodePanel;
+use crate::InitialData;
+
+// --- Composant Vue Outils ---
+// Outils pratiques autour du pupitre, hors du contenu du guide.
+#[derive(Clone, Copy, PartialEq)]
+enum ToolsTab {
+    Shortcuts,
+    Osc,
+    Console,
+    Msc,
+    Timecode,
+    Sacn,
+    Artnet,
+    Patch,
+    Network,
+}
+
+const TOOLS_TABS: [(ToolsTab, &str); 9] = [
+    (ToolsTab::Shortcuts, "⌨️ Raccourcis clavier"),
+    (ToolsTab::Osc, "📡 OSC"),
+    (ToolsTab::Console, "🖧 Console"),
+    (ToolsTab::Msc, "🎼 MSC"),
+    (ToolsTab::Timecode, "⏱️ Time code"),
+    (ToolsTab::Sacn, "📶 sACN"),
+    (ToolsTab::Artnet, "🌐 Art-Net"),
+    (ToolsTab::Patch, "🧮 Plan de patch"),
+    (ToolsTab::Network, "🗺️ Plan réseau"),
+];
+
+#[derive(Properties, PartialEq)]
+pub struct ToolsViewProps {
+    pub data: InitialData,
+}
+
+#[function_component(ToolsView)]
+pub fn tools_view(props: &ToolsViewProps) -> Html {
+    let tab = use_state(|| ToolsTab::Shortcuts);
+
+    html! {
+        <div id="tools-view" class="view active">
+            <h1 class="no-print">{ "🧰 Outils" }</h1>
+            <div class="sim-tabs no-print">
+                { for TOOLS_TABS.iter().map(|(t, label)| {
+                    let tab = tab.clone();
+                    let t = *t;
+                    html! {
+                        <button class={classes!("back-btn", (*tab == t).then_some("active"))}
+                                onclick={Callback::from(move |_| tab.set(t))}>{ *label }</button>
+                    }
+                })}
+            </div>
+            { match *tab {
+                ToolsTab::Shortcuts => html! { <ShortcutSheet data={props.data.clone()} /> },
+                ToolsTab::Osc => html! { <OscPanel /> },
+                ToolsTab::Console => html! { <ConsolePanel /> },
+                ToolsTab::Msc => html! { <MscPanel /> },
+                ToolsTab::Timecode => html! { <TimecodePanel /> },
+                ToolsTab::Sacn => html! { <SacnPanel /> },
+                ToolsTab::Artnet => html! { <ArtnetPanel /> },
+                ToolsTab::Patch => html! { <PatchPlanner /> },
+                ToolsTab::Network => html! { <NetworkPlanner /> },
+            }}
+        </div>
+    }
+}
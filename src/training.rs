@@ -0,0 +1,86 @@
+use yew::prelude::*;
+
+use crate::challenges::ChallengesPanel;
+use crate::drill::DrillPanel;
+use crate::flashcards::{FlashcardsPanel, StudyProgress};
+use crate::quiz::QuizPanel;
+use crate::InitialData;
+
+// --- Composant Vue Formation ---
+// Regroupe les modes d'entraînement construits à partir du contenu du guide.
+#[derive(Clone, Copy, PartialEq)]
+enum TrainingTab {
+    Flashcards,
+    Drill,
+    Challenges,
+    Quiz,
+}
+
+const TRAINING_TABS: [(TrainingTab, &str); 4] = [
+    (TrainingTab::Flashcards, "🃏 Flashcards"),
+    (TrainingTab::Drill, "⌨️ Exercice de frappe"),
+    (TrainingTab::Challenges, "🏆 Défis"),
+    (TrainingTab::Quiz, "📝 Quiz"),
+];
+
+#[derive(Properties, PartialEq)]
+pub struct TrainingViewProps {
+    pub data: InitialData,
+    pub progress: StudyProgress,
+    pub on_progress: Callback<StudyProgress>,
+    /// Défi réussi, à noter aussi pour le profil de parcours courant.
+    pub on_complete_challenge: Callback<String>,
+    pub on_show_command: Callback<String>,
+}
+
+#[function_component(TrainingView)]
+pub fn training_view(props: &TrainingViewProps) -> Html {
+    let tab = use_state(|| TrainingTab::Flashcards);
+
+    let complete_challenge = {
+        let progress = props.progress.clone();
+        let on_progress = props.on_progress.clone();
+        let on_complete_challenge = props.on_complete_challenge.clone();
+        Callback::from(move |id: String| {
+            on_complete_challenge.emit(id.clone());
+            let mut progress = progress.clone();
+            if progress.challenges.insert(id) {
+                on_progress.emit(progress);
+            }
+        })
+    };
+
+    html! {
+        <div id="training-view" class="view active">
+            <h1>{ "🎓 Formation" }</h1>
+            <div class="sim-tabs">
+                { for TRAINING_TABS.iter().map(|(t, label)| {
+                    let tab = tab.clone();
+                    let t = *t;
+                    html! {
+                        <button class={classes!("back-btn", (*tab == t).then_some("active"))}
+                                onclick={Callback::from(move |_| tab.set(t))}>{ *label }</button>
+                    }
+                })}
+            </div>
+            { match *tab {
+                TrainingTab::Flashcards => html! {
+                    <FlashcardsPanel data={props.data.clone()} progress={props.progress.clone()} on_progress={props.on_progress.clone()} />
+                },
+                TrainingTab::Drill => html! {
+                    <DrillPanel data={props.data.clone()} progress={props.progress.clone()} on_progress={props.on_progress.clone()} />
+                },
+                TrainingTab::Challenges => html! {
+                    <ChallengesPanel
+                        completed={props.progress.challenges.clone()}
+                        on_complete={complete_challenge}
+                        on_show_command={props.on_show_command.clone()}
+                    />
+                },
+                TrainingTab::Quiz => html! {
+                    <QuizPanel data={props.data.clone()} progress={props.progress.clone()} on_progress={props.on_progress.clone()} />
+                },
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,111 @@
+use yew::prelude::*;
+
+use std::collections::BTreeMap;
+
+use crate::cues::{CueList, Levels};
+use crate::simulator::Simulator;
+
+// --- Historique d'Undo du simulateur ---
+// Indépendant de l'édition du guide : seules les exécutions du command line du simulateur y entrent.
+// Comme sur la console, les changements manuels consécutifs forment un seul pas d'undo ;
+// chaque Record, Update, Delete ou Go To Cue est un pas à lui seul.
+
+/// Partie de l'état du simulateur restaurée par un Undo.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShowSnapshot {
+    pub levels: Levels,
+    pub selection: Vec<u32>,
+    pub cues: CueList,
+    pub current_cue: Option<f32>,
+    /// Partition choisie par chaque utilisateur (`{Partition}[n][Enter]`).
+    pub user_partitions: BTreeMap<u32, u32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UndoKind {
+    Manual,
+    Record,
+    Update,
+    Delete,
+    Playback,
+}
+
+impl UndoKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            UndoKind::Manual => "Changements manuels",
+            UndoKind::Record => "Record",
+            UndoKind::Update => "Update",
+            UndoKind::Delete => "Delete",
+            UndoKind::Playback => "Go To Cue",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UndoEntry {
+    pub kind: UndoKind,
+    /// Command lines exécutés dans ce pas, en notation du guide.
+    pub commands: Vec<String>,
+    /// État avant le premier command line du pas.
+    pub before: ShowSnapshot,
+}
+
+/// Nombre de pas conservés, les plus anciens sont oubliés.
+pub const MAX_UNDO: usize = 50;
+
+pub fn push_undo(history: &mut Vec<UndoEntry>, kind: UndoKind, command: String, before: ShowSnapshot) {
+    if kind == UndoKind::Manual {
+        if let Some(last) = history.last_mut().filter(|e| e.kind == UndoKind::Manual) {
+            last.commands.push(command);
+            return;
+        }
+    }
+    history.push(UndoEntry { kind, commands: vec![command], before });
+    if history.len() > MAX_UNDO {
+        history.remove(0);
+    }
+}
+
+// --- Composant Panneau Undo ---
+#[derive(Properties, PartialEq)]
+pub struct UndoPanelProps {
+    pub simulator: Simulator,
+    pub on_change: Callback<Simulator>,
+}
+
+#[function_component(UndoPanel)]
+pub fn undo_panel(props: &UndoPanelProps) -> Html {
+    let sim = &props.simulator;
+    if sim.history.is_empty() {
+        return html! {
+            <p class="sim-message">{ "Aucune commande à annuler. Chaque [Enter] du simulateur ajoute une entrée ici." }</p>
+        };
+    }
+
+    html! {
+        <div class="undo-panel">
+            <p class="sim-message">{ "Annuler une entrée annule aussi toutes celles qui la suivent, comme [Undo] sur la console." }</p>
+            <ol class="undo-list" reversed=true>
+                { for sim.history.iter().enumerate().rev().map(|(i, entry)| {
+                    let simulator = props.simulator.clone();
+                    let on_change = props.on_change.clone();
+                    let undo = Callback::from(move |_: MouseEvent| {
+                        let mut sim = simulator.clone();
+                        sim.undo_to(i);
+                        on_change.emit(sim);
+                    });
+                    html! {
+                        <li class="command-item">
+                            <div class="undo-entry">
+                                <strong>{ entry.kind.label() }</strong>
+                                <button class="back-btn" onclick={undo}>{ "↶ Annuler jusqu'ici" }</button>
+                            </div>
+                            { for entry.commands.iter().map(|c| html! { <div class="undo-command">{ c }</div> }) }
+                        </li>
+                    }
+                })}
+            </ol>
+        </div>
+    }
+}
@@ -0,0 +1,204 @@
+// --- Lecteur XML minimal ---
+// Suffisant pour les descriptions GDTF : éléments, attributs et entités prédéfinies.
+// Le texte entre les balises, les commentaires, les instructions de traitement et le DOCTYPE sont ignorés.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Element>,
+}
+
+impl Element {
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// Premier élément de ce nom, en profondeur d'abord.
+    pub fn find(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find_map(|c| if c.name == name { Some(c) } else { c.find(name) })
+    }
+}
+
+/// Décode les entités prédéfinies et les références numériques (`&#233;`, `&#xE9;`).
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(at) = rest.find('&') {
+        out.push_str(&rest[..at]);
+        rest = &rest[at..];
+        let Some(end) = rest.find(';') else { break };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(|dec| dec.parse::<u32>()))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Profondeur d'imbrication maximale : un GDTF en compte une dizaine, la limite protège la pile.
+const MAX_DEPTH: usize = 64;
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Saute jusqu'après `end`.
+    fn skip_past(&mut self, end: &str) -> Result<(), String> {
+        let at = self.rest().find(end).ok_or_else(|| format!("« {} » attendu avant la fin du document", end))?;
+        self.pos += at + end.len();
+        Ok(())
+    }
+
+    /// Saute le texte, les commentaires et autres balises sans contenu utile jusqu'à la prochaine balise d'élément.
+    fn skip_misc(&mut self) -> Result<(), String> {
+        loop {
+            let at = self.rest().find('<').unwrap_or(self.rest().len());
+            self.pos += at;
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.skip_past("]]>")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!") {
+                self.skip_doctype()?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn skip_doctype(&mut self) -> Result<(), String> {
+        // Le DOCTYPE peut contenir un sous-ensemble interne entre crochets.
+        let mut depth = 0;
+        for (i, c) in self.rest().char_indices() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth -= 1,
+                '>' if depth == 0 => {
+                    self.pos += i + 1;
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        Err("DOCTYPE non terminé".to_string())
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let len = rest.find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '=')).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(format!("nom attendu à la position {}", self.pos));
+        }
+        let name = rest[..len].to_string();
+        self.pos += len;
+        Ok(name)
+    }
+
+    fn element(&mut self, depth: usize) -> Result<Element, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("éléments imbriqués sur plus de {} niveaux", MAX_DEPTH));
+        }
+        self.pos += 1; // '<'
+        let name = self.name()?;
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(Element { name, attributes, children: Vec::new() });
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            if rest.is_empty() {
+                return Err(format!("balise <{}> non terminée", name));
+            }
+            let key = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(format!("« = » attendu après l'attribut {} de <{}>", key, name));
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let quote = self.rest().chars().next().filter(|c| *c == '"' || *c == '\'').ok_or_else(|| format!("valeur entre guillemets attendue pour {}", key))?;
+            self.pos += 1;
+            let len = self.rest().find(quote).ok_or_else(|| format!("valeur de {} non terminée", key))?;
+            attributes.push((key, unescape(&self.rest()[..len])));
+            self.pos += len + 1;
+        }
+        let mut children = Vec::new();
+        loop {
+            self.skip_misc()?;
+            if self.rest().is_empty() {
+                return Err(format!("balise fermante </{}> manquante", name));
+            }
+            if self.rest().starts_with("</") {
+                self.pos += 2;
+                let closing = self.name()?;
+                if closing != name {
+                    return Err(format!("</{}> trouvé alors que </{}> était attendu", closing, name));
+                }
+                self.skip_past(">")?;
+                return Ok(Element { name, attributes, children });
+            }
+            children.push(self.element(depth + 1)?);
+        }
+    }
+}
+
+/// Analyse un document et renvoie son élément racine.
+pub fn parse(text: &str) -> Result<Element, String> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut parser = Parser { text, pos: 0 };
+    parser.skip_misc()?;
+    if parser.rest().is_empty() {
+        return Err("document XML vide".to_string());
+    }
+    parser.element(1)
+}
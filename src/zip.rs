@@ -0,0 +1,357 @@
+// --- Archives ZIP ---
+// Juste ce qu'il faut pour ouvrir un fichier .gdtf : le répertoire central, les entrées « stored »
+// et « deflate » (RFC 1951). Pas de chiffrement, pas de ZIP64, pas d'archives multi-volumes.
+
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+const CENTRAL_FILE_HEADER: u32 = 0x0201_4b50;
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATE: u16 = 8;
+
+/// Taille décompressée maximale d'une entrée : une description GDTF fait quelques centaines de Kio.
+pub const MAX_ENTRY_SIZE: usize = 16 << 20;
+
+fn u16_at(bytes: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(bytes.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(bytes.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// Entrée du répertoire central.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZipEntry {
+    pub name: String,
+    method: u16,
+    compressed_size: usize,
+    size: usize,
+    local_offset: usize,
+}
+
+pub struct ZipArchive<'a> {
+    bytes: &'a [u8],
+    pub entries: Vec<ZipEntry>,
+}
+
+impl<'a> ZipArchive<'a> {
+    pub fn open(bytes: &'a [u8]) -> Result<Self, String> {
+        // Fin du répertoire central : 22 octets, suivis d'un commentaire de 64 Kio au plus.
+        let lowest = bytes.len().saturating_sub(22 + 0xffff);
+        let eocd = (lowest..=bytes.len().saturating_sub(22))
+            .rev()
+            .find(|&at| u32_at(bytes, at) == Some(END_OF_CENTRAL_DIRECTORY))
+            .ok_or("pas une archive ZIP (fin de répertoire central introuvable)")?;
+        let count = u16_at(bytes, eocd + 10).unwrap_or(0) as usize;
+        let mut at = u32_at(bytes, eocd + 16).unwrap_or(u32::MAX) as usize;
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let truncated = || "répertoire central tronqué".to_string();
+            if u32_at(bytes, at) != Some(CENTRAL_FILE_HEADER) {
+                return Err(truncated());
+            }
+            let name_len = u16_at(bytes, at + 28).ok_or_else(truncated)? as usize;
+            let extra_len = u16_at(bytes, at + 30).ok_or_else(truncated)? as usize;
+            let comment_len = u16_at(bytes, at + 32).ok_or_else(truncated)? as usize;
+            let name = bytes.get(at + 46..at + 46 + name_len).ok_or_else(truncated)?;
+            entries.push(ZipEntry {
+                name: String::from_utf8_lossy(name).into_owned(),
+                method: u16_at(bytes, at + 10).ok_or_else(truncated)?,
+                compressed_size: u32_at(bytes, at + 20).ok_or_else(truncated)? as usize,
+                size: u32_at(bytes, at + 24).ok_or_else(truncated)? as usize,
+                local_offset: u32_at(bytes, at + 42).ok_or_else(truncated)? as usize,
+            });
+            at += 46 + name_len + extra_len + comment_len;
+        }
+        Ok(ZipArchive { bytes, entries })
+    }
+
+    /// Contenu décompressé d'une entrée, recherchée sans tenir compte de la casse.
+    pub fn read(&self, name: &str) -> Result<Vec<u8>, String> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("{} absent de l'archive", name))?;
+        let at = entry.local_offset;
+        if u32_at(self.bytes, at) != Some(LOCAL_FILE_HEADER) {
+            return Err(format!("en-tête local de {} invalide", entry.name));
+        }
+        if entry.size > MAX_ENTRY_SIZE {
+            return Err(format!("{} : {} octets décompressés, au-delà de la limite de {} Mio", entry.name, entry.size, MAX_ENTRY_SIZE >> 20));
+        }
+        let start = at + 30 + u16_at(self.bytes, at + 26).unwrap_or(0) as usize + u16_at(self.bytes, at + 28).unwrap_or(0) as usize;
+        let data = start
+            .checked_add(entry.compressed_size)
+            .and_then(|end| self.bytes.get(start..end))
+            .ok_or_else(|| format!("données de {} tronquées", entry.name))?;
+        let content = match entry.method {
+            METHOD_STORED => data.to_vec(),
+            METHOD_DEFLATE => inflate(data, entry.size).map_err(|e| format!("{} : {}", entry.name, e))?,
+            other => return Err(format!("{} : méthode de compression {} non gérée", entry.name, other)),
+        };
+        if content.len() != entry.size {
+            return Err(format!("{} : taille décompressée incohérente", entry.name));
+        }
+        Ok(content)
+    }
+}
+
+/// CRC-32 (polynôme 0xEDB88320) exigé par les en-têtes ZIP.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Archive ZIP sans compression, pour les fichiers de démonstration.
+pub fn write_stored(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in files {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let mut common = Vec::new();
+        common.extend_from_slice(&20u16.to_le_bytes()); // version nécessaire
+        common.extend_from_slice(&0u16.to_le_bytes()); // drapeaux
+        common.extend_from_slice(&METHOD_STORED.to_le_bytes());
+        common.extend_from_slice(&[0; 4]); // heure et date DOS
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        common.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        common.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes()); // champ extra
+
+        out.extend_from_slice(&LOCAL_FILE_HEADER.to_le_bytes());
+        out.extend_from_slice(&common);
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        central.extend_from_slice(&CENTRAL_FILE_HEADER.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes()); // version créatrice
+        central.extend_from_slice(&common);
+        central.extend_from_slice(&[0; 6]); // commentaire, disque, attributs internes
+        central.extend_from_slice(&[0; 4]); // attributs externes
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // numéros de disque
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // commentaire
+    out
+}
+
+// --- Deflate ---
+// Décodeur canonique de Huffman à la manière de « puff » (zlib) : lent mais court.
+
+const MAX_BITS: usize = 15;
+
+const LENGTH_BASE: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DIST_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+/// Ordre de transmission des longueurs de code du code des longueurs.
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+struct Huffman {
+    /// Nombre de codes par longueur.
+    counts: [u16; MAX_BITS + 1],
+    /// Symboles triés par code canonique.
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, String> {
+        let mut counts = [0u16; MAX_BITS + 1];
+        for &len in lengths {
+            counts[len as usize] += 1;
+        }
+        // Code sur-souscrit : impossible à décoder.
+        let mut left: i32 = 1;
+        for &count in &counts[1..] {
+            left = left * 2 - count as i32;
+            if left < 0 {
+                return Err("table de Huffman invalide".to_string());
+            }
+        }
+        let mut offsets = [0u16; MAX_BITS + 2];
+        for len in 1..=MAX_BITS {
+            offsets[len + 1] = offsets[len] + counts[len];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                symbols[offsets[len as usize] as usize] = symbol as u16;
+                offsets[len as usize] += 1;
+            }
+        }
+        Ok(Huffman { counts, symbols })
+    }
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    bit_buf: u32,
+    bit_count: u32,
+}
+
+impl BitReader<'_> {
+    fn bits(&mut self, need: u32) -> Result<u32, String> {
+        let mut value = self.bit_buf;
+        while self.bit_count < need {
+            let byte = *self.data.get(self.pos).ok_or("flux deflate tronqué")?;
+            self.pos += 1;
+            value |= (byte as u32) << self.bit_count;
+            self.bit_count += 8;
+        }
+        self.bit_buf = if need >= 32 { 0 } else { value >> need };
+        self.bit_count -= need;
+        Ok(if need >= 32 { value } else { value & ((1 << need) - 1) })
+    }
+
+    fn decode(&mut self, table: &Huffman) -> Result<u16, String> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for len in 1..=MAX_BITS {
+            code |= self.bits(1)? as i32;
+            let count = table.counts[len] as i32;
+            if code - count < first {
+                return Ok(table.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("code de Huffman inconnu".to_string())
+    }
+}
+
+/// Décompresse un flux deflate brut (sans en-tête zlib ni gzip), sans dépasser `limit` octets
+/// (ni [`MAX_ENTRY_SIZE`]) : une archive piégée ne peut pas épuiser la mémoire.
+pub fn inflate(data: &[u8], limit: usize) -> Result<Vec<u8>, String> {
+    let limit = limit.min(MAX_ENTRY_SIZE);
+    let mut input = BitReader { data, pos: 0, bit_buf: 0, bit_count: 0 };
+    let mut out = Vec::new();
+    loop {
+        let last = input.bits(1)? == 1;
+        match input.bits(2)? {
+            0 => {
+                // Bloc stocké : on se réaligne sur l'octet.
+                input.bit_buf = 0;
+                input.bit_count = 0;
+                let header = data.get(input.pos..input.pos + 4).ok_or("bloc stocké tronqué")?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                if len != !u16::from_le_bytes([header[2], header[3]]) {
+                    return Err("longueur de bloc stocké incohérente".to_string());
+                }
+                input.pos += 4;
+                let block = data.get(input.pos..input.pos + len as usize).ok_or("bloc stocké tronqué")?;
+                if out.len() + block.len() > limit {
+                    return Err(too_large(limit));
+                }
+                out.extend_from_slice(block);
+                input.pos += len as usize;
+            }
+            1 => {
+                let mut lengths = [0u8; 288];
+                lengths[..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..].fill(8);
+                let literals = Huffman::new(&lengths)?;
+                let distances = Huffman::new(&[5; 30])?;
+                inflate_block(&mut input, &mut out, limit, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_tables(&mut input)?;
+                inflate_block(&mut input, &mut out, limit, &literals, &distances)?;
+            }
+            _ => return Err("type de bloc deflate invalide".to_string()),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+fn dynamic_tables(input: &mut BitReader) -> Result<(Huffman, Huffman), String> {
+    let literal_count = input.bits(5)? as usize + 257;
+    let distance_count = input.bits(5)? as usize + 1;
+    let code_count = input.bits(4)? as usize + 4;
+    if literal_count > 286 || distance_count > 30 {
+        return Err("table dynamique trop grande".to_string());
+    }
+    let mut code_lengths = [0u8; 19];
+    for &slot in &CODE_LENGTH_ORDER[..code_count] {
+        code_lengths[slot] = input.bits(3)? as u8;
+    }
+    let code_table = Huffman::new(&code_lengths)?;
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let (value, repeat) = match input.decode(&code_table)? {
+            symbol @ 0..=15 => (symbol as u8, 1),
+            16 => (*lengths.last().ok_or("répétition sans longueur précédente")?, 3 + input.bits(2)?),
+            17 => (0, 3 + input.bits(3)?),
+            _ => (0, 11 + input.bits(7)?),
+        };
+        lengths.extend(std::iter::repeat_n(value, repeat as usize));
+    }
+    if lengths.len() > literal_count + distance_count {
+        return Err("trop de longueurs dans la table dynamique".to_string());
+    }
+    if lengths[256] == 0 {
+        return Err("code de fin de bloc absent".to_string());
+    }
+    Ok((Huffman::new(&lengths[..literal_count])?, Huffman::new(&lengths[literal_count..])?))
+}
+
+fn too_large(limit: usize) -> String {
+    format!("données décompressées au-delà de {} octets", limit)
+}
+
+fn inflate_block(input: &mut BitReader, out: &mut Vec<u8>, limit: usize, literals: &Huffman, distances: &Huffman) -> Result<(), String> {
+    loop {
+        let symbol = input.decode(literals)? as usize;
+        match symbol {
+            0..=255 if out.len() >= limit => return Err(too_large(limit)),
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let index = symbol - 257;
+                if index >= LENGTH_BASE.len() {
+                    return Err("longueur de copie invalide".to_string());
+                }
+                let length = LENGTH_BASE[index] as usize + input.bits(LENGTH_EXTRA[index] as u32)? as usize;
+                let index = input.decode(distances)? as usize;
+                if index >= DIST_BASE.len() {
+                    return Err("distance de copie invalide".to_string());
+                }
+                let distance = DIST_BASE[index] as usize + input.bits(DIST_EXTRA[index] as u32)? as usize;
+                if distance > out.len() {
+                    return Err("distance au-delà du début des données".to_string());
+                }
+                if out.len() + length > limit {
+                    return Err(too_large(limit));
+                }
+                let start = out.len() - distance;
+                for i in 0..length {
+                    out.push(out[start + i]);
+                }
+            }
+        }
+    }
+}